@@ -1,20 +1,36 @@
 pub mod post;
 pub mod prelude;
 pub mod chat;
+pub mod checklist_result;
+pub mod checklist_template;
+pub mod checklist_template_item;
+pub mod fast_pass;
 pub mod lost_and_found_item;
 pub mod maintenance;
+pub mod maintenance_note;
+pub mod maintenance_part_usage;
+pub mod maintenance_plan;
+pub mod maintenance_sla_policy;
 pub mod menu;
 pub mod message;
 pub mod notification;
 pub mod order;
+pub mod order_line;
+pub mod order_status_history;
 pub mod proposal;
 pub mod queue;
+pub mod queue_party_member;
 pub mod report;
 pub mod restaurant;
 pub mod ride;
+pub mod ride_cycle;
+pub mod ride_status_history;
+pub mod sea_orm_active_enums;
 pub mod souvenir;
+pub mod spare_part;
 pub mod store;
 pub mod user;
+pub mod wallet_transaction;
 
 pub fn add(left: u64, right: u64) -> u64 {
     left + right
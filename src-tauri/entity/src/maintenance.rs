@@ -1,5 +1,6 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.6
 
+use super::sea_orm_active_enums::MaintenanceStatus;
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -12,13 +13,36 @@ pub struct Model {
     pub r#type: String,
     pub issue: String,
     pub date: String,
-    pub status: String,
+    pub status: MaintenanceStatus,
     pub maintenance_staff_id: Option<String>,
     pub sender_id: String,
+    pub assigned_at: Option<String>,
+    pub started_at: Option<String>,
+    pub completed_at: Option<String>,
+    pub plan_id: Option<String>,
+    pub due_at: Option<String>,
+    pub is_overdue: bool,
+    pub reported_at: Option<String>,
+    pub escalation_level: i32,
+    pub escalated_at: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::checklist_result::Entity")]
+    ChecklistResult,
+    #[sea_orm(has_many = "super::maintenance_note::Entity")]
+    MaintenanceNote,
+    #[sea_orm(has_many = "super::maintenance_part_usage::Entity")]
+    MaintenancePartUsage,
+    #[sea_orm(
+        belongs_to = "super::maintenance_plan::Entity",
+        from = "Column::PlanId",
+        to = "super::maintenance_plan::Column::PlanId",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    MaintenancePlan,
     #[sea_orm(
         belongs_to = "super::ride::Entity",
         from = "Column::RideId",
@@ -45,6 +69,30 @@ pub enum Relation {
     User1,
 }
 
+impl Related<super::checklist_result::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ChecklistResult.def()
+    }
+}
+
+impl Related<super::maintenance_note::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::MaintenanceNote.def()
+    }
+}
+
+impl Related<super::maintenance_part_usage::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::MaintenancePartUsage.def()
+    }
+}
+
+impl Related<super::maintenance_plan::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::MaintenancePlan.def()
+    }
+}
+
 impl Related<super::ride::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Ride.def()
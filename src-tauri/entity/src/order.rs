@@ -1,5 +1,6 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.6
 
+use super::sea_orm_active_enums::OrderStatus;
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -10,15 +11,26 @@ pub struct Model {
     pub order_id: String,
     pub customer_id: String,
     pub item_type: String,
-    pub item_id: String,
-    pub quantity: i32,
     pub date: String,
     pub is_paid: bool,
-    pub status: Option<String>,
+    pub status: Option<OrderStatus>,
+    pub restaurant_id: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::order_line::Entity")]
+    OrderLine,
+    #[sea_orm(has_many = "super::order_status_history::Entity")]
+    OrderStatusHistory,
+    #[sea_orm(
+        belongs_to = "super::restaurant::Entity",
+        from = "Column::RestaurantId",
+        to = "super::restaurant::Column::RestaurantId",
+        on_update = "NoAction",
+        on_delete = "NoAction"
+    )]
+    Restaurant,
     #[sea_orm(
         belongs_to = "super::user::Entity",
         from = "Column::CustomerId",
@@ -29,6 +41,24 @@ pub enum Relation {
     User,
 }
 
+impl Related<super::order_line::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::OrderLine.def()
+    }
+}
+
+impl Related<super::order_status_history::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::OrderStatusHistory.def()
+    }
+}
+
+impl Related<super::restaurant::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Restaurant.def()
+    }
+}
+
 impl Related<super::user::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::User.def()
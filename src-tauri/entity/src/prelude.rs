@@ -1,18 +1,33 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.6
 
 pub use super::chat::Entity as Chat;
+pub use super::checklist_result::Entity as ChecklistResult;
+pub use super::checklist_template::Entity as ChecklistTemplate;
+pub use super::checklist_template_item::Entity as ChecklistTemplateItem;
+pub use super::fast_pass::Entity as FastPass;
 pub use super::lost_and_found_item::Entity as LostAndFoundItem;
 pub use super::maintenance::Entity as Maintenance;
+pub use super::maintenance_note::Entity as MaintenanceNote;
+pub use super::maintenance_part_usage::Entity as MaintenancePartUsage;
+pub use super::maintenance_plan::Entity as MaintenancePlan;
+pub use super::maintenance_sla_policy::Entity as MaintenanceSlaPolicy;
 pub use super::menu::Entity as Menu;
 pub use super::message::Entity as Message;
 pub use super::notification::Entity as Notification;
 pub use super::order::Entity as Order;
+pub use super::order_line::Entity as OrderLine;
+pub use super::order_status_history::Entity as OrderStatusHistory;
 pub use super::post::Entity as Post;
 pub use super::proposal::Entity as Proposal;
 pub use super::queue::Entity as Queue;
+pub use super::queue_party_member::Entity as QueuePartyMember;
 pub use super::report::Entity as Report;
 pub use super::restaurant::Entity as Restaurant;
 pub use super::ride::Entity as Ride;
+pub use super::ride_cycle::Entity as RideCycle;
+pub use super::ride_status_history::Entity as RideStatusHistory;
 pub use super::souvenir::Entity as Souvenir;
+pub use super::spare_part::Entity as SparePart;
 pub use super::store::Entity as Store;
 pub use super::user::Entity as User;
+pub use super::wallet_transaction::Entity as WalletTransaction;
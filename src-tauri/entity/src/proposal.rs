@@ -23,6 +23,8 @@ pub struct Model {
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::spare_part::Entity")]
+    SparePart,
     #[sea_orm(
         belongs_to = "super::user::Entity",
         from = "Column::SenderId",
@@ -33,6 +35,12 @@ pub enum Relation {
     User,
 }
 
+impl Related<super::spare_part::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SparePart.def()
+    }
+}
+
 impl Related<super::user::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::User.def()
@@ -12,10 +12,14 @@ pub struct Model {
     pub customer_id: String,
     pub position: i32,
     pub joined_at: String,
+    pub party_size: i32,
+    pub on_hold: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::queue_party_member::Entity")]
+    QueuePartyMember,
     #[sea_orm(
         belongs_to = "super::ride::Entity",
         from = "Column::RideId",
@@ -34,6 +38,12 @@ pub enum Relation {
     User,
 }
 
+impl Related<super::queue_party_member::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::QueuePartyMember.def()
+    }
+}
+
 impl Related<super::ride::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Ride.def()
@@ -25,6 +25,8 @@ pub struct Model {
 pub enum Relation {
     #[sea_orm(has_many = "super::menu::Entity")]
     Menu,
+    #[sea_orm(has_many = "super::order::Entity")]
+    Order,
     #[sea_orm(has_many = "super::user::Entity")]
     User,
 }
@@ -35,6 +37,12 @@ impl Related<super::menu::Entity> for Entity {
     }
 }
 
+impl Related<super::order::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Order.def()
+    }
+}
+
 impl Related<super::user::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::User.def()
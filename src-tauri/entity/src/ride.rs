@@ -1,5 +1,6 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.6
 
+use super::sea_orm_active_enums::{RideMaintenanceStatus, RideStatus};
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -16,17 +17,38 @@ pub struct Model {
     pub image: Vec<u8>,
     pub description: String,
     pub location: String,
-    pub status: String,
+    pub status: RideStatus,
     pub capacity: i32,
-    pub maintenance_status: String,
+    pub maintenance_status: RideMaintenanceStatus,
+    pub cycle_duration: i32,
+    pub min_age: Option<i32>,
+    pub min_height_cm: Option<i32>,
+    pub max_height_cm: Option<i32>,
+    pub wheelchair_accessible: bool,
+    pub health_advisory: Option<String>,
+    pub ride_type: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::fast_pass::Entity")]
+    FastPass,
     #[sea_orm(has_many = "super::maintenance::Entity")]
     Maintenance,
+    #[sea_orm(has_many = "super::maintenance_plan::Entity")]
+    MaintenancePlan,
     #[sea_orm(has_many = "super::queue::Entity")]
     Queue,
+    #[sea_orm(has_many = "super::ride_cycle::Entity")]
+    RideCycle,
+    #[sea_orm(has_many = "super::ride_status_history::Entity")]
+    RideStatusHistory,
+}
+
+impl Related<super::fast_pass::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::FastPass.def()
+    }
 }
 
 impl Related<super::maintenance::Entity> for Entity {
@@ -35,10 +57,28 @@ impl Related<super::maintenance::Entity> for Entity {
     }
 }
 
+impl Related<super::maintenance_plan::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::MaintenancePlan.def()
+    }
+}
+
 impl Related<super::queue::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Queue.def()
     }
 }
 
+impl Related<super::ride_cycle::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RideCycle.def()
+    }
+}
+
+impl Related<super::ride_status_history::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RideStatusHistory.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
\ No newline at end of file
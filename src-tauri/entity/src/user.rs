@@ -3,7 +3,7 @@
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
 #[sea_orm(table_name = "user")]
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
@@ -14,24 +14,43 @@ pub struct Model {
     pub password: Option<String>,
     pub dob: String,
     pub role: String,
-    #[sea_orm(column_type = "Double")]
-    pub balance: f64,
+    pub balance: i64,
     pub status: Option<String>,
     pub restaurant_id: Option<String>,
+    pub must_change_password: bool,
+    pub password_changed_at: Option<String>,
+    pub height_cm: Option<i32>,
+    pub uses_wheelchair: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::checklist_result::Entity")]
+    ChecklistResult,
+    #[sea_orm(has_many = "super::checklist_template::Entity")]
+    ChecklistTemplate,
+    #[sea_orm(has_many = "super::fast_pass::Entity")]
+    FastPass,
+    #[sea_orm(has_many = "super::maintenance_note::Entity")]
+    MaintenanceNote,
+    #[sea_orm(has_many = "super::maintenance_part_usage::Entity")]
+    MaintenancePartUsage,
+    #[sea_orm(has_many = "super::maintenance_plan::Entity")]
+    MaintenancePlan,
     #[sea_orm(has_many = "super::message::Entity")]
     Message,
     #[sea_orm(has_many = "super::notification::Entity")]
     Notification,
     #[sea_orm(has_many = "super::order::Entity")]
     Order,
+    #[sea_orm(has_many = "super::order_status_history::Entity")]
+    OrderStatusHistory,
     #[sea_orm(has_many = "super::proposal::Entity")]
     Proposal,
     #[sea_orm(has_many = "super::queue::Entity")]
     Queue,
+    #[sea_orm(has_many = "super::queue_party_member::Entity")]
+    QueuePartyMember,
     #[sea_orm(
         belongs_to = "super::restaurant::Entity",
         from = "Column::RestaurantId",
@@ -40,8 +59,50 @@ pub enum Relation {
         on_delete = "NoAction"
     )]
     Restaurant,
+    #[sea_orm(has_many = "super::ride_cycle::Entity")]
+    RideCycle,
+    #[sea_orm(has_many = "super::ride_status_history::Entity")]
+    RideStatusHistory,
     #[sea_orm(has_many = "super::store::Entity")]
     Store,
+    #[sea_orm(has_many = "super::wallet_transaction::Entity")]
+    WalletTransaction,
+}
+
+impl Related<super::checklist_result::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ChecklistResult.def()
+    }
+}
+
+impl Related<super::checklist_template::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ChecklistTemplate.def()
+    }
+}
+
+impl Related<super::fast_pass::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::FastPass.def()
+    }
+}
+
+impl Related<super::maintenance_note::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::MaintenanceNote.def()
+    }
+}
+
+impl Related<super::maintenance_part_usage::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::MaintenancePartUsage.def()
+    }
+}
+
+impl Related<super::maintenance_plan::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::MaintenancePlan.def()
+    }
 }
 
 impl Related<super::message::Entity> for Entity {
@@ -62,6 +123,12 @@ impl Related<super::order::Entity> for Entity {
     }
 }
 
+impl Related<super::order_status_history::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::OrderStatusHistory.def()
+    }
+}
+
 impl Related<super::proposal::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Proposal.def()
@@ -74,16 +141,40 @@ impl Related<super::queue::Entity> for Entity {
     }
 }
 
+impl Related<super::queue_party_member::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::QueuePartyMember.def()
+    }
+}
+
 impl Related<super::restaurant::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Restaurant.def()
     }
 }
 
+impl Related<super::ride_cycle::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RideCycle.def()
+    }
+}
+
+impl Related<super::ride_status_history::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RideStatusHistory.def()
+    }
+}
+
 impl Related<super::store::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Store.def()
     }
 }
 
+impl Related<super::wallet_transaction::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::WalletTransaction.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
\ No newline at end of file
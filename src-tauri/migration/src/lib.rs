@@ -16,6 +16,26 @@ mod m20250304_121615_create_table_notification;
 mod m20250304_121631_create_table_lost_and_found_item;
 mod m20250304_121645_create_table_chat;
 mod m20250304_121652_create_table_message;
+mod m20261018_090000_hash_user_passwords;
+mod m20261018_091000_create_table_wallet_transaction;
+mod m20261018_092000_create_table_order_line;
+mod m20261018_093000_create_table_order_status_history;
+mod m20261018_094000_add_restaurant_to_order;
+mod m20261018_095000_add_listing_indexes;
+mod m20261018_100000_add_queue_position_unique;
+mod m20261018_101000_create_table_ride_cycle;
+mod m20261018_102000_create_table_fast_pass;
+mod m20261018_103000_add_queue_party;
+mod m20261018_104000_add_queue_on_hold;
+mod m20261018_105000_create_table_ride_status_history;
+mod m20261018_106000_add_ride_eligibility;
+mod m20261018_107000_add_maintenance_workflow;
+mod m20261018_108000_create_table_maintenance_plan;
+mod m20261018_109000_create_table_inspection_checklist;
+mod m20261018_110000_create_table_spare_part;
+mod m20261018_111000_create_table_maintenance_sla_policy;
+mod m20261018_112000_add_ride_status_checks;
+mod m20261018_113000_add_maintenance_plan_anchor_day;
 pub struct Migrator;
 
 #[async_trait::async_trait]
@@ -38,6 +58,26 @@ impl MigratorTrait for Migrator {
             Box::new(m20250304_121631_create_table_lost_and_found_item::Migration),
             Box::new(m20250304_121645_create_table_chat::Migration),
             Box::new(m20250304_121652_create_table_message::Migration),
+            Box::new(m20261018_090000_hash_user_passwords::Migration),
+            Box::new(m20261018_091000_create_table_wallet_transaction::Migration),
+            Box::new(m20261018_092000_create_table_order_line::Migration),
+            Box::new(m20261018_093000_create_table_order_status_history::Migration),
+            Box::new(m20261018_094000_add_restaurant_to_order::Migration),
+            Box::new(m20261018_095000_add_listing_indexes::Migration),
+            Box::new(m20261018_100000_add_queue_position_unique::Migration),
+            Box::new(m20261018_101000_create_table_ride_cycle::Migration),
+            Box::new(m20261018_102000_create_table_fast_pass::Migration),
+            Box::new(m20261018_103000_add_queue_party::Migration),
+            Box::new(m20261018_104000_add_queue_on_hold::Migration),
+            Box::new(m20261018_105000_create_table_ride_status_history::Migration),
+            Box::new(m20261018_106000_add_ride_eligibility::Migration),
+            Box::new(m20261018_107000_add_maintenance_workflow::Migration),
+            Box::new(m20261018_108000_create_table_maintenance_plan::Migration),
+            Box::new(m20261018_109000_create_table_inspection_checklist::Migration),
+            Box::new(m20261018_110000_create_table_spare_part::Migration),
+            Box::new(m20261018_111000_create_table_maintenance_sla_policy::Migration),
+            Box::new(m20261018_112000_add_ride_status_checks::Migration),
+            Box::new(m20261018_113000_add_maintenance_plan_anchor_day::Migration),
         ]
     }
 }
@@ -319,6 +319,7 @@ impl MigrationTrait for Migration {
     }
 }
 
+#[allow(clippy::enum_variant_names, clippy::upper_case_acronyms)]
 #[derive(Iden)]
 enum User {
     Table,
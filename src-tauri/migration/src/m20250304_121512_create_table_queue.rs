@@ -71,6 +71,7 @@ impl MigrationTrait for Migration {
     }
 }
 
+#[allow(clippy::enum_variant_names)]
 #[derive(Iden)]
 enum Queue {
     Table,
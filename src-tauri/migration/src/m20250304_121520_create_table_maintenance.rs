@@ -53,6 +53,7 @@ impl MigrationTrait for Migration {
     }
 }
 
+#[allow(clippy::enum_variant_names)]
 #[derive(Iden)]
 enum Maintenance {
     Table,
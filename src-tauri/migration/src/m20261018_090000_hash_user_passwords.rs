@@ -0,0 +1,99 @@
+use argon2::{
+    password_hash::{rand_core::OsRng, PasswordHasher, SaltString},
+    Argon2,
+};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(User::Table)
+                    .add_column(
+                        ColumnDef::new(User::MustChangePassword)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .add_column(ColumnDef::new(User::PasswordChangedAt).string().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        let db = manager.get_connection();
+        let backend = manager.get_database_backend();
+
+        let rows = db
+            .query_all(
+                backend.build(
+                    Query::select()
+                        .columns([User::UserID, User::Password])
+                        .from(User::Table)
+                        .and_where(Expr::col(User::Password).is_not_null())
+                ),
+            )
+            .await?;
+
+        // Seeded staff passwords are plaintext copies of the user ID, so every
+        // existing password is rehashed and its owner forced to rotate it.
+        for row in rows {
+            let user_id: String = row.try_get("", "user_id")?;
+            let password: String = row.try_get("", "password")?;
+
+            if password.starts_with("$argon2") {
+                continue;
+            }
+
+            let salt = SaltString::generate(&mut OsRng);
+            let hashed = Argon2::default()
+                .hash_password(password.as_bytes(), &salt)
+                .map_err(|e| DbErr::Custom(format!("Failed to hash password for {}: {}", user_id, e)))?
+                .to_string();
+
+            db.execute(
+                backend.build(
+                    Query::update()
+                        .table(User::Table)
+                        .values([
+                            (User::Password, hashed.into()),
+                            (User::MustChangePassword, true.into()),
+                        ])
+                        .and_where(Expr::col(User::UserID).eq(user_id))
+                ),
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Hashes cannot be reversed, so rolling back only drops the lifecycle columns.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(User::Table)
+                    .drop_column(User::MustChangePassword)
+                    .drop_column(User::PasswordChangedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Iden)]
+enum User {
+    Table,
+    UserID,
+    Password,
+    MustChangePassword,
+    PasswordChangedAt,
+}
@@ -0,0 +1,155 @@
+use chrono::Utc;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(WalletTransaction::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(WalletTransaction::TransactionID).string().not_null().primary_key())
+                    .col(ColumnDef::new(WalletTransaction::UserID).string().not_null())
+                    .col(ColumnDef::new(WalletTransaction::Kind).string().not_null())
+                    .col(ColumnDef::new(WalletTransaction::Amount).big_integer().not_null())
+                    .col(ColumnDef::new(WalletTransaction::BalanceAfter).big_integer().not_null())
+                    .col(ColumnDef::new(WalletTransaction::ReferenceID).string().null())
+                    .col(ColumnDef::new(WalletTransaction::Description).string().not_null())
+                    .col(ColumnDef::new(WalletTransaction::CreatedAt).string().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_wallet_transaction_user")
+                            .from(WalletTransaction::Table, WalletTransaction::UserID)
+                            .to(User::Table, User::UserID),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_wallet_transaction_user_created")
+                    .table(WalletTransaction::Table)
+                    .col(WalletTransaction::UserID)
+                    .col(WalletTransaction::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        let db = manager.get_connection();
+        let backend = manager.get_database_backend();
+
+        // Balances move from floating point major units to integer minor units (cents).
+        db.execute(
+            backend.build(
+                Query::update()
+                    .table(User::Table)
+                    .value(User::Balance, Expr::col(User::Balance).mul(100))
+            ),
+        )
+        .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(User::Table)
+                    .modify_column(ColumnDef::new(User::Balance).big_integer().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // Every existing balance gets an opening entry so the ledger sums to the stored balance.
+        let now = Utc::now().to_rfc3339();
+
+        let rows = db
+            .query_all(
+                backend.build(
+                    Query::select()
+                        .columns([User::UserID, User::Balance])
+                        .from(User::Table)
+                        .and_where(Expr::col(User::Balance).ne(0))
+                ),
+            )
+            .await?;
+
+        for row in rows {
+            let user_id: String = row.try_get("", "user_id")?;
+            let balance: i64 = row.try_get("", "balance")?;
+
+            db.execute(
+                backend.build(
+                    Query::insert()
+                        .into_table(WalletTransaction::Table)
+                        .columns([
+                            WalletTransaction::TransactionID, WalletTransaction::UserID, WalletTransaction::Kind,
+                            WalletTransaction::Amount, WalletTransaction::BalanceAfter, WalletTransaction::ReferenceID,
+                            WalletTransaction::Description, WalletTransaction::CreatedAt,
+                        ])
+                        .values_panic([
+                            format!("WTX-OPEN-{}", user_id).into(), user_id.into(), "Opening Balance".into(),
+                            balance.into(), balance.into(), None::<String>.into(),
+                            "Balance carried over when the wallet ledger was introduced".into(), now.clone().into(),
+                        ])
+                ),
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(User::Table)
+                    .modify_column(ColumnDef::new(User::Balance).double().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        let db = manager.get_connection();
+        let backend = manager.get_database_backend();
+
+        db.execute(
+            backend.build(
+                Query::update()
+                    .table(User::Table)
+                    .value(User::Balance, Expr::col(User::Balance).div(100))
+            ),
+        )
+        .await?;
+
+        manager
+            .drop_table(Table::drop().table(WalletTransaction::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum WalletTransaction {
+    Table,
+    TransactionID,
+    UserID,
+    Kind,
+    Amount,
+    BalanceAfter,
+    ReferenceID,
+    Description,
+    CreatedAt,
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Iden)]
+enum User {
+    Table,
+    UserID,
+    Balance,
+}
@@ -0,0 +1,191 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(OrderLine::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(OrderLine::LineID).string().not_null().primary_key())
+                    .col(ColumnDef::new(OrderLine::OrderID).string().not_null())
+                    .col(ColumnDef::new(OrderLine::ItemID).string().not_null())
+                    .col(ColumnDef::new(OrderLine::ItemName).string().not_null())
+                    .col(ColumnDef::new(OrderLine::UnitPrice).big_integer().not_null())
+                    .col(ColumnDef::new(OrderLine::Quantity).integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_order_line_order")
+                            .from(OrderLine::Table, OrderLine::OrderID)
+                            .to(Order::Table, Order::OrderID)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_order_line_order")
+                    .table(OrderLine::Table)
+                    .col(OrderLine::OrderID)
+                    .to_owned(),
+            )
+            .await?;
+
+        let db = manager.get_connection();
+        let backend = manager.get_database_backend();
+
+        let rows = db
+            .query_all(
+                backend.build(
+                    Query::select()
+                        .columns([Order::OrderID, Order::ItemType, Order::ItemID, Order::Quantity])
+                        .from(Order::Table)
+                ),
+            )
+            .await?;
+
+        // Each existing single-item order becomes a one-line cart, priced at today's catalogue price.
+        for row in rows {
+            let order_id: String = row.try_get("", "order_id")?;
+            let item_type: String = row.try_get("", "item_type")?;
+            let item_id: String = row.try_get("", "item_id")?;
+            let quantity: i32 = row.try_get("", "quantity")?;
+
+            let (table, id_column) = match item_type.as_str() {
+                "restaurant" => (Alias::new("menu"), Alias::new("menu_id")),
+                "store" => (Alias::new("souvenir"), Alias::new("souvenir_id")),
+                _ => (Alias::new("ride"), Alias::new("ride_id")),
+            };
+
+            let item = db
+                .query_one(
+                    backend.build(
+                        Query::select()
+                            .columns([Alias::new("name"), Alias::new("price")])
+                            .from(table)
+                            .and_where(Expr::col(id_column).eq(item_id.clone()))
+                    ),
+                )
+                .await?;
+
+            let (item_name, unit_price) = match item {
+                Some(item) => {
+                    let name: String = item.try_get("", "name")?;
+                    let price: f64 = item.try_get("", "price")?;
+                    (name, (price * 100.0).round() as i64)
+                }
+                None => (item_id.clone(), 0),
+            };
+
+            db.execute(
+                backend.build(
+                    Query::insert()
+                        .into_table(OrderLine::Table)
+                        .columns([
+                            OrderLine::LineID, OrderLine::OrderID, OrderLine::ItemID,
+                            OrderLine::ItemName, OrderLine::UnitPrice, OrderLine::Quantity,
+                        ])
+                        .values_panic([
+                            format!("OL-{}-1", order_id).into(), order_id.into(), item_id.into(),
+                            item_name.into(), unit_price.into(), quantity.into(),
+                        ])
+                ),
+            )
+            .await?;
+        }
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Order::Table)
+                    .drop_column(Order::ItemID)
+                    .drop_column(Order::Quantity)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Order::Table)
+                    .add_column(ColumnDef::new(Order::ItemID).string().not_null().default(""))
+                    .add_column(ColumnDef::new(Order::Quantity).integer().not_null().default(0))
+                    .to_owned(),
+            )
+            .await?;
+
+        let db = manager.get_connection();
+        let backend = manager.get_database_backend();
+
+        let rows = db
+            .query_all(
+                backend.build(
+                    Query::select()
+                        .columns([OrderLine::OrderID, OrderLine::ItemID, OrderLine::Quantity])
+                        .from(OrderLine::Table)
+                        .order_by(OrderLine::LineID, sea_orm_migration::sea_query::Order::Asc)
+                ),
+            )
+            .await?;
+
+        // Only the first line of each cart fits back into the single-item layout.
+        let mut restored = std::collections::HashSet::new();
+        for row in rows {
+            let order_id: String = row.try_get("", "order_id")?;
+            if !restored.insert(order_id.clone()) {
+                continue;
+            }
+
+            let item_id: String = row.try_get("", "item_id")?;
+            let quantity: i32 = row.try_get("", "quantity")?;
+
+            db.execute(
+                backend.build(
+                    Query::update()
+                        .table(Order::Table)
+                        .values([(Order::ItemID, item_id.into()), (Order::Quantity, quantity.into())])
+                        .and_where(Expr::col(Order::OrderID).eq(order_id))
+                ),
+            )
+            .await?;
+        }
+
+        manager
+            .drop_table(Table::drop().table(OrderLine::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum OrderLine {
+    Table,
+    LineID,
+    OrderID,
+    ItemID,
+    ItemName,
+    UnitPrice,
+    Quantity,
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Iden)]
+enum Order {
+    Table,
+    OrderID,
+    ItemType,
+    ItemID,
+    Quantity,
+}
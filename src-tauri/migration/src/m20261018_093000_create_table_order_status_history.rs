@@ -0,0 +1,120 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// Free-form statuses written by the old kitchen screens and their place in the typed lifecycle.
+const STATUS_MAPPING: &[(&str, &str)] = &[
+    ("Waiting for Cooking", "Placed"),
+    ("Waiting for Delivery", "Ready"),
+    ("Delivered", "Served"),
+];
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(OrderStatusHistory::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(OrderStatusHistory::HistoryID).string().not_null().primary_key())
+                    .col(ColumnDef::new(OrderStatusHistory::OrderID).string().not_null())
+                    .col(ColumnDef::new(OrderStatusHistory::FromStatus).string().null())
+                    .col(ColumnDef::new(OrderStatusHistory::ToStatus).string().not_null())
+                    .col(ColumnDef::new(OrderStatusHistory::ActorID).string().not_null())
+                    .col(ColumnDef::new(OrderStatusHistory::ChangedAt).string().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_order_status_history_order")
+                            .from(OrderStatusHistory::Table, OrderStatusHistory::OrderID)
+                            .to(Order::Table, Order::OrderID)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_order_status_history_user")
+                            .from(OrderStatusHistory::Table, OrderStatusHistory::ActorID)
+                            .to(User::Table, User::UserID),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_order_status_history_order")
+                    .table(OrderStatusHistory::Table)
+                    .col(OrderStatusHistory::OrderID)
+                    .col(OrderStatusHistory::ChangedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        let db = manager.get_connection();
+        let backend = manager.get_database_backend();
+
+        for (old, new) in STATUS_MAPPING {
+            db.execute(
+                backend.build(
+                    Query::update()
+                        .table(Order::Table)
+                        .value(Order::Status, *new)
+                        .and_where(Expr::col(Order::Status).eq(*old))
+                ),
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        let backend = manager.get_database_backend();
+
+        for (old, new) in STATUS_MAPPING {
+            db.execute(
+                backend.build(
+                    Query::update()
+                        .table(Order::Table)
+                        .value(Order::Status, *old)
+                        .and_where(Expr::col(Order::Status).eq(*new))
+                ),
+            )
+            .await?;
+        }
+
+        manager
+            .drop_table(Table::drop().table(OrderStatusHistory::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum OrderStatusHistory {
+    Table,
+    HistoryID,
+    OrderID,
+    FromStatus,
+    ToStatus,
+    ActorID,
+    ChangedAt,
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Iden)]
+enum Order {
+    Table,
+    OrderID,
+    Status,
+}
+
+#[derive(Iden)]
+enum User {
+    Table,
+    UserID,
+}
@@ -0,0 +1,126 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Order::Table)
+                    .add_column(ColumnDef::new(Order::RestaurantID).string().null())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_order_restaurant")
+                            .from_tbl(Order::Table)
+                            .from_col(Order::RestaurantID)
+                            .to_tbl(Restaurant::Table)
+                            .to_col(Restaurant::RestaurantID),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        let db = manager.get_connection();
+        let backend = manager.get_database_backend();
+
+        // Restaurant orders take the restaurant of the menu items on their lines.
+        let rows = db
+            .query_all(
+                backend.build(
+                    Query::select()
+                        .distinct()
+                        .column((OrderLine::Table, OrderLine::OrderID))
+                        .column((Menu::Table, Menu::RestaurantID))
+                        .from(OrderLine::Table)
+                        .inner_join(
+                            Menu::Table,
+                            Expr::col((Menu::Table, Menu::MenuID)).equals((OrderLine::Table, OrderLine::ItemID)),
+                        )
+                        .inner_join(
+                            Order::Table,
+                            Expr::col((Order::Table, Order::OrderID)).equals((OrderLine::Table, OrderLine::OrderID)),
+                        )
+                        .and_where(Expr::col((Order::Table, Order::ItemType)).eq("restaurant"))
+                ),
+            )
+            .await?;
+
+        for row in rows {
+            let order_id: String = row.try_get("", "order_id")?;
+            let restaurant_id: String = row.try_get("", "restaurant_id")?;
+
+            db.execute(
+                backend.build(
+                    Query::update()
+                        .table(Order::Table)
+                        .value(Order::RestaurantID, restaurant_id)
+                        .and_where(Expr::col(Order::OrderID).eq(order_id))
+                ),
+            )
+            .await?;
+        }
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_order_restaurant")
+                    .table(Order::Table)
+                    .col(Order::RestaurantID)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(Index::drop().name("idx_order_restaurant").table(Order::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Order::Table)
+                    .drop_foreign_key(Alias::new("fk_order_restaurant"))
+                    .drop_column(Order::RestaurantID)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Iden)]
+enum Order {
+    Table,
+    OrderID,
+    ItemType,
+    RestaurantID,
+}
+
+#[derive(Iden)]
+enum OrderLine {
+    Table,
+    OrderID,
+    ItemID,
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Iden)]
+enum Menu {
+    Table,
+    MenuID,
+    RestaurantID,
+}
+
+#[derive(Iden)]
+enum Restaurant {
+    Table,
+    RestaurantID,
+}
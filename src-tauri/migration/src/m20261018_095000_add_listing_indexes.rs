@@ -0,0 +1,83 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_order_customer_type_date")
+                    .table(Order::Table)
+                    .col(Order::CustomerID)
+                    .col(Order::ItemType)
+                    .col(Order::Date)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_notification_recipient_date")
+                    .table(Notification::Table)
+                    .col(Notification::RecipientID)
+                    .col(Notification::Date)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_queue_ride_position")
+                    .table(Queue::Table)
+                    .col(Queue::RideID)
+                    .col(Queue::Position)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(Index::drop().name("idx_queue_ride_position").table(Queue::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_index(Index::drop().name("idx_notification_recipient_date").table(Notification::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_index(Index::drop().name("idx_order_customer_type_date").table(Order::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Order {
+    Table,
+    CustomerID,
+    ItemType,
+    Date,
+}
+
+#[derive(Iden)]
+enum Notification {
+    Table,
+    RecipientID,
+    Date,
+}
+
+#[derive(Iden)]
+enum Queue {
+    Table,
+    RideID,
+    Position,
+}
@@ -0,0 +1,97 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        let backend = manager.get_database_backend();
+
+        let rows = db
+            .query_all(
+                backend.build(
+                    Query::select()
+                        .columns([Queue::QueueID, Queue::RideID])
+                        .from(Queue::Table)
+                        .order_by(Queue::RideID, Order::Asc)
+                        .order_by(Queue::Position, Order::Asc)
+                        .order_by(Queue::JoinedAt, Order::Asc)
+                ),
+            )
+            .await?;
+
+        // Close the gaps and duplicates left by earlier deletes and concurrent joins so every
+        // line is numbered 1..n before the unique index goes on.
+        let mut current_ride = String::new();
+        let mut position = 0;
+        for row in rows {
+            let queue_id: String = row.try_get("", "queue_id")?;
+            let ride_id: String = row.try_get("", "ride_id")?;
+
+            if ride_id != current_ride {
+                current_ride = ride_id;
+                position = 0;
+            }
+            position += 1;
+
+            db.execute(
+                backend.build(
+                    Query::update()
+                        .table(Queue::Table)
+                        .value(Queue::Position, position)
+                        .and_where(Expr::col(Queue::QueueID).eq(queue_id))
+                ),
+            )
+            .await?;
+        }
+
+        manager
+            .drop_index(Index::drop().name("idx_queue_ride_position").table(Queue::Table).to_owned())
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_queue_ride_position")
+                    .table(Queue::Table)
+                    .col(Queue::RideID)
+                    .col(Queue::Position)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(Index::drop().name("uq_queue_ride_position").table(Queue::Table).to_owned())
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_queue_ride_position")
+                    .table(Queue::Table)
+                    .col(Queue::RideID)
+                    .col(Queue::Position)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Iden)]
+enum Queue {
+    Table,
+    QueueID,
+    RideID,
+    Position,
+    JoinedAt,
+}
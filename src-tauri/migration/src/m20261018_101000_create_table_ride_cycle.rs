@@ -0,0 +1,100 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Seconds from one dispatch to the next, used until enough cycles have been observed.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Ride::Table)
+                    .add_column(ColumnDef::new(Ride::CycleDuration).integer().not_null().default(300))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(RideCycle::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(RideCycle::CycleID).string().not_null().primary_key())
+                    .col(ColumnDef::new(RideCycle::RideID).string().not_null())
+                    .col(ColumnDef::new(RideCycle::StaffID).string().not_null())
+                    .col(ColumnDef::new(RideCycle::RiderCount).integer().not_null())
+                    .col(ColumnDef::new(RideCycle::DispatchedAt).string().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_ride_cycle_ride")
+                            .from(RideCycle::Table, RideCycle::RideID)
+                            .to(Ride::Table, Ride::RideID)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_ride_cycle_user")
+                            .from(RideCycle::Table, RideCycle::StaffID)
+                            .to(User::Table, User::UserID),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_ride_cycle_ride_dispatched")
+                    .table(RideCycle::Table)
+                    .col(RideCycle::RideID)
+                    .col(RideCycle::DispatchedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(RideCycle::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Ride::Table)
+                    .drop_column(Ride::CycleDuration)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum RideCycle {
+    Table,
+    CycleID,
+    RideID,
+    StaffID,
+    RiderCount,
+    DispatchedAt,
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Iden)]
+enum Ride {
+    Table,
+    RideID,
+    CycleDuration,
+}
+
+#[derive(Iden)]
+enum User {
+    Table,
+    UserID,
+}
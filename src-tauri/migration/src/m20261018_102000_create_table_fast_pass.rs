@@ -0,0 +1,96 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(FastPass::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(FastPass::FastPassID).string().not_null().primary_key())
+                    .col(ColumnDef::new(FastPass::RideID).string().not_null())
+                    .col(ColumnDef::new(FastPass::CustomerID).string().not_null())
+                    .col(ColumnDef::new(FastPass::ReturnStart).string().not_null())
+                    .col(ColumnDef::new(FastPass::ReturnEnd).string().not_null())
+                    .col(ColumnDef::new(FastPass::Status).string().not_null())
+                    .col(ColumnDef::new(FastPass::BookedAt).string().not_null())
+                    .col(ColumnDef::new(FastPass::RedeemedAt).string().null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_fast_pass_ride")
+                            .from(FastPass::Table, FastPass::RideID)
+                            .to(Ride::Table, Ride::RideID)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_fast_pass_user")
+                            .from(FastPass::Table, FastPass::CustomerID)
+                            .to(User::Table, User::UserID),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_fast_pass_ride_slot")
+                    .table(FastPass::Table)
+                    .col(FastPass::RideID)
+                    .col(FastPass::ReturnStart)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_fast_pass_customer")
+                    .table(FastPass::Table)
+                    .col(FastPass::CustomerID)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(FastPass::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Iden)]
+enum FastPass {
+    Table,
+    FastPassID,
+    RideID,
+    CustomerID,
+    ReturnStart,
+    ReturnEnd,
+    Status,
+    BookedAt,
+    RedeemedAt,
+}
+
+#[derive(Iden)]
+enum Ride {
+    Table,
+    RideID,
+}
+
+#[derive(Iden)]
+enum User {
+    Table,
+    UserID,
+}
@@ -0,0 +1,98 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Seats the entry takes when it boards: the lead customer plus their party members.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Queue::Table)
+                    .add_column(ColumnDef::new(Queue::PartySize).integer().not_null().default(1))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(QueuePartyMember::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(QueuePartyMember::QueueID).string().not_null())
+                    .col(ColumnDef::new(QueuePartyMember::CustomerID).string().not_null())
+                    .primary_key(
+                        Index::create()
+                            .col(QueuePartyMember::QueueID)
+                            .col(QueuePartyMember::CustomerID),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_queue_party_member_queue")
+                            .from(QueuePartyMember::Table, QueuePartyMember::QueueID)
+                            .to(Queue::Table, Queue::QueueID)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_queue_party_member_user")
+                            .from(QueuePartyMember::Table, QueuePartyMember::CustomerID)
+                            .to(User::Table, User::UserID),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_queue_party_member_customer")
+                    .table(QueuePartyMember::Table)
+                    .col(QueuePartyMember::CustomerID)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(QueuePartyMember::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Queue::Table)
+                    .drop_column(Queue::PartySize)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum QueuePartyMember {
+    Table,
+    QueueID,
+    CustomerID,
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Iden)]
+enum Queue {
+    Table,
+    QueueID,
+    PartySize,
+}
+
+#[derive(Iden)]
+enum User {
+    Table,
+    UserID,
+}
@@ -0,0 +1,188 @@
+use chrono::Utc;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// Free-form ride statuses written by the old forms and their place in the typed lifecycle.
+const STATUS_MAPPING: &[(&str, &str)] = &[("Open", "Operational")];
+
+const RIDE_STATUSES: [&str; 5] = ["Operational", "Closed", "Maintenance", "Weather Hold", "Under Construction"];
+const MAINTENANCE_STATUSES: [&str; 3] = ["Available", "Pending", "In Progress"];
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(RideStatusHistory::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(RideStatusHistory::HistoryID).string().not_null().primary_key())
+                    .col(ColumnDef::new(RideStatusHistory::RideID).string().not_null())
+                    .col(ColumnDef::new(RideStatusHistory::FromStatus).string().null())
+                    .col(ColumnDef::new(RideStatusHistory::ToStatus).string().not_null())
+                    .col(ColumnDef::new(RideStatusHistory::FromMaintenanceStatus).string().null())
+                    .col(ColumnDef::new(RideStatusHistory::ToMaintenanceStatus).string().not_null())
+                    .col(ColumnDef::new(RideStatusHistory::ActorID).string().null())
+                    .col(ColumnDef::new(RideStatusHistory::Reason).string().null())
+                    .col(ColumnDef::new(RideStatusHistory::ChangedAt).string().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_ride_status_history_ride")
+                            .from(RideStatusHistory::Table, RideStatusHistory::RideID)
+                            .to(Ride::Table, Ride::RideID)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_ride_status_history_user")
+                            .from(RideStatusHistory::Table, RideStatusHistory::ActorID)
+                            .to(User::Table, User::UserID),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_ride_status_history_ride")
+                    .table(RideStatusHistory::Table)
+                    .col(RideStatusHistory::RideID)
+                    .col(RideStatusHistory::ChangedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        let db = manager.get_connection();
+        let backend = manager.get_database_backend();
+
+        for (old, new) in STATUS_MAPPING {
+            db.execute(
+                backend.build(
+                    Query::update()
+                        .table(Ride::Table)
+                        .value(Ride::Status, *new)
+                        .and_where(Expr::col(Ride::Status).eq(*old))
+                ),
+            )
+            .await?;
+        }
+
+        // Anything else outside the lifecycle is treated as closed until someone reviews it.
+        db.execute(
+            backend.build(
+                Query::update()
+                    .table(Ride::Table)
+                    .value(Ride::Status, "Closed")
+                    .and_where(Expr::col(Ride::Status).is_not_in(RIDE_STATUSES))
+            ),
+        )
+        .await?;
+
+        db.execute(
+            backend.build(
+                Query::update()
+                    .table(Ride::Table)
+                    .value(Ride::MaintenanceStatus, "Available")
+                    .and_where(Expr::col(Ride::MaintenanceStatus).is_not_in(MAINTENANCE_STATUSES))
+            ),
+        )
+        .await?;
+
+        // Every ride starts its history with the state it is in today so downtime can be
+        // measured from the moment history begins.
+        let now = Utc::now().to_rfc3339();
+
+        let rows = db
+            .query_all(
+                backend.build(
+                    Query::select()
+                        .columns([Ride::RideID, Ride::Status, Ride::MaintenanceStatus])
+                        .from(Ride::Table)
+                ),
+            )
+            .await?;
+
+        for row in rows {
+            let ride_id: String = row.try_get("", "ride_id")?;
+            let status: String = row.try_get("", "status")?;
+            let maintenance_status: String = row.try_get("", "maintenance_status")?;
+
+            db.execute(
+                backend.build(
+                    Query::insert()
+                        .into_table(RideStatusHistory::Table)
+                        .columns([
+                            RideStatusHistory::HistoryID, RideStatusHistory::RideID, RideStatusHistory::FromStatus,
+                            RideStatusHistory::ToStatus, RideStatusHistory::FromMaintenanceStatus,
+                            RideStatusHistory::ToMaintenanceStatus, RideStatusHistory::ActorID,
+                            RideStatusHistory::Reason, RideStatusHistory::ChangedAt,
+                        ])
+                        .values_panic([
+                            format!("RSH-OPEN-{}", ride_id).into(), ride_id.into(), None::<String>.into(),
+                            status.into(), None::<String>.into(),
+                            maintenance_status.into(), None::<String>.into(),
+                            "Status recorded when ride status history was introduced".into(), now.clone().into(),
+                        ])
+                ),
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        let backend = manager.get_database_backend();
+
+        for (old, new) in STATUS_MAPPING {
+            db.execute(
+                backend.build(
+                    Query::update()
+                        .table(Ride::Table)
+                        .value(Ride::Status, *old)
+                        .and_where(Expr::col(Ride::Status).eq(*new))
+                ),
+            )
+            .await?;
+        }
+
+        manager
+            .drop_table(Table::drop().table(RideStatusHistory::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum RideStatusHistory {
+    Table,
+    HistoryID,
+    RideID,
+    FromStatus,
+    ToStatus,
+    FromMaintenanceStatus,
+    ToMaintenanceStatus,
+    ActorID,
+    Reason,
+    ChangedAt,
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Iden)]
+enum Ride {
+    Table,
+    RideID,
+    Status,
+    MaintenanceStatus,
+}
+
+#[derive(Iden)]
+enum User {
+    Table,
+    UserID,
+}
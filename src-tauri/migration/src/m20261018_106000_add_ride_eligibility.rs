@@ -0,0 +1,80 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Null limits mean the ride has no restriction of that kind.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Ride::Table)
+                    .add_column(ColumnDef::new(Ride::MinAge).integer().null())
+                    .add_column(ColumnDef::new(Ride::MinHeightCm).integer().null())
+                    .add_column(ColumnDef::new(Ride::MaxHeightCm).integer().null())
+                    .add_column(ColumnDef::new(Ride::WheelchairAccessible).boolean().not_null().default(true))
+                    .add_column(ColumnDef::new(Ride::HealthAdvisory).string().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // Measured by staff; a ride with a height limit turns away riders who have not been measured.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(User::Table)
+                    .add_column(ColumnDef::new(User::HeightCm).integer().null())
+                    .add_column(ColumnDef::new(User::UsesWheelchair).boolean().not_null().default(false))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(User::Table)
+                    .drop_column(User::HeightCm)
+                    .drop_column(User::UsesWheelchair)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Ride::Table)
+                    .drop_column(Ride::MinAge)
+                    .drop_column(Ride::MinHeightCm)
+                    .drop_column(Ride::MaxHeightCm)
+                    .drop_column(Ride::WheelchairAccessible)
+                    .drop_column(Ride::HealthAdvisory)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Ride {
+    Table,
+    MinAge,
+    MinHeightCm,
+    MaxHeightCm,
+    WheelchairAccessible,
+    HealthAdvisory,
+}
+
+#[derive(Iden)]
+enum User {
+    Table,
+    HeightCm,
+    UsesWheelchair,
+}
@@ -0,0 +1,126 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const TICKET_STATUSES: [&str; 5] = ["Pending", "Assigned", "In Progress", "Paused", "Completed"];
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Maintenance::Table)
+                    .add_column(ColumnDef::new(Maintenance::AssignedAt).string().null())
+                    .add_column(ColumnDef::new(Maintenance::StartedAt).string().null())
+                    .add_column(ColumnDef::new(Maintenance::CompletedAt).string().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(MaintenanceNote::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(MaintenanceNote::NoteID).string().not_null().primary_key())
+                    .col(ColumnDef::new(MaintenanceNote::MaintenanceID).string().not_null())
+                    .col(ColumnDef::new(MaintenanceNote::AuthorID).string().not_null())
+                    .col(ColumnDef::new(MaintenanceNote::FromStatus).string().null())
+                    .col(ColumnDef::new(MaintenanceNote::ToStatus).string().not_null())
+                    .col(ColumnDef::new(MaintenanceNote::Note).string().null())
+                    .col(ColumnDef::new(MaintenanceNote::CreatedAt).string().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_maintenance_note_maintenance")
+                            .from(MaintenanceNote::Table, MaintenanceNote::MaintenanceID)
+                            .to(Maintenance::Table, Maintenance::MaintenanceID)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_maintenance_note_user")
+                            .from(MaintenanceNote::Table, MaintenanceNote::AuthorID)
+                            .to(User::Table, User::UserID),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_maintenance_note_maintenance")
+                    .table(MaintenanceNote::Table)
+                    .col(MaintenanceNote::MaintenanceID)
+                    .col(MaintenanceNote::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Tickets written with statuses outside the workflow go back to the manager's inbox.
+        let db = manager.get_connection();
+        let backend = manager.get_database_backend();
+
+        db.execute(
+            backend.build(
+                Query::update()
+                    .table(Maintenance::Table)
+                    .value(Maintenance::Status, "Pending")
+                    .and_where(Expr::col(Maintenance::Status).is_not_in(TICKET_STATUSES))
+            ),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(MaintenanceNote::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Maintenance::Table)
+                    .drop_column(Maintenance::AssignedAt)
+                    .drop_column(Maintenance::StartedAt)
+                    .drop_column(Maintenance::CompletedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum MaintenanceNote {
+    Table,
+    NoteID,
+    MaintenanceID,
+    AuthorID,
+    FromStatus,
+    ToStatus,
+    Note,
+    CreatedAt,
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Iden)]
+enum Maintenance {
+    Table,
+    MaintenanceID,
+    Status,
+    AssignedAt,
+    StartedAt,
+    CompletedAt,
+}
+
+#[derive(Iden)]
+enum User {
+    Table,
+    UserID,
+}
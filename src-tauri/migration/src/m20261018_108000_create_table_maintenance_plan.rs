@@ -0,0 +1,144 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(MaintenancePlan::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(MaintenancePlan::PlanID).string().not_null().primary_key())
+                    .col(ColumnDef::new(MaintenancePlan::RideID).string().not_null())
+                    .col(ColumnDef::new(MaintenancePlan::Name).string().not_null())
+                    .col(ColumnDef::new(MaintenancePlan::Type).string().not_null())
+                    .col(ColumnDef::new(MaintenancePlan::Description).string().not_null())
+                    .col(ColumnDef::new(MaintenancePlan::Frequency).string().not_null())
+                    .col(ColumnDef::new(MaintenancePlan::FrequencyInterval).integer().not_null().default(1))
+                    .col(ColumnDef::new(MaintenancePlan::LeadHours).integer().not_null().default(0))
+                    .col(ColumnDef::new(MaintenancePlan::NextDueAt).string().not_null())
+                    .col(ColumnDef::new(MaintenancePlan::IsActive).boolean().not_null().default(true))
+                    .col(ColumnDef::new(MaintenancePlan::CreatedBy).string().not_null())
+                    .col(ColumnDef::new(MaintenancePlan::CreatedAt).string().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_maintenance_plan_ride")
+                            .from(MaintenancePlan::Table, MaintenancePlan::RideID)
+                            .to(Ride::Table, Ride::RideID)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_maintenance_plan_user")
+                            .from(MaintenancePlan::Table, MaintenancePlan::CreatedBy)
+                            .to(User::Table, User::UserID),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_maintenance_plan_due")
+                    .table(MaintenancePlan::Table)
+                    .col(MaintenancePlan::IsActive)
+                    .col(MaintenancePlan::NextDueAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Tickets raised from a plan keep a link to it and the time the work is due.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Maintenance::Table)
+                    .add_column(ColumnDef::new(Maintenance::PlanID).string().null())
+                    .add_column(ColumnDef::new(Maintenance::DueAt).string().null())
+                    .add_column(ColumnDef::new(Maintenance::IsOverdue).boolean().not_null().default(false))
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_maintenance_plan")
+                            .from_tbl(Maintenance::Table)
+                            .from_col(Maintenance::PlanID)
+                            .to_tbl(MaintenancePlan::Table)
+                            .to_col(MaintenancePlan::PlanID)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_maintenance_plan_id")
+                    .table(Maintenance::Table)
+                    .col(Maintenance::PlanID)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Maintenance::Table)
+                    .drop_foreign_key(Alias::new("fk_maintenance_plan"))
+                    .drop_column(Maintenance::PlanID)
+                    .drop_column(Maintenance::DueAt)
+                    .drop_column(Maintenance::IsOverdue)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(MaintenancePlan::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum MaintenancePlan {
+    Table,
+    PlanID,
+    RideID,
+    Name,
+    Type,
+    Description,
+    Frequency,
+    FrequencyInterval,
+    LeadHours,
+    NextDueAt,
+    IsActive,
+    CreatedBy,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+enum Maintenance {
+    Table,
+    PlanID,
+    DueAt,
+    IsOverdue,
+}
+
+#[derive(Iden)]
+enum Ride {
+    Table,
+    RideID,
+}
+
+#[derive(Iden)]
+enum User {
+    Table,
+    UserID,
+}
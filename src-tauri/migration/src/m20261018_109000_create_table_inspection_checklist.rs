@@ -0,0 +1,208 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Checklist templates are matched to rides by this category, e.g. "Roller Coaster".
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Ride::Table)
+                    .add_column(ColumnDef::new(Ride::RideType).string().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(ChecklistTemplate::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(ChecklistTemplate::TemplateID).string().not_null().primary_key())
+                    .col(ColumnDef::new(ChecklistTemplate::Name).string().not_null())
+                    .col(ColumnDef::new(ChecklistTemplate::RideType).string().null())
+                    .col(ColumnDef::new(ChecklistTemplate::MaintenanceType).string().null())
+                    .col(ColumnDef::new(ChecklistTemplate::IsActive).boolean().not_null().default(true))
+                    .col(ColumnDef::new(ChecklistTemplate::CreatedBy).string().not_null())
+                    .col(ColumnDef::new(ChecklistTemplate::CreatedAt).string().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_checklist_template_user")
+                            .from(ChecklistTemplate::Table, ChecklistTemplate::CreatedBy)
+                            .to(User::Table, User::UserID),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(ChecklistTemplateItem::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(ChecklistTemplateItem::ItemID).string().not_null().primary_key())
+                    .col(ColumnDef::new(ChecklistTemplateItem::TemplateID).string().not_null())
+                    .col(ColumnDef::new(ChecklistTemplateItem::Position).integer().not_null())
+                    .col(ColumnDef::new(ChecklistTemplateItem::Label).string().not_null())
+                    .col(ColumnDef::new(ChecklistTemplateItem::Kind).string().not_null())
+                    .col(ColumnDef::new(ChecklistTemplateItem::Required).boolean().not_null().default(true))
+                    .col(ColumnDef::new(ChecklistTemplateItem::MinValue).double().null())
+                    .col(ColumnDef::new(ChecklistTemplateItem::MaxValue).double().null())
+                    .col(ColumnDef::new(ChecklistTemplateItem::Unit).string().null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_checklist_template_item_template")
+                            .from(ChecklistTemplateItem::Table, ChecklistTemplateItem::TemplateID)
+                            .to(ChecklistTemplate::Table, ChecklistTemplate::TemplateID)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Results copy the item's definition so later template edits don't rewrite past inspections.
+        manager
+            .create_table(
+                Table::create()
+                    .table(ChecklistResult::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(ChecklistResult::ResultID).string().not_null().primary_key())
+                    .col(ColumnDef::new(ChecklistResult::MaintenanceID).string().not_null())
+                    .col(ColumnDef::new(ChecklistResult::TemplateName).string().not_null())
+                    .col(ColumnDef::new(ChecklistResult::Position).integer().not_null())
+                    .col(ColumnDef::new(ChecklistResult::Label).string().not_null())
+                    .col(ColumnDef::new(ChecklistResult::Kind).string().not_null())
+                    .col(ColumnDef::new(ChecklistResult::Required).boolean().not_null())
+                    .col(ColumnDef::new(ChecklistResult::MinValue).double().null())
+                    .col(ColumnDef::new(ChecklistResult::MaxValue).double().null())
+                    .col(ColumnDef::new(ChecklistResult::Unit).string().null())
+                    .col(ColumnDef::new(ChecklistResult::Status).string().not_null())
+                    .col(ColumnDef::new(ChecklistResult::MeasuredValue).double().null())
+                    .col(ColumnDef::new(ChecklistResult::Photo).binary().null())
+                    .col(ColumnDef::new(ChecklistResult::Comment).string().null())
+                    .col(ColumnDef::new(ChecklistResult::RecordedBy).string().null())
+                    .col(ColumnDef::new(ChecklistResult::RecordedAt).string().null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_checklist_result_maintenance")
+                            .from(ChecklistResult::Table, ChecklistResult::MaintenanceID)
+                            .to(Maintenance::Table, Maintenance::MaintenanceID)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_checklist_result_user")
+                            .from(ChecklistResult::Table, ChecklistResult::RecordedBy)
+                            .to(User::Table, User::UserID),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_checklist_result_maintenance")
+                    .table(ChecklistResult::Table)
+                    .col(ChecklistResult::MaintenanceID)
+                    .col(ChecklistResult::Position)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ChecklistResult::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(ChecklistTemplateItem::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(ChecklistTemplate::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Ride::Table)
+                    .drop_column(Ride::RideType)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum ChecklistTemplate {
+    Table,
+    TemplateID,
+    Name,
+    RideType,
+    MaintenanceType,
+    IsActive,
+    CreatedBy,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+enum ChecklistTemplateItem {
+    Table,
+    ItemID,
+    TemplateID,
+    Position,
+    Label,
+    Kind,
+    Required,
+    MinValue,
+    MaxValue,
+    Unit,
+}
+
+#[derive(Iden)]
+enum ChecklistResult {
+    Table,
+    ResultID,
+    MaintenanceID,
+    TemplateName,
+    Position,
+    Label,
+    Kind,
+    Required,
+    MinValue,
+    MaxValue,
+    Unit,
+    Status,
+    MeasuredValue,
+    Photo,
+    Comment,
+    RecordedBy,
+    RecordedAt,
+}
+
+#[derive(Iden)]
+enum Maintenance {
+    Table,
+    MaintenanceID,
+}
+
+#[derive(Iden)]
+enum Ride {
+    Table,
+    RideType,
+}
+
+#[derive(Iden)]
+enum User {
+    Table,
+    UserID,
+}
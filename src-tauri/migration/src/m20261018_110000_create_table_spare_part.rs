@@ -0,0 +1,142 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // The open purchase proposal is kept on the part so low stock raises it only once.
+        manager
+            .create_table(
+                Table::create()
+                    .table(SparePart::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(SparePart::PartID).string().not_null().primary_key())
+                    .col(ColumnDef::new(SparePart::PartNumber).string().not_null().unique_key())
+                    .col(ColumnDef::new(SparePart::Name).string().not_null())
+                    .col(ColumnDef::new(SparePart::Description).string().null())
+                    .col(ColumnDef::new(SparePart::Unit).string().not_null())
+                    .col(ColumnDef::new(SparePart::UnitCost).double().not_null())
+                    .col(ColumnDef::new(SparePart::StockQuantity).integer().not_null().default(0))
+                    .col(ColumnDef::new(SparePart::ReorderThreshold).integer().not_null().default(0))
+                    .col(ColumnDef::new(SparePart::ReorderQuantity).integer().not_null().default(1))
+                    .col(ColumnDef::new(SparePart::ReorderProposalID).string().null())
+                    .col(ColumnDef::new(SparePart::IsActive).boolean().not_null().default(true))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_spare_part_proposal")
+                            .from(SparePart::Table, SparePart::ReorderProposalID)
+                            .to(Proposal::Table, Proposal::ProposalID)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(MaintenancePartUsage::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(MaintenancePartUsage::UsageID).string().not_null().primary_key())
+                    .col(ColumnDef::new(MaintenancePartUsage::MaintenanceID).string().not_null())
+                    .col(ColumnDef::new(MaintenancePartUsage::PartID).string().not_null())
+                    .col(ColumnDef::new(MaintenancePartUsage::Quantity).integer().not_null())
+                    .col(ColumnDef::new(MaintenancePartUsage::UnitCost).double().not_null())
+                    .col(ColumnDef::new(MaintenancePartUsage::UsedBy).string().not_null())
+                    .col(ColumnDef::new(MaintenancePartUsage::UsedAt).string().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_maintenance_part_usage_maintenance")
+                            .from(MaintenancePartUsage::Table, MaintenancePartUsage::MaintenanceID)
+                            .to(Maintenance::Table, Maintenance::MaintenanceID)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_maintenance_part_usage_part")
+                            .from(MaintenancePartUsage::Table, MaintenancePartUsage::PartID)
+                            .to(SparePart::Table, SparePart::PartID),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_maintenance_part_usage_user")
+                            .from(MaintenancePartUsage::Table, MaintenancePartUsage::UsedBy)
+                            .to(User::Table, User::UserID),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_maintenance_part_usage_maintenance")
+                    .table(MaintenancePartUsage::Table)
+                    .col(MaintenancePartUsage::MaintenanceID)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(MaintenancePartUsage::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(SparePart::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum SparePart {
+    Table,
+    PartID,
+    PartNumber,
+    Name,
+    Description,
+    Unit,
+    UnitCost,
+    StockQuantity,
+    ReorderThreshold,
+    ReorderQuantity,
+    ReorderProposalID,
+    IsActive,
+}
+
+#[derive(Iden)]
+enum MaintenancePartUsage {
+    Table,
+    UsageID,
+    MaintenanceID,
+    PartID,
+    Quantity,
+    UnitCost,
+    UsedBy,
+    UsedAt,
+}
+
+#[derive(Iden)]
+enum Maintenance {
+    Table,
+    MaintenanceID,
+}
+
+#[derive(Iden)]
+enum Proposal {
+    Table,
+    ProposalID,
+}
+
+#[derive(Iden)]
+enum User {
+    Table,
+    UserID,
+}
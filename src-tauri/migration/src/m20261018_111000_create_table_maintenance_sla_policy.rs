@@ -0,0 +1,136 @@
+use chrono::{NaiveDateTime, SecondsFormat};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// Starting targets for the priorities the ride manager screen raises tickets with, in minutes:
+// (type, response, resolution, escalation to the COO after a breach).
+const DEFAULT_POLICIES: [(&str, i32, i32, i32); 4] = [
+    ("Critical", 15, 240, 15),
+    ("High", 60, 480, 60),
+    ("Medium", 240, 1440, 240),
+    ("Low", 1440, 4320, 1440),
+];
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(MaintenanceSlaPolicy::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(MaintenanceSlaPolicy::MaintenanceType).string().not_null().primary_key())
+                    .col(ColumnDef::new(MaintenanceSlaPolicy::ResponseMinutes).integer().not_null())
+                    .col(ColumnDef::new(MaintenanceSlaPolicy::ResolutionMinutes).integer().not_null())
+                    .col(ColumnDef::new(MaintenanceSlaPolicy::EscalationMinutes).integer().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        let mut seed = Query::insert()
+            .into_table(MaintenanceSlaPolicy::Table)
+            .columns([
+                MaintenanceSlaPolicy::MaintenanceType,
+                MaintenanceSlaPolicy::ResponseMinutes,
+                MaintenanceSlaPolicy::ResolutionMinutes,
+                MaintenanceSlaPolicy::EscalationMinutes,
+            ])
+            .to_owned();
+        for (maintenance_type, response, resolution, escalation) in DEFAULT_POLICIES {
+            seed.values_panic([maintenance_type.into(), response.into(), resolution.into(), escalation.into()]);
+        }
+        manager.exec_stmt(seed).await?;
+
+        // `date` is a display string, so tickets get a sortable RFC 3339 report time for SLA
+        // clocks, and a record of how far their breach has been escalated.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Maintenance::Table)
+                    .add_column(ColumnDef::new(Maintenance::ReportedAt).string().null())
+                    .add_column(ColumnDef::new(Maintenance::EscalationLevel).integer().not_null().default(0))
+                    .add_column(ColumnDef::new(Maintenance::EscalatedAt).string().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        let db = manager.get_connection();
+        let backend = manager.get_database_backend();
+
+        let rows = db
+            .query_all(
+                backend.build(
+                    Query::select()
+                        .columns([Maintenance::MaintenanceID, Maintenance::Date])
+                        .from(Maintenance::Table),
+                ),
+            )
+            .await?;
+
+        // Existing tickets were stamped in UTC; any that can't be read are left without an SLA.
+        for row in rows {
+            let maintenance_id: String = row.try_get("", "maintenance_id")?;
+            let date: String = row.try_get("", "date")?;
+
+            let Ok(reported_at) = NaiveDateTime::parse_from_str(&date, "%Y-%m-%d %H:%M:%S") else {
+                continue;
+            };
+
+            db.execute(
+                backend.build(
+                    Query::update()
+                        .table(Maintenance::Table)
+                        .value(
+                            Maintenance::ReportedAt,
+                            reported_at.and_utc().to_rfc3339_opts(SecondsFormat::Secs, false),
+                        )
+                        .and_where(Expr::col(Maintenance::MaintenanceID).eq(maintenance_id)),
+                ),
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Maintenance::Table)
+                    .drop_column(Maintenance::ReportedAt)
+                    .drop_column(Maintenance::EscalationLevel)
+                    .drop_column(Maintenance::EscalatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(MaintenanceSlaPolicy::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum MaintenanceSlaPolicy {
+    Table,
+    MaintenanceType,
+    ResponseMinutes,
+    ResolutionMinutes,
+    EscalationMinutes,
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Iden)]
+enum Maintenance {
+    Table,
+    MaintenanceID,
+    Date,
+    ReportedAt,
+    EscalationLevel,
+    EscalatedAt,
+}
@@ -0,0 +1,63 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// Must stay in step with `RideStatus` and `RideMaintenanceStatus` in the entity crate.
+const RIDE_STATUSES: [&str; 5] = ["Operational", "Closed", "Maintenance", "Weather Hold", "Under Construction"];
+const MAINTENANCE_STATUSES: [&str; 3] = ["Available", "Pending", "In Progress"];
+
+fn check_constraint(name: &str, column: &str, values: &[&str]) -> String {
+    let allowed = values.iter().map(|value| format!("'{}'", value)).collect::<Vec<_>>().join(", ");
+    format!(r#"ALTER TABLE "ride" ADD CONSTRAINT "{}" CHECK ("{}" IN ({}))"#, name, column, allowed)
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        // Rows written outside the typed lifecycle would fail the constraints below.
+        db.execute(
+            manager.get_database_backend().build(
+                Query::update()
+                    .table(Ride::Table)
+                    .value(Ride::Status, "Closed")
+                    .and_where(Expr::col(Ride::Status).is_not_in(RIDE_STATUSES))
+            ),
+        )
+        .await?;
+
+        db.execute(
+            manager.get_database_backend().build(
+                Query::update()
+                    .table(Ride::Table)
+                    .value(Ride::MaintenanceStatus, "Available")
+                    .and_where(Expr::col(Ride::MaintenanceStatus).is_not_in(MAINTENANCE_STATUSES))
+            ),
+        )
+        .await?;
+
+        db.execute_unprepared(&check_constraint("chk_ride_status", "status", &RIDE_STATUSES)).await?;
+        db.execute_unprepared(&check_constraint("chk_ride_maintenance_status", "maintenance_status", &MAINTENANCE_STATUSES))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared(r#"ALTER TABLE "ride" DROP CONSTRAINT IF EXISTS "chk_ride_maintenance_status""#).await?;
+        db.execute_unprepared(r#"ALTER TABLE "ride" DROP CONSTRAINT IF EXISTS "chk_ride_status""#).await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Ride {
+    Table,
+    Status,
+    MaintenanceStatus,
+}
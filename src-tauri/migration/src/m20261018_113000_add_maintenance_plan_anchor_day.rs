@@ -0,0 +1,49 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(MaintenancePlan::Table)
+                    .add_column(ColumnDef::new(MaintenancePlan::AnchorDay).integer().not_null().default(1))
+                    .to_owned(),
+            )
+            .await?;
+
+        // Existing plans anchor on the day they are next due; a plan already clamped to the end
+        // of a short month can't be told apart from one created on that day.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "UPDATE maintenance_plan \
+                 SET anchor_day = EXTRACT(DAY FROM (next_due_at::timestamptz AT TIME ZONE 'UTC'))::integer",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(MaintenancePlan::Table)
+                    .drop_column(MaintenancePlan::AnchorDay)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum MaintenancePlan {
+    Table,
+    AnchorDay,
+}
@@ -1,4 +1,5 @@
 use crate::AppState;
+use crate::controllers::session_handler::authorize;
 use firestore::FirestoreDb;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -23,8 +24,11 @@ pub struct BroadcastPayload {
 #[tauri::command]
 pub async fn send_broadcast_message(
     state: State<'_, AppState>,
+    session_token: String,
     payload: BroadcastPayload,
 ) -> Result<(), String> {
+    authorize(&state, &session_token, "send_broadcast_message").await?;
+
     let firestore = {
         let lock = state.firestore.lock().await;
         lock.clone().ok_or("Firestore not initialized")?
@@ -1,4 +1,5 @@
 use crate::AppState;
+use crate::controllers::session_handler::authorize;
 use firestore::{
     FirestoreDb, FirestoreListenEvent, FirestoreListenerTarget, 
     FirestoreMemListenStateStorage, FirestoreGetByIdSupport
@@ -18,8 +19,11 @@ pub struct GroupInfo {
 #[tauri::command]
 pub async fn fetch_group_info(
     state: State<'_, AppState>,
+    session_token: String,
     group_id: String,
 ) -> Result<GroupInfo, String> {
+    authorize(&state, &session_token, "fetch_group_info").await?;
+
     let firestore = {
         let fs_lock = state.firestore.lock().await;
         fs_lock.clone().ok_or("Firestore instance not initialized")?
@@ -34,7 +38,12 @@ pub async fn fetch_group_info(
 }
 
 #[tauri::command]
-pub async fn get_all_groups(state: State<'_, AppState>) -> Result<Vec<GroupInfo>, String> {
+pub async fn get_all_groups(
+    state: State<'_, AppState>,
+    session_token: String,
+) -> Result<Vec<GroupInfo>, String> {
+    authorize(&state, &session_token, "get_all_groups").await?;
+
     let firestore = {
         let fs = state.firestore.lock().await;
         fs.clone().ok_or("Firestore not initialized")?
@@ -55,10 +64,16 @@ pub async fn get_all_groups(state: State<'_, AppState>) -> Result<Vec<GroupInfo>
 #[tauri::command]
 pub async fn send_group_message(
     state: State<'_, AppState>,
+    session_token: String,
     group_id: String,
     sender_id: String,
     content: String,
 ) -> Result<(), String> {
+    let session = authorize(&state, &session_token, "send_group_message").await?;
+    if sender_id != session.user_id {
+        return Err("Sender does not match the signed-in user".to_string());
+    }
+
     let firestore = {
         let fs_lock = state.firestore.lock().await;
         fs_lock.clone().ok_or("Firestore instance not initialized")?
@@ -110,8 +125,11 @@ pub struct GroupChatMessage {
 pub async fn listen_to_group_chat(
     app: AppHandle,
     state: State<'_, AppState>,
+    session_token: String,
     group_id: String,
 ) -> Result<(), String> {
+    authorize(&state, &session_token, "listen_to_group_chat").await?;
+
     let firestore = {
         let fs_lock = state.firestore.lock().await;
         fs_lock.clone().ok_or("Firestore instance not initialized")?
@@ -208,8 +226,11 @@ pub async fn listen_to_group_chat(
 #[tauri::command]
 pub async fn fetch_group_chat_messages(
     state: State<'_, AppState>,
+    session_token: String,
     group_id: String,
 ) -> Result<Vec<GroupChatMessage>, String> {
+    authorize(&state, &session_token, "fetch_group_chat_messages").await?;
+
     let firestore = {
         let fs_lock = state.firestore.lock().await;
         fs_lock.clone().ok_or("Firestore instance not initialized")?
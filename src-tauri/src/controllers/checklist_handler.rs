@@ -0,0 +1,595 @@
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use chrono::Utc;
+use entity::checklist_result::{self, Entity as ChecklistResult};
+use entity::checklist_template::{self, Entity as ChecklistTemplate};
+use entity::checklist_template_item::{self, Entity as ChecklistTemplateItem};
+use entity::maintenance::{self, Entity as Maintenance};
+use entity::ride::Entity as Ride;
+use entity::sea_orm_active_enums::{ChecklistItemKind, ChecklistResultStatus, MaintenanceStatus};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect, TransactionTrait, ActiveValue::Set,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tauri::State;
+use uuid::Uuid;
+use crate::{ApiResponse, AppState};
+use crate::controllers::session_handler::authorize;
+
+// Copies every active template that fits the ticket's ride type and maintenance type onto the
+// ticket. Does nothing if the ticket already has its checklist.
+pub async fn attach_checklists<C: ConnectionTrait>(db: &C, ticket: &maintenance::Model) -> Result<(), String> {
+    let existing = ChecklistResult::find()
+        .filter(checklist_result::Column::MaintenanceId.eq(&ticket.maintenance_id))
+        .count(db)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    if existing > 0 {
+        return Ok(());
+    }
+
+    let ride_type = Ride::find_by_id(ticket.ride_id.clone())
+        .one(db)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .and_then(|ride| ride.ride_type);
+
+    let mut ride_type_matches = Condition::any().add(checklist_template::Column::RideType.is_null());
+    if let Some(ride_type) = ride_type {
+        ride_type_matches = ride_type_matches.add(checklist_template::Column::RideType.eq(ride_type));
+    }
+
+    let templates = ChecklistTemplate::find()
+        .filter(checklist_template::Column::IsActive.eq(true))
+        .filter(ride_type_matches)
+        .filter(
+            Condition::any()
+                .add(checklist_template::Column::MaintenanceType.is_null())
+                .add(checklist_template::Column::MaintenanceType.eq(&ticket.r#type)),
+        )
+        .order_by_asc(checklist_template::Column::Name)
+        .find_with_related(ChecklistTemplateItem)
+        .all(db)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let mut position = 0;
+    for (template, mut items) in templates {
+        items.sort_by_key(|item| item.position);
+
+        for item in items {
+            position += 1;
+            checklist_result::ActiveModel {
+                result_id: Set(format!("CR-{}", Uuid::new_v4())),
+                maintenance_id: Set(ticket.maintenance_id.clone()),
+                template_name: Set(template.name.clone()),
+                position: Set(position),
+                label: Set(item.label),
+                kind: Set(item.kind),
+                required: Set(item.required),
+                min_value: Set(item.min_value),
+                max_value: Set(item.max_value),
+                unit: Set(item.unit),
+                status: Set(ChecklistResultStatus::Pending),
+                measured_value: Set(None),
+                photo: Set(None),
+                comment: Set(None),
+                recorded_by: Set(None),
+                recorded_at: Set(None),
+            }
+            .insert(db)
+            .await
+            .map_err(|e| format!("Failed to attach checklist: {}", e))?;
+        }
+    }
+
+    Ok(())
+}
+
+// Required items on the ticket that are still unrecorded or have failed.
+pub async fn unresolved_required_items<C: ConnectionTrait>(db: &C, maintenance_id: &str) -> Result<Vec<String>, String> {
+    ChecklistResult::find()
+        .select_only()
+        .column(checklist_result::Column::Label)
+        .filter(checklist_result::Column::MaintenanceId.eq(maintenance_id))
+        .filter(checklist_result::Column::Required.eq(true))
+        .filter(checklist_result::Column::Status.ne(ChecklistResultStatus::Passed))
+        .order_by_asc(checklist_result::Column::Position)
+        .into_tuple()
+        .all(db)
+        .await
+        .map_err(|e| format!("Database error: {}", e))
+}
+
+// Failed required items on the ride's unfinished tickets; while any remain the ride stays shut.
+pub async fn failed_required_items<C: ConnectionTrait>(db: &C, ride_id: &str) -> Result<Vec<String>, String> {
+    ChecklistResult::find()
+        .select_only()
+        .column(checklist_result::Column::Label)
+        .inner_join(Maintenance)
+        .filter(maintenance::Column::RideId.eq(ride_id))
+        .filter(maintenance::Column::Status.ne(MaintenanceStatus::Completed))
+        .filter(checklist_result::Column::Required.eq(true))
+        .filter(checklist_result::Column::Status.eq(ChecklistResultStatus::Failed))
+        .into_tuple()
+        .all(db)
+        .await
+        .map_err(|e| format!("Database error: {}", e))
+}
+
+#[derive(Serialize)]
+pub struct ChecklistTemplateResponse {
+    #[serde(flatten)]
+    pub template: checklist_template::Model,
+    pub items: Vec<checklist_template_item::Model>,
+}
+
+#[tauri::command]
+pub async fn view_checklist_templates(
+    state: State<'_, AppState>,
+    session_token: String,
+) -> Result<ApiResponse<Vec<ChecklistTemplateResponse>>, String> {
+    if let Err(message) = authorize(&state, &session_token, "view_checklist_templates").await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    match ChecklistTemplate::find()
+        .order_by_asc(checklist_template::Column::Name)
+        .find_with_related(ChecklistTemplateItem)
+        .all(&state.db)
+        .await
+    {
+        Ok(templates) => Ok(ApiResponse::success(
+            templates
+                .into_iter()
+                .map(|(template, mut items)| {
+                    items.sort_by_key(|item| item.position);
+                    ChecklistTemplateResponse { template, items }
+                })
+                .collect(),
+        )),
+        Err(err) => Ok(ApiResponse::error(format!("Database error: {}", err))),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct ChecklistItemRequest {
+    pub label: String,
+    pub kind: ChecklistItemKind,
+    pub required: Option<bool>,
+    pub min_value: Option<f64>,
+    pub max_value: Option<f64>,
+    pub unit: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct CreateChecklistTemplateRequest {
+    pub name: String,
+    // Either left empty applies the template to every ride or ticket type.
+    pub ride_type: Option<String>,
+    pub maintenance_type: Option<String>,
+    pub items: Vec<ChecklistItemRequest>,
+}
+
+fn validate_item(item: &ChecklistItemRequest) -> Result<(), String> {
+    if item.label.trim().is_empty() {
+        return Err("Every checklist item needs a label".to_string());
+    }
+
+    let bounds = [item.min_value, item.max_value];
+    if item.kind != ChecklistItemKind::Measurement && bounds.iter().any(Option::is_some) {
+        return Err(format!("Only measurements can have limits ({})", item.label));
+    }
+
+    if bounds.iter().flatten().any(|bound| !bound.is_finite()) {
+        return Err(format!("Limits must be numbers ({})", item.label));
+    }
+
+    if let (Some(min_value), Some(max_value)) = (item.min_value, item.max_value) {
+        if min_value > max_value {
+            return Err(format!("Minimum is above the maximum ({})", item.label));
+        }
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn create_checklist_template(
+    state: State<'_, AppState>,
+    session_token: String,
+    payload: CreateChecklistTemplateRequest,
+) -> Result<ApiResponse<ChecklistTemplateResponse>, String> {
+    let session = match authorize(&state, &session_token, "create_checklist_template").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    if payload.items.is_empty() {
+        return Ok(ApiResponse::error("A checklist needs at least one item".to_string()));
+    }
+
+    if let Some(message) = payload.items.iter().find_map(|item| validate_item(item).err()) {
+        return Ok(ApiResponse::error(message));
+    }
+
+    let txn = match state.db.begin().await {
+        Ok(txn) => txn,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    let template = match (checklist_template::ActiveModel {
+        template_id: Set(format!("CT-{}", Uuid::new_v4())),
+        name: Set(payload.name),
+        ride_type: Set(payload.ride_type.filter(|ride_type| !ride_type.trim().is_empty())),
+        maintenance_type: Set(payload.maintenance_type.filter(|maintenance_type| !maintenance_type.trim().is_empty())),
+        is_active: Set(true),
+        created_by: Set(session.user_id),
+        created_at: Set(Utc::now().to_rfc3339()),
+    })
+    .insert(&txn)
+    .await
+    {
+        Ok(template) => template,
+        Err(err) => return Ok(ApiResponse::error(format!("Failed to create checklist: {}", err))),
+    };
+
+    let mut items = Vec::new();
+    for (position, item) in payload.items.into_iter().enumerate() {
+        match (checklist_template_item::ActiveModel {
+            item_id: Set(format!("CTI-{}", Uuid::new_v4())),
+            template_id: Set(template.template_id.clone()),
+            position: Set(position as i32 + 1),
+            label: Set(item.label),
+            kind: Set(item.kind),
+            required: Set(item.required.unwrap_or(true)),
+            min_value: Set(item.min_value),
+            max_value: Set(item.max_value),
+            unit: Set(item.unit),
+        })
+        .insert(&txn)
+        .await
+        {
+            Ok(item) => items.push(item),
+            Err(err) => return Ok(ApiResponse::error(format!("Failed to create checklist: {}", err))),
+        }
+    }
+
+    if let Err(err) = txn.commit().await {
+        return Ok(ApiResponse::error(format!("Failed to create checklist: {}", err)));
+    }
+
+    Ok(ApiResponse::success(ChecklistTemplateResponse { template, items }))
+}
+
+#[derive(Deserialize)]
+pub struct SetChecklistTemplateActiveRequest {
+    pub template_id: String,
+    pub is_active: bool,
+}
+
+// Retired templates stop being attached to new tickets; checklists already attached are kept.
+#[tauri::command]
+pub async fn set_checklist_template_active(
+    state: State<'_, AppState>,
+    session_token: String,
+    payload: SetChecklistTemplateActiveRequest,
+) -> Result<ApiResponse<checklist_template::Model>, String> {
+    if let Err(message) = authorize(&state, &session_token, "set_checklist_template_active").await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    let template = match ChecklistTemplate::find_by_id(payload.template_id.clone()).one(&state.db).await {
+        Ok(Some(template)) => template,
+        Ok(None) => return Ok(ApiResponse::error(format!("No checklist found with ID: {}", payload.template_id))),
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    let mut active_template: checklist_template::ActiveModel = template.into();
+    active_template.is_active = Set(payload.is_active);
+
+    match active_template.update(&state.db).await {
+        Ok(template) => Ok(ApiResponse::success(template)),
+        Err(err) => Ok(ApiResponse::error(format!("Failed to update checklist: {}", err))),
+    }
+}
+
+#[derive(Serialize)]
+pub struct ChecklistResultResponse {
+    pub result_id: String,
+    pub template_name: String,
+    pub position: i32,
+    pub label: String,
+    pub kind: ChecklistItemKind,
+    pub required: bool,
+    pub min_value: Option<f64>,
+    pub max_value: Option<f64>,
+    pub unit: Option<String>,
+    pub status: ChecklistResultStatus,
+    pub measured_value: Option<f64>,
+    pub photo: Option<String>,
+    pub comment: Option<String>,
+    pub recorded_by: Option<String>,
+    pub recorded_at: Option<String>,
+}
+
+impl From<checklist_result::Model> for ChecklistResultResponse {
+    fn from(result: checklist_result::Model) -> Self {
+        ChecklistResultResponse {
+            result_id: result.result_id,
+            template_name: result.template_name,
+            position: result.position,
+            label: result.label,
+            kind: result.kind,
+            required: result.required,
+            min_value: result.min_value,
+            max_value: result.max_value,
+            unit: result.unit,
+            status: result.status,
+            measured_value: result.measured_value,
+            photo: result.photo.as_deref().map(|photo| STANDARD.encode(photo)),
+            comment: result.comment,
+            recorded_by: result.recorded_by,
+            recorded_at: result.recorded_at,
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn view_maintenance_checklist(
+    state: State<'_, AppState>,
+    session_token: String,
+    maintenance_id: String,
+) -> Result<ApiResponse<Vec<ChecklistResultResponse>>, String> {
+    if let Err(message) = authorize(&state, &session_token, "view_maintenance_checklist").await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    match ChecklistResult::find()
+        .filter(checklist_result::Column::MaintenanceId.eq(&maintenance_id))
+        .order_by_asc(checklist_result::Column::Position)
+        .all(&state.db)
+        .await
+    {
+        Ok(results) => Ok(ApiResponse::success(results.into_iter().map(Into::into).collect())),
+        Err(err) => Ok(ApiResponse::error(format!("Database error: {}", err))),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct RecordChecklistResultRequest {
+    pub result_id: String,
+    // Ticks for checks; for photos, an explicit false records a photographed defect.
+    pub passed: Option<bool>,
+    pub measured_value: Option<f64>,
+    pub photo: Option<Vec<u8>>,
+    pub comment: Option<String>,
+}
+
+fn evaluate(result: &checklist_result::Model, payload: &RecordChecklistResultRequest) -> Result<ChecklistResultStatus, String> {
+    let outcome = |passed: bool| if passed { ChecklistResultStatus::Passed } else { ChecklistResultStatus::Failed };
+
+    match result.kind {
+        ChecklistItemKind::Check => payload
+            .passed
+            .map(outcome)
+            .ok_or_else(|| format!("{} must be marked as passed or failed", result.label)),
+        ChecklistItemKind::Measurement => {
+            let value = payload
+                .measured_value
+                .filter(|value| value.is_finite())
+                .ok_or_else(|| format!("{} needs a measured value", result.label))?;
+            let within = result.min_value.is_none_or(|min_value| value >= min_value)
+                && result.max_value.is_none_or(|max_value| value <= max_value);
+            Ok(outcome(within))
+        }
+        ChecklistItemKind::Photo => {
+            if payload.photo.as_ref().is_none_or(Vec::is_empty) && result.photo.is_none() {
+                return Err(format!("{} needs a photo", result.label));
+            }
+            Ok(outcome(payload.passed.unwrap_or(true)))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn record_checklist_result(
+    state: State<'_, AppState>,
+    session_token: String,
+    payload: RecordChecklistResultRequest,
+) -> Result<ApiResponse<ChecklistResultResponse>, String> {
+    let session = match authorize(&state, &session_token, "record_checklist_result").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    let txn = match state.db.begin().await {
+        Ok(txn) => txn,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    let maintenance_id = match ChecklistResult::find_by_id(payload.result_id.clone()).one(&txn).await {
+        Ok(Some(result)) => result.maintenance_id,
+        Ok(None) => return Ok(ApiResponse::error(format!("No checklist item found with ID: {}", payload.result_id))),
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    // The ticket row is locked before the item is read again, so results recorded at the same
+    // time, and the completion that reopens the ride, all see each other's writes.
+    let ticket = match Maintenance::find_by_id(maintenance_id).lock_exclusive().one(&txn).await {
+        Ok(Some(ticket)) => ticket,
+        Ok(None) => return Ok(ApiResponse::error(format!("No checklist item found with ID: {}", payload.result_id))),
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    let result = match ChecklistResult::find_by_id(payload.result_id.clone()).lock_exclusive().one(&txn).await {
+        Ok(Some(result)) => result,
+        Ok(None) => return Ok(ApiResponse::error(format!("No checklist item found with ID: {}", payload.result_id))),
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    if ticket.maintenance_staff_id.as_deref() != Some(session.user_id.as_str()) {
+        return Ok(ApiResponse::error("Only the assigned maintenance staff can fill in this checklist".to_string()));
+    }
+
+    if ticket.status != MaintenanceStatus::InProgress {
+        return Ok(ApiResponse::error("Checklist items can only be recorded while work is in progress".to_string()));
+    }
+
+    let status = match evaluate(&result, &payload) {
+        Ok(status) => status,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    let mut active_result: checklist_result::ActiveModel = result.into();
+    active_result.status = Set(status);
+    active_result.measured_value = Set(payload.measured_value);
+    if let Some(photo) = payload.photo.filter(|photo| !photo.is_empty()) {
+        active_result.photo = Set(Some(photo));
+    }
+    active_result.comment = Set(payload.comment);
+    active_result.recorded_by = Set(Some(session.user_id));
+    active_result.recorded_at = Set(Some(Utc::now().to_rfc3339()));
+
+    let result = match active_result.update(&txn).await {
+        Ok(result) => result,
+        Err(err) => return Ok(ApiResponse::error(format!("Failed to record checklist item: {}", err))),
+    };
+
+    if let Err(err) = txn.commit().await {
+        return Ok(ApiResponse::error(format!("Failed to record checklist item: {}", err)));
+    }
+
+    Ok(ApiResponse::success(result.into()))
+}
+
+// Summary of each ticket's checklist, for listing tickets without loading every photo.
+#[derive(Serialize)]
+pub struct ChecklistProgress {
+    pub total: usize,
+    pub passed: usize,
+    pub failed: usize,
+}
+
+#[tauri::command]
+pub async fn view_checklist_progress(
+    state: State<'_, AppState>,
+    session_token: String,
+    maintenance_ids: Vec<String>,
+) -> Result<ApiResponse<HashMap<String, ChecklistProgress>>, String> {
+    if let Err(message) = authorize(&state, &session_token, "view_checklist_progress").await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    let rows: Vec<(String, ChecklistResultStatus)> = match ChecklistResult::find()
+        .select_only()
+        .column(checklist_result::Column::MaintenanceId)
+        .column(checklist_result::Column::Status)
+        .filter(checklist_result::Column::MaintenanceId.is_in(maintenance_ids))
+        .into_tuple()
+        .all(&state.db)
+        .await
+    {
+        Ok(rows) => rows,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    let mut progress: HashMap<String, ChecklistProgress> = HashMap::new();
+    for (maintenance_id, status) in rows {
+        let entry = progress
+            .entry(maintenance_id)
+            .or_insert(ChecklistProgress { total: 0, passed: 0, failed: 0 });
+        entry.total += 1;
+        match status {
+            ChecklistResultStatus::Passed => entry.passed += 1,
+            ChecklistResultStatus::Failed => entry.failed += 1,
+            ChecklistResultStatus::Pending => {}
+        }
+    }
+
+    Ok(ApiResponse::success(progress))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(kind: ChecklistItemKind, required: bool) -> checklist_result::Model {
+        checklist_result::Model {
+            result_id: "CR-1".to_string(),
+            maintenance_id: "M-1".to_string(),
+            template_name: "Daily opening".to_string(),
+            position: 1,
+            label: "Lap bars".to_string(),
+            kind,
+            required,
+            min_value: None,
+            max_value: None,
+            unit: None,
+            status: ChecklistResultStatus::Pending,
+            measured_value: None,
+            photo: None,
+            comment: None,
+            recorded_by: None,
+            recorded_at: None,
+        }
+    }
+
+    fn recorded(passed: Option<bool>, measured_value: Option<f64>, photo: Option<Vec<u8>>) -> RecordChecklistResultRequest {
+        RecordChecklistResultRequest {
+            result_id: "CR-1".to_string(),
+            passed,
+            measured_value,
+            photo,
+            comment: None,
+        }
+    }
+
+    #[test]
+    fn checks_record_the_tick_for_required_and_optional_items() {
+        for required in [true, false] {
+            let check = item(ChecklistItemKind::Check, required);
+
+            assert_eq!(evaluate(&check, &recorded(Some(true), None, None)), Ok(ChecklistResultStatus::Passed));
+            assert_eq!(evaluate(&check, &recorded(Some(false), None, None)), Ok(ChecklistResultStatus::Failed));
+        }
+    }
+
+    #[test]
+    fn missing_results_are_refused() {
+        let check = item(ChecklistItemKind::Check, true);
+        let measurement = item(ChecklistItemKind::Measurement, true);
+        let photo = item(ChecklistItemKind::Photo, false);
+
+        assert!(evaluate(&check, &recorded(None, None, None)).is_err());
+        assert!(evaluate(&measurement, &recorded(None, None, None)).is_err());
+        assert!(evaluate(&measurement, &recorded(None, Some(f64::NAN), None)).is_err());
+        assert!(evaluate(&photo, &recorded(Some(true), None, Some(Vec::new()))).is_err());
+    }
+
+    #[test]
+    fn measurements_fail_outside_their_bounds() {
+        let mut measurement = item(ChecklistItemKind::Measurement, true);
+        measurement.min_value = Some(4.5);
+        measurement.max_value = Some(5.5);
+
+        assert_eq!(evaluate(&measurement, &recorded(None, Some(4.5), None)), Ok(ChecklistResultStatus::Passed));
+        assert_eq!(evaluate(&measurement, &recorded(None, Some(5.5), None)), Ok(ChecklistResultStatus::Passed));
+        assert_eq!(evaluate(&measurement, &recorded(None, Some(4.4), None)), Ok(ChecklistResultStatus::Failed));
+        assert_eq!(evaluate(&measurement, &recorded(None, Some(5.6), None)), Ok(ChecklistResultStatus::Failed));
+    }
+
+    #[test]
+    fn photos_pass_unless_marked_as_a_defect() {
+        let photo = item(ChecklistItemKind::Photo, true);
+
+        assert_eq!(evaluate(&photo, &recorded(None, None, Some(vec![1]))), Ok(ChecklistResultStatus::Passed));
+        assert_eq!(evaluate(&photo, &recorded(Some(false), None, Some(vec![1]))), Ok(ChecklistResultStatus::Failed));
+
+        let mut retaken = photo.clone();
+        retaken.photo = Some(vec![1]);
+        assert_eq!(evaluate(&retaken, &recorded(Some(true), None, None)), Ok(ChecklistResultStatus::Passed));
+    }
+}
@@ -0,0 +1,356 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use entity::fast_pass::{self, ActiveModel as FastPassActiveModel, Entity as FastPass};
+use entity::ride::{self, Entity as Ride};
+use entity::sea_orm_active_enums::FastPassStatus;
+use entity::user::Entity as User;
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
+    TransactionTrait, ActiveValue::Set,
+};
+use serde::{Deserialize, Serialize};
+use tauri::State;
+use uuid::Uuid;
+use crate::{ApiResponse, AppState};
+use crate::controllers::queue_handler::{can_operate_ride, check_eligibility, ride_is_operational, QueuePolicy};
+use crate::controllers::session_handler::authorize;
+
+// How many upcoming return windows are open for booking at any time.
+const BOOKABLE_SLOTS: i64 = 8;
+
+// Passes that still hold a seat in their return window.
+const HELD_STATUSES: [FastPassStatus; 3] = [FastPassStatus::Booked, FastPassStatus::Redeemed, FastPassStatus::Boarded];
+
+fn slot_seconds(policy: &QueuePolicy) -> i64 {
+    policy.fast_pass_slot_minutes * 60
+}
+
+// Passes per return window: the priority seats the ride dispatches during one window.
+fn slot_quota(policy: &QueuePolicy, ride: &ride::Model) -> u64 {
+    let cycles_per_slot = slot_seconds(policy) as f64 / f64::from(ride.cycle_duration.max(1));
+    (f64::from(ride.capacity.max(0)) * cycles_per_slot * policy.fast_pass_ratio).floor() as u64
+}
+
+// Return windows are aligned to the slot length so every client sees the same boundaries.
+fn upcoming_slots(policy: &QueuePolicy, now: DateTime<Utc>) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+    let length = slot_seconds(policy);
+    let first = now.timestamp() - now.timestamp().rem_euclid(length) + length;
+
+    (0..BOOKABLE_SLOTS)
+        .filter_map(|i| DateTime::from_timestamp(first + i * length, 0))
+        .map(|start| (start, start + Duration::seconds(length)))
+        .collect()
+}
+
+fn format_slot_time(at: DateTime<Utc>) -> String {
+    at.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+// Passes nobody boarded with before their return window closed stop holding the customer's
+// pass for the ride and drop out of the priority lane. Run before anything reads them.
+pub async fn expire_fast_passes<C: ConnectionTrait>(db: &C, now: DateTime<Utc>) -> Result<u64, String> {
+    FastPass::update_many()
+        .col_expr(fast_pass::Column::Status, Expr::value(FastPassStatus::Expired))
+        .filter(fast_pass::Column::Status.is_in([FastPassStatus::Booked, FastPassStatus::Redeemed]))
+        .filter(fast_pass::Column::ReturnEnd.lte(format_slot_time(now)))
+        .exec(db)
+        .await
+        .map(|result| result.rows_affected)
+        .map_err(|err| format!("Failed to expire fast passes: {}", err))
+}
+
+#[derive(Serialize)]
+pub struct FastPassSlotResponse {
+    pub return_start: String,
+    pub return_end: String,
+    pub quota: u64,
+    pub remaining: u64,
+}
+
+#[tauri::command]
+pub async fn view_fast_pass_slots(
+    state: State<'_, AppState>,
+    session_token: String,
+    ride_id: String,
+) -> Result<ApiResponse<Vec<FastPassSlotResponse>>, String> {
+    if let Err(message) = authorize(&state, &session_token, "view_fast_pass_slots").await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    let ride = match Ride::find_by_id(ride_id.clone()).one(&state.db).await {
+        Ok(Some(ride)) => ride,
+        Ok(None) => return Ok(ApiResponse::error(format!("No ride found with ID: {}", ride_id))),
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    let quota = slot_quota(&state.queue_policy, &ride);
+    let slots = upcoming_slots(&state.queue_policy, Utc::now());
+    let slot_starts: Vec<String> = slots.iter().map(|(start, _)| format_slot_time(*start)).collect();
+
+    let held: Vec<String> = match FastPass::find()
+        .select_only()
+        .column(fast_pass::Column::ReturnStart)
+        .filter(fast_pass::Column::RideId.eq(&ride_id))
+        .filter(fast_pass::Column::Status.is_in(HELD_STATUSES))
+        .filter(fast_pass::Column::ReturnStart.is_in(slot_starts))
+        .into_tuple()
+        .all(&state.db)
+        .await
+    {
+        Ok(held) => held,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    let response = slots
+        .into_iter()
+        .map(|(start, end)| {
+            let return_start = format_slot_time(start);
+            let taken = held.iter().filter(|held_start| **held_start == return_start).count() as u64;
+            FastPassSlotResponse {
+                return_start,
+                return_end: format_slot_time(end),
+                quota,
+                remaining: quota.saturating_sub(taken),
+            }
+        })
+        .collect();
+
+    Ok(ApiResponse::success(response))
+}
+
+#[tauri::command]
+pub async fn view_fast_passes(
+    state: State<'_, AppState>,
+    session_token: String,
+    customer_id: String,
+) -> Result<ApiResponse<Vec<fast_pass::Model>>, String> {
+    let session = match authorize(&state, &session_token, "view_fast_passes").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    if !session.can_act_for(&customer_id) {
+        return Ok(ApiResponse::error("Customers can only view their own fast passes".to_string()));
+    }
+
+    if let Err(message) = expire_fast_passes(&state.db, Utc::now()).await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    match FastPass::find()
+        .filter(fast_pass::Column::CustomerId.eq(&customer_id))
+        .order_by_desc(fast_pass::Column::ReturnStart)
+        .all(&state.db)
+        .await
+    {
+        Ok(passes) => Ok(ApiResponse::success(passes)),
+        Err(err) => Ok(ApiResponse::error(format!("Database error: {}", err))),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct BookFastPassRequest {
+    pub customer_id: String,
+    pub ride_id: String,
+    pub return_start: String,
+}
+
+#[tauri::command]
+pub async fn book_fast_pass(
+    state: State<'_, AppState>,
+    session_token: String,
+    payload: BookFastPassRequest,
+) -> Result<ApiResponse<fast_pass::Model>, String> {
+    let session = match authorize(&state, &session_token, "book_fast_pass").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    if !session.can_act_for(&payload.customer_id) {
+        return Ok(ApiResponse::error("Customers can only book fast passes for themselves".to_string()));
+    }
+
+    let requested_start = match DateTime::parse_from_rfc3339(&payload.return_start) {
+        Ok(start) => start.with_timezone(&Utc),
+        Err(_) => return Ok(ApiResponse::error("Invalid return time".to_string())),
+    };
+
+    let Some((return_start, return_end)) = upcoming_slots(&state.queue_policy, Utc::now())
+        .into_iter()
+        .find(|(start, _)| *start == requested_start)
+    else {
+        return Ok(ApiResponse::error("That return window is not open for booking".to_string()));
+    };
+    let return_start = format_slot_time(return_start);
+
+    let txn = match state.db.begin().await {
+        Ok(txn) => txn,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    // Bookings for the same ride are serialised on the ride row so the quota can't be oversold.
+    let ride = match Ride::find_by_id(payload.ride_id.clone()).lock_exclusive().one(&txn).await {
+        Ok(Some(ride)) => ride,
+        Ok(None) => return Ok(ApiResponse::error(format!("No ride found with ID: {}", payload.ride_id))),
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    if !ride_is_operational(&ride) {
+        return Ok(ApiResponse::error(format!("{} is not accepting riders right now", ride.name)));
+    }
+
+    match User::find_by_id(payload.customer_id.clone()).one(&txn).await {
+        Ok(Some(customer)) => {
+            if let Err(message) = check_eligibility(&ride, &customer) {
+                return Ok(ApiResponse::error(message));
+            }
+        }
+        Ok(None) => return Ok(ApiResponse::error(format!("Customer not found: {}", payload.customer_id))),
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    }
+
+    if let Err(message) = expire_fast_passes(&txn, Utc::now()).await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    let already_holding = match FastPass::find()
+        .filter(fast_pass::Column::RideId.eq(&ride.ride_id))
+        .filter(fast_pass::Column::CustomerId.eq(&payload.customer_id))
+        .filter(fast_pass::Column::Status.is_in([FastPassStatus::Booked, FastPassStatus::Redeemed]))
+        .count(&txn)
+        .await
+    {
+        Ok(count) => count,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    if already_holding > 0 {
+        return Ok(ApiResponse::error("Customer already holds a fast pass for this ride".to_string()));
+    }
+
+    let taken = match FastPass::find()
+        .filter(fast_pass::Column::RideId.eq(&ride.ride_id))
+        .filter(fast_pass::Column::ReturnStart.eq(&return_start))
+        .filter(fast_pass::Column::Status.is_in(HELD_STATUSES))
+        .count(&txn)
+        .await
+    {
+        Ok(count) => count,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    if taken >= slot_quota(&state.queue_policy, &ride) {
+        return Ok(ApiResponse::error("That return window is fully booked".to_string()));
+    }
+
+    let pass = match (FastPassActiveModel {
+        fast_pass_id: Set(format!("FP-{}", Uuid::new_v4())),
+        ride_id: Set(ride.ride_id),
+        customer_id: Set(payload.customer_id),
+        return_start: Set(return_start),
+        return_end: Set(format_slot_time(return_end)),
+        status: Set(FastPassStatus::Booked),
+        booked_at: Set(Utc::now().to_rfc3339()),
+        redeemed_at: Set(None),
+    })
+    .insert(&txn)
+    .await
+    {
+        Ok(pass) => pass,
+        Err(err) => return Ok(ApiResponse::error(format!("Failed to book fast pass: {}", err))),
+    };
+
+    if let Err(err) = txn.commit().await {
+        return Ok(ApiResponse::error(format!("Failed to book fast pass: {}", err)));
+    }
+
+    Ok(ApiResponse::success(pass))
+}
+
+#[derive(Deserialize)]
+pub struct FastPassRequest {
+    pub fast_pass_id: String,
+}
+
+#[tauri::command]
+pub async fn cancel_fast_pass(
+    state: State<'_, AppState>,
+    session_token: String,
+    payload: FastPassRequest,
+) -> Result<ApiResponse<fast_pass::Model>, String> {
+    let session = match authorize(&state, &session_token, "cancel_fast_pass").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    let pass = match FastPass::find_by_id(payload.fast_pass_id.clone()).one(&state.db).await {
+        Ok(Some(pass)) => pass,
+        Ok(None) => return Ok(ApiResponse::error(format!("No fast pass found with ID: {}", payload.fast_pass_id))),
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    if !session.can_act_for(&pass.customer_id) {
+        return Ok(ApiResponse::error("Customers can only cancel their own fast passes".to_string()));
+    }
+
+    if pass.status != FastPassStatus::Booked {
+        return Ok(ApiResponse::error("Only booked fast passes can be cancelled".to_string()));
+    }
+
+    let mut active_pass: FastPassActiveModel = pass.into();
+    active_pass.status = Set(FastPassStatus::Cancelled);
+
+    match active_pass.update(&state.db).await {
+        Ok(pass) => Ok(ApiResponse::success(pass)),
+        Err(err) => Ok(ApiResponse::error(format!("Failed to cancel fast pass: {}", err))),
+    }
+}
+
+// Scanned at the ride entrance: a pass inside its return window joins the priority lane,
+// which dispatch_ride_cycle merges into each cycle.
+#[tauri::command]
+pub async fn redeem_fast_pass(
+    state: State<'_, AppState>,
+    session_token: String,
+    payload: FastPassRequest,
+) -> Result<ApiResponse<fast_pass::Model>, String> {
+    let session = match authorize(&state, &session_token, "redeem_fast_pass").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    let (pass, ride) = match FastPass::find_by_id(payload.fast_pass_id.clone())
+        .find_also_related(Ride)
+        .one(&state.db)
+        .await
+    {
+        Ok(Some((pass, Some(ride)))) => (pass, ride),
+        Ok(_) => return Ok(ApiResponse::error(format!("No fast pass found with ID: {}", payload.fast_pass_id))),
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    if !can_operate_ride(&session, &ride) {
+        return Ok(ApiResponse::error("Ride staff can only redeem passes for the ride they are assigned to".to_string()));
+    }
+
+    if pass.status != FastPassStatus::Booked {
+        return Ok(ApiResponse::error("This fast pass has already been used or cancelled".to_string()));
+    }
+
+    let now = format_slot_time(Utc::now());
+    if now < pass.return_start || now >= pass.return_end {
+        return Ok(ApiResponse::error(format!(
+            "This fast pass is valid from {} to {}",
+            pass.return_start, pass.return_end
+        )));
+    }
+
+    let mut active_pass: FastPassActiveModel = pass.into();
+    active_pass.status = Set(FastPassStatus::Redeemed);
+    active_pass.redeemed_at = Set(Some(Utc::now().to_rfc3339()));
+
+    match active_pass.update(&state.db).await {
+        Ok(pass) => Ok(ApiResponse::success(pass)),
+        Err(err) => Ok(ApiResponse::error(format!("Failed to redeem fast pass: {}", err))),
+    }
+}
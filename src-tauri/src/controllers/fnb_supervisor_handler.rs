@@ -2,7 +2,8 @@ use entity::user::{self, ActiveModel as UserActiveModel};
 use sea_orm::{ActiveModelTrait, EntityTrait, Set};
 use serde::Deserialize;
 use tauri::State;
-use crate::{ApiResponse, AppState};
+use crate::{ApiResponse, AppState, cache_invalidate};
+use crate::controllers::session_handler::{authorize, refresh_user_sessions};
 
 #[derive(Deserialize)]
 pub struct AssignRestaurantStaffRequest {
@@ -13,17 +14,22 @@ pub struct AssignRestaurantStaffRequest {
 #[tauri::command]
 pub async fn assign_restaurant_staff(
     state: State<'_, AppState>,
+    session_token: String,
     payload: AssignRestaurantStaffRequest,
 ) -> Result<ApiResponse<()>, String> {
+    if let Err(message) = authorize(&state, &session_token, "assign_restaurant_staff").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     match user::Entity::find_by_id(payload.staff_id.clone()).one(&state.db).await {
         Ok(Some(existing_user)) => {
             let mut active_user: UserActiveModel = existing_user.into();
             active_user.restaurant_id = Set(payload.restaurant_id);
 
             match active_user.update(&state.db).await {
-                Ok(_) => {
-                    // Optional: Hapus cache terkait jika ada
-                    // cache_delete(&state.redis_pool, "some_cache_key").await;
+                Ok(updated_user) => {
+                    cache_invalidate(&state.redis_pool, &["user", &format!("user:{}", updated_user.user_id)]).await;
+                    refresh_user_sessions(&state, &updated_user).await;
                     Ok(ApiResponse::success(()))
                 }
                 Err(err) => Ok(ApiResponse::error(format!("Gagal memperbarui staff: {}", err))),
@@ -1,11 +1,12 @@
 use anyhow::Result;
-use base64::encode;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
 use entity::lost_and_found_item::{self, ActiveModel as LostItemActiveModel, Entity as LostItem};
 use sea_orm::{ActiveModelTrait, ActiveValue::Set, EntityTrait, QueryOrder};
 use serde::{Deserialize, Serialize};
 use tauri::State;
 
-use crate::{ApiResponse, AppState, cache_get, cache_set, cache_delete};
+use crate::{ApiResponse, AppState, cache_get, cache_set_tagged, cache_invalidate};
+use crate::controllers::session_handler::authorize;
 
 #[derive(Serialize, Deserialize)]
 pub struct LostAndFoundItemResponse {
@@ -23,7 +24,12 @@ pub struct LostAndFoundItemResponse {
 #[tauri::command]
 pub async fn view_lost_and_found_items(
     state: State<'_, AppState>,
+    session_token: String,
 ) -> Result<ApiResponse<Vec<LostAndFoundItemResponse>>, String> {
+    if let Err(message) = authorize(&state, &session_token, "view_lost_and_found_items").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     let cache_key = "get_all_lost_and_found_items_cache";
 
     if let Some(cached_items) = cache_get::<Vec<lost_and_found_item::Model>>(&state.redis_pool, cache_key).await {
@@ -38,7 +44,7 @@ pub async fn view_lost_and_found_items(
                 status: item.status,
                 finder_id: item.finder_id,
                 owner_id: item.owner_id,
-                image: encode(&item.image),
+                image: STANDARD.encode(&item.image),
             })
             .collect();
 
@@ -62,11 +68,11 @@ pub async fn view_lost_and_found_items(
                     status: item.status.clone(),
                     finder_id: item.finder_id.clone(),
                     owner_id: item.owner_id.clone(),
-                    image: encode(&item.image),
+                    image: STANDARD.encode(&item.image),
                 })
                 .collect::<Vec<_>>();
 
-            cache_set(&state.redis_pool, cache_key, &items, 60).await;
+            cache_set_tagged(&state.redis_pool, cache_key, &items, 60, &["lost_and_found_item"]).await;
             Ok(ApiResponse::success(formatted))
         }
         Err(err) => Ok(ApiResponse::error(format!("Database error: {}", err))),
@@ -89,8 +95,13 @@ pub struct CreateLostItemRequest {
 #[tauri::command]
 pub async fn create_lost_item(
     state: State<'_, AppState>,
+    session_token: String,
     payload: CreateLostItemRequest,
 ) -> Result<ApiResponse<lost_and_found_item::Model>, String> {
+    if let Err(message) = authorize(&state, &session_token, "create_lost_item").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     let new_item = LostItemActiveModel {
         item_id: Set(payload.item_id),
         name: Set(payload.name),
@@ -101,12 +112,11 @@ pub async fn create_lost_item(
         finder_id: Set(payload.finder_id),
         owner_id: Set(payload.owner_id),
         image: Set(payload.image),
-        ..Default::default()
     };
 
     match new_item.insert(&state.db).await {
         Ok(item) => {
-            cache_delete(&state.redis_pool, "get_all_lost_and_found_items_cache").await;
+            cache_invalidate(&state.redis_pool, &["lost_and_found_item"]).await;
             Ok(ApiResponse::success(item))
         }
         Err(err) => Ok(ApiResponse::error(format!("Failed to create item: {}", err))),
@@ -129,8 +139,13 @@ pub struct UpdateLostItemRequest {
 #[tauri::command]
 pub async fn update_lost_item(
     state: State<'_, AppState>,
+    session_token: String,
     payload: UpdateLostItemRequest,
 ) -> Result<ApiResponse<lost_and_found_item::Model>, String> {
+    if let Err(message) = authorize(&state, &session_token, "update_lost_item").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     match LostItem::find_by_id(payload.item_id.clone()).one(&state.db).await {
         Ok(Some(existing)) => {
             let mut model: LostItemActiveModel = existing.into();
@@ -145,7 +160,7 @@ pub async fn update_lost_item(
 
             match model.update(&state.db).await {
                 Ok(updated) => {
-                    cache_delete(&state.redis_pool, "get_all_lost_and_found_items_cache").await;
+                    cache_invalidate(&state.redis_pool, &["lost_and_found_item"]).await;
                     Ok(ApiResponse::success(updated))
                 }
                 Err(err) => Ok(ApiResponse::error(format!("Failed to update item: {}", err))),
@@ -164,14 +179,19 @@ pub struct DeleteLostItemRequest {
 #[tauri::command]
 pub async fn delete_lost_item(
     state: State<'_, AppState>,
+    session_token: String,
     payload: DeleteLostItemRequest,
 ) -> Result<ApiResponse<()>, String> {
+    if let Err(message) = authorize(&state, &session_token, "delete_lost_item").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     match LostItem::delete_by_id(payload.item_id.clone()).exec(&state.db).await {
         Ok(result) => {
             if result.rows_affected == 0 {
                 return Ok(ApiResponse::error(format!("No item found with ID: {}", payload.item_id)));
             }
-            cache_delete(&state.redis_pool, "get_all_lost_and_found_items_cache").await;
+            cache_invalidate(&state.redis_pool, &["lost_and_found_item"]).await;
             Ok(ApiResponse::success(()))
         }
         Err(err) => Ok(ApiResponse::error(format!("Failed to delete item: {}", err))),
@@ -1,8 +1,39 @@
-use entity::maintenance::{self, Entity as Maintenance, Model as MaintenanceModel};
-use sea_orm::{ActiveModelTrait, EntityTrait};
+use chrono::Utc;
+use entity::maintenance::{self, Entity as Maintenance};
+use entity::maintenance_note::{self, Entity as MaintenanceNote};
+use entity::ride::Entity as Ride;
+use entity::sea_orm_active_enums::{MaintenanceStatus, RideMaintenanceStatus};
+use entity::user::{self, Entity as User};
+use sea_orm::{
+    ActiveEnum, ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+    TransactionTrait, ActiveValue::Set,
+};
 use serde::{Deserialize, Serialize};
 use tauri::State;
-use crate::{AppState, ApiResponse, cache_get, cache_set};
+use uuid::Uuid;
+use crate::{AppState, ApiResponse, cache_get, cache_set_tagged, cache_invalidate};
+use crate::controllers::checklist_handler::{attach_checklists, failed_required_items, unresolved_required_items};
+use crate::controllers::maintenance_sla_handler::{load_sla_policies, ticket_sla, TicketSla};
+use crate::controllers::notification_handler::insert_notification;
+use crate::controllers::queue_handler::QueuePolicy;
+use crate::controllers::ride_handler::{apply_ride_state, invalidate_ride_state};
+use crate::controllers::session_handler::{authorize, Session};
+
+// Tickets whose work is not finished yet.
+pub const OPEN_STATUSES: [MaintenanceStatus; 4] = [
+    MaintenanceStatus::Pending,
+    MaintenanceStatus::Assigned,
+    MaintenanceStatus::InProgress,
+    MaintenanceStatus::Paused,
+];
+
+// Work moves forward only through the assigned staff member.
+const TICKET_TRANSITIONS: &[(MaintenanceStatus, MaintenanceStatus)] = &[
+    (MaintenanceStatus::Assigned, MaintenanceStatus::InProgress),
+    (MaintenanceStatus::Paused, MaintenanceStatus::InProgress),
+    (MaintenanceStatus::InProgress, MaintenanceStatus::Paused),
+    (MaintenanceStatus::InProgress, MaintenanceStatus::Completed),
+];
 
 #[derive(Serialize, Deserialize)]
 pub struct MaintenanceResponse {
@@ -11,40 +42,484 @@ pub struct MaintenanceResponse {
     pub r#type: String,
     pub issue: String,
     pub date: String,
-    pub status: String,
+    pub status: MaintenanceStatus,
     pub maintenance_staff_id: Option<String>,
     pub sender_id: String,
+    pub assigned_at: Option<String>,
+    pub started_at: Option<String>,
+    pub completed_at: Option<String>,
+    pub plan_id: Option<String>,
+    pub due_at: Option<String>,
+    pub is_overdue: bool,
+    pub reported_at: Option<String>,
+    pub escalation_level: i32,
+    pub escalated_at: Option<String>,
+    // Worked out on every read, so it is never stale even when the list comes from cache.
+    pub sla: Option<TicketSla>,
+}
+
+pub async fn record_ticket_change<C: ConnectionTrait>(
+    db: &C,
+    maintenance_id: &str,
+    from_status: Option<MaintenanceStatus>,
+    to_status: MaintenanceStatus,
+    author_id: &str,
+    note: Option<String>,
+) -> Result<(), String> {
+    maintenance_note::ActiveModel {
+        note_id: Set(format!("MTN-{}", Uuid::new_v4())),
+        maintenance_id: Set(maintenance_id.to_string()),
+        author_id: Set(author_id.to_string()),
+        from_status: Set(from_status),
+        to_status: Set(to_status),
+        note: Set(note.filter(|note| !note.trim().is_empty())),
+        created_at: Set(Utc::now().to_rfc3339()),
+    }
+    .insert(db)
+    .await
+    .map_err(|e| format!("Failed to record maintenance note: {}", e))?;
+
+    Ok(())
+}
+
+// Moves the ride's maintenance status to match its open tickets: any work under way keeps it
+// in progress, any other open reported fault keeps it pending, and none frees it. Scheduled
+// tickets only take the ride down once work starts. Returns the customers whose queue entries
+// were held or released as a result.
+pub async fn sync_ride_maintenance<C: ConnectionTrait>(
+    db: &C,
+    policy: &QueuePolicy,
+    actor_id: &str,
+    ride_id: &str,
+    reason: String,
+) -> Result<Vec<String>, String> {
+    let ride = Ride::find_by_id(ride_id.to_string())
+        .lock_exclusive()
+        .one(db)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .ok_or_else(|| format!("No ride found with ID: {}", ride_id))?;
+
+    let open: Vec<(MaintenanceStatus, Option<String>)> = Maintenance::find()
+        .select_only()
+        .column(maintenance::Column::Status)
+        .column(maintenance::Column::PlanId)
+        .filter(maintenance::Column::RideId.eq(ride_id))
+        .filter(maintenance::Column::Status.is_in(OPEN_STATUSES))
+        .into_tuple()
+        .all(db)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let failed_checks = failed_required_items(db, ride_id).await?;
+
+    let maintenance_status = if open.iter().any(|(status, _)| *status == MaintenanceStatus::InProgress) {
+        RideMaintenanceStatus::InProgress
+    } else if open.iter().any(|(_, plan_id)| plan_id.is_none()) || !failed_checks.is_empty() {
+        RideMaintenanceStatus::Pending
+    } else {
+        RideMaintenanceStatus::Available
+    };
+
+    let status = ride.status;
+    let (_, affected) = apply_ride_state(db, policy, actor_id, ride, status, maintenance_status, Some(reason)).await?;
+    Ok(affected)
+}
+
+async fn invalidate_ticket(state: &AppState, ride_id: &str, affected: &[String]) {
+    cache_invalidate(&state.redis_pool, &["maintenance"]).await;
+    invalidate_ride_state(state, ride_id, affected).await;
 }
 
 #[tauri::command]
 pub async fn view_all_maintenance(
     state: State<'_, AppState>,
+    session_token: String,
 ) -> Result<ApiResponse<Vec<MaintenanceResponse>>, String> {
+    if let Err(message) = authorize(&state, &session_token, "view_all_maintenance").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     let cache_key = "all_maintenance_cache";
 
-    if let Some(cached) = cache_get::<Vec<MaintenanceResponse>>(&state.redis_pool, cache_key).await {
-        return Ok(ApiResponse::success(cached));
-    }
-
-    match Maintenance::find().all(&state.db).await {
-        Ok(results) => {
-            let data = results
-                .into_iter()
-                .map(|m| MaintenanceResponse {
-                    maintenance_id: m.maintenance_id,
-                    ride_id: m.ride_id,
-                    r#type: m.r#type,
-                    issue: m.issue,
-                    date: m.date,
-                    status: m.status,
-                    maintenance_staff_id: m.maintenance_staff_id,
-                    sender_id: m.sender_id,
-                })
-                .collect::<Vec<_>>();
-
-            cache_set(&state.redis_pool, cache_key, &data, 60).await;
-            Ok(ApiResponse::success(data))
+    let mut data = match cache_get::<Vec<MaintenanceResponse>>(&state.redis_pool, cache_key).await {
+        Some(cached) => cached,
+        None => match Maintenance::find().all(&state.db).await {
+            Ok(results) => {
+                let data = results
+                    .into_iter()
+                    .map(|m| MaintenanceResponse {
+                        maintenance_id: m.maintenance_id,
+                        ride_id: m.ride_id,
+                        r#type: m.r#type,
+                        issue: m.issue,
+                        date: m.date,
+                        status: m.status,
+                        maintenance_staff_id: m.maintenance_staff_id,
+                        sender_id: m.sender_id,
+                        assigned_at: m.assigned_at,
+                        started_at: m.started_at,
+                        completed_at: m.completed_at,
+                        plan_id: m.plan_id,
+                        due_at: m.due_at,
+                        is_overdue: m.is_overdue,
+                        reported_at: m.reported_at,
+                        escalation_level: m.escalation_level,
+                        escalated_at: m.escalated_at,
+                        sla: None,
+                    })
+                    .collect::<Vec<_>>();
+
+                cache_set_tagged(&state.redis_pool, cache_key, &data, 60, &["maintenance"]).await;
+                data
+            }
+            Err(err) => return Ok(ApiResponse::error(format!("Failed to retrieve maintenance data: {}", err))),
+        },
+    };
+
+    let policies = match load_sla_policies(&state.db).await {
+        Ok(policies) => policies,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    let now = Utc::now();
+    for ticket in &mut data {
+        ticket.sla = ticket_sla(&policies, ticket, now);
+    }
+
+    Ok(ApiResponse::success(data))
+}
+
+#[derive(Serialize)]
+pub struct MaintenanceStaffWorkload {
+    pub user_id: String,
+    pub name: String,
+    pub status: Option<String>,
+    pub open_tickets: usize,
+}
+
+// Maintenance staff with the number of open tickets each is carrying, for choosing an assignee.
+#[tauri::command]
+pub async fn view_maintenance_staff_workload(
+    state: State<'_, AppState>,
+    session_token: String,
+) -> Result<ApiResponse<Vec<MaintenanceStaffWorkload>>, String> {
+    if let Err(message) = authorize(&state, &session_token, "view_maintenance_staff_workload").await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    let staff = match User::find()
+        .filter(user::Column::Role.eq("Maintenance Staff"))
+        .order_by_asc(user::Column::Name)
+        .all(&state.db)
+        .await
+    {
+        Ok(staff) => staff,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    let assignees: Vec<Option<String>> = match Maintenance::find()
+        .select_only()
+        .column(maintenance::Column::MaintenanceStaffId)
+        .filter(maintenance::Column::Status.is_in(OPEN_STATUSES))
+        .into_tuple()
+        .all(&state.db)
+        .await
+    {
+        Ok(assignees) => assignees,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    let workload = staff
+        .into_iter()
+        .map(|member| MaintenanceStaffWorkload {
+            open_tickets: assignees
+                .iter()
+                .filter(|assignee| assignee.as_deref() == Some(member.user_id.as_str()))
+                .count(),
+            user_id: member.user_id,
+            name: member.name,
+            status: member.status,
+        })
+        .collect();
+
+    Ok(ApiResponse::success(workload))
+}
+
+#[derive(Deserialize)]
+pub struct AssignMaintenanceStaffRequest {
+    pub maintenance_id: String,
+    pub staff_id: String,
+    pub note: Option<String>,
+}
+
+// Assigns or reassigns an open ticket that nobody is currently working on.
+#[tauri::command]
+pub async fn assign_maintenance_staff(
+    state: State<'_, AppState>,
+    session_token: String,
+    payload: AssignMaintenanceStaffRequest,
+) -> Result<ApiResponse<maintenance::Model>, String> {
+    let session = match authorize(&state, &session_token, "assign_maintenance_staff").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    let txn = match state.db.begin().await {
+        Ok(txn) => txn,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    let ticket = match Maintenance::find_by_id(payload.maintenance_id.clone()).lock_exclusive().one(&txn).await {
+        Ok(Some(ticket)) => ticket,
+        Ok(None) => {
+            return Ok(ApiResponse::error(format!("No maintenance request found with ID: {}", payload.maintenance_id)))
+        }
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    if !matches!(
+        ticket.status,
+        MaintenanceStatus::Pending | MaintenanceStatus::Assigned | MaintenanceStatus::Paused
+    ) {
+        return Ok(ApiResponse::error(format!(
+            "A ticket that is {} cannot be assigned",
+            ticket.status.to_value()
+        )));
+    }
+
+    match User::find_by_id(payload.staff_id.clone()).one(&txn).await {
+        Ok(Some(staff)) if staff.role == "Maintenance Staff" => {}
+        Ok(Some(_)) => return Ok(ApiResponse::error("Tickets can only be assigned to maintenance staff".to_string())),
+        Ok(None) => return Ok(ApiResponse::error(format!("Staff not found: {}", payload.staff_id))),
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    }
+
+    let from_status = ticket.status;
+    let ride_id = ticket.ride_id.clone();
+    let issue = ticket.issue.clone();
+
+    let mut active_ticket: maintenance::ActiveModel = ticket.into();
+    active_ticket.maintenance_staff_id = Set(Some(payload.staff_id.clone()));
+    active_ticket.status = Set(MaintenanceStatus::Assigned);
+    active_ticket.assigned_at = Set(Some(Utc::now().to_rfc3339()));
+
+    let updated_ticket = match active_ticket.update(&txn).await {
+        Ok(updated_ticket) => updated_ticket,
+        Err(err) => return Ok(ApiResponse::error(format!("Failed to assign maintenance staff: {}", err))),
+    };
+
+    let note = payload.note.or_else(|| Some(format!("Assigned to {}", payload.staff_id)));
+    if let Err(message) = record_ticket_change(
+        &txn,
+        &updated_ticket.maintenance_id,
+        Some(from_status),
+        MaintenanceStatus::Assigned,
+        &session.user_id,
+        note,
+    )
+    .await
+    {
+        return Ok(ApiResponse::error(message));
+    }
+
+    let reason = format!("Maintenance ticket {} assigned", updated_ticket.maintenance_id);
+    let affected = match sync_ride_maintenance(&txn, &state.queue_policy, &session.user_id, &ride_id, reason).await {
+        Ok(affected) => affected,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    if let Err(message) = insert_notification(
+        &txn,
+        payload.staff_id.clone(),
+        "Maintenance assigned".to_string(),
+        format!("You have been assigned maintenance ticket {}: {}", updated_ticket.maintenance_id, issue),
+        "Maintenance".to_string(),
+    )
+    .await
+    {
+        return Ok(ApiResponse::error(message));
+    }
+
+    if let Err(err) = txn.commit().await {
+        return Ok(ApiResponse::error(format!("Failed to assign maintenance staff: {}", err)));
+    }
+
+    cache_invalidate(&state.redis_pool, &[&format!("notification:{}", payload.staff_id)]).await;
+    invalidate_ticket(&state, &ride_id, &affected).await;
+    Ok(ApiResponse::success(updated_ticket))
+}
+
+// Moves a ticket along its workflow on behalf of the staff member assigned to it.
+async fn progress_ticket(
+    state: &AppState,
+    session: &Session,
+    maintenance_id: &str,
+    next: MaintenanceStatus,
+    note: Option<String>,
+) -> Result<maintenance::Model, String> {
+    let txn = state.db.begin().await.map_err(|e| format!("Database error: {}", e))?;
+
+    let ticket = Maintenance::find_by_id(maintenance_id.to_string())
+        .lock_exclusive()
+        .one(&txn)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .ok_or_else(|| format!("No maintenance request found with ID: {}", maintenance_id))?;
+
+    if ticket.maintenance_staff_id.as_deref() != Some(session.user_id.as_str()) {
+        return Err("Only the assigned maintenance staff can update this ticket".to_string());
+    }
+
+    let current = ticket.status;
+    if !TICKET_TRANSITIONS.contains(&(current, next)) {
+        return Err(format!(
+            "Cannot move a maintenance ticket from {} to {}",
+            current.to_value(),
+            next.to_value()
+        ));
+    }
+
+    if matches!(next, MaintenanceStatus::Paused | MaintenanceStatus::Completed)
+        && note.as_deref().is_none_or(|note| note.trim().is_empty())
+    {
+        return Err(format!("A note is required to mark a ticket {}", next.to_value()));
+    }
+
+    if next == MaintenanceStatus::Completed {
+        let unresolved = unresolved_required_items(&txn, maintenance_id).await?;
+        if !unresolved.is_empty() {
+            return Err(format!(
+                "Required checklist items must pass before completing this ticket: {}",
+                unresolved.join(", ")
+            ));
+        }
+    }
+
+    let now = Utc::now().to_rfc3339();
+    let ride_id = ticket.ride_id.clone();
+    let sender_id = ticket.sender_id.clone();
+
+    let mut active_ticket: maintenance::ActiveModel = ticket.into();
+    active_ticket.status = Set(next);
+    match next {
+        MaintenanceStatus::InProgress if current == MaintenanceStatus::Assigned => {
+            active_ticket.started_at = Set(Some(now));
         }
-        Err(err) => Err(format!("Failed to retrieve maintenance data: {}", err)),
+        MaintenanceStatus::Completed => active_ticket.completed_at = Set(Some(now)),
+        _ => {}
+    }
+
+    let updated_ticket = active_ticket
+        .update(&txn)
+        .await
+        .map_err(|e| format!("Failed to update maintenance ticket: {}", e))?;
+
+    if next == MaintenanceStatus::InProgress {
+        attach_checklists(&txn, &updated_ticket).await?;
+    }
+
+    record_ticket_change(&txn, maintenance_id, Some(current), next, &session.user_id, note).await?;
+
+    let reason = format!("Maintenance ticket {} {}", maintenance_id, next.to_value().to_lowercase());
+    let affected = sync_ride_maintenance(&txn, &state.queue_policy, &session.user_id, &ride_id, reason).await?;
+
+    if next == MaintenanceStatus::Completed {
+        insert_notification(
+            &txn,
+            sender_id.clone(),
+            "Maintenance completed".to_string(),
+            format!("Maintenance ticket {} has been completed", maintenance_id),
+            "Maintenance".to_string(),
+        )
+        .await?;
     }
-}
\ No newline at end of file
+
+    txn.commit()
+        .await
+        .map_err(|e| format!("Failed to update maintenance ticket: {}", e))?;
+
+    if next == MaintenanceStatus::Completed {
+        cache_invalidate(&state.redis_pool, &[&format!("notification:{}", sender_id)]).await;
+    }
+    invalidate_ticket(state, &ride_id, &affected).await;
+    Ok(updated_ticket)
+}
+
+#[derive(Deserialize)]
+pub struct MaintenanceProgressRequest {
+    pub maintenance_id: String,
+    pub note: Option<String>,
+}
+
+#[tauri::command]
+pub async fn start_maintenance(
+    state: State<'_, AppState>,
+    session_token: String,
+    payload: MaintenanceProgressRequest,
+) -> Result<ApiResponse<maintenance::Model>, String> {
+    let session = match authorize(&state, &session_token, "start_maintenance").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    match progress_ticket(&state, &session, &payload.maintenance_id, MaintenanceStatus::InProgress, payload.note).await {
+        Ok(ticket) => Ok(ApiResponse::success(ticket)),
+        Err(message) => Ok(ApiResponse::error(message)),
+    }
+}
+
+#[tauri::command]
+pub async fn pause_maintenance(
+    state: State<'_, AppState>,
+    session_token: String,
+    payload: MaintenanceProgressRequest,
+) -> Result<ApiResponse<maintenance::Model>, String> {
+    let session = match authorize(&state, &session_token, "pause_maintenance").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    match progress_ticket(&state, &session, &payload.maintenance_id, MaintenanceStatus::Paused, payload.note).await {
+        Ok(ticket) => Ok(ApiResponse::success(ticket)),
+        Err(message) => Ok(ApiResponse::error(message)),
+    }
+}
+
+#[tauri::command]
+pub async fn complete_maintenance(
+    state: State<'_, AppState>,
+    session_token: String,
+    payload: MaintenanceProgressRequest,
+) -> Result<ApiResponse<maintenance::Model>, String> {
+    let session = match authorize(&state, &session_token, "complete_maintenance").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    match progress_ticket(&state, &session, &payload.maintenance_id, MaintenanceStatus::Completed, payload.note).await {
+        Ok(ticket) => Ok(ApiResponse::success(ticket)),
+        Err(message) => Ok(ApiResponse::error(message)),
+    }
+}
+
+#[tauri::command]
+pub async fn view_maintenance_notes(
+    state: State<'_, AppState>,
+    session_token: String,
+    maintenance_id: String,
+) -> Result<ApiResponse<Vec<maintenance_note::Model>>, String> {
+    if let Err(message) = authorize(&state, &session_token, "view_maintenance_notes").await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    match MaintenanceNote::find()
+        .filter(maintenance_note::Column::MaintenanceId.eq(&maintenance_id))
+        .order_by_asc(maintenance_note::Column::CreatedAt)
+        .all(&state.db)
+        .await
+    {
+        Ok(notes) => Ok(ApiResponse::success(notes)),
+        Err(err) => Ok(ApiResponse::error(format!("Database error: {}", err))),
+    }
+}
@@ -0,0 +1,634 @@
+use chrono::{DateTime, Datelike, Duration, Months, NaiveDate, SecondsFormat, Utc};
+use deadpool_redis::Pool as RedisPool;
+use entity::maintenance::{self, ActiveModel as MaintenanceActiveModel, Entity as Maintenance};
+use entity::maintenance_plan::{self, ActiveModel as MaintenancePlanActiveModel, Entity as MaintenancePlan};
+use entity::ride::Entity as Ride;
+use entity::sea_orm_active_enums::{MaintenanceFrequency, MaintenanceStatus};
+use entity::user::{self, Entity as User};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DatabaseConnection, EntityTrait, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, TransactionTrait, ActiveValue::Set,
+};
+use serde::{Deserialize, Serialize};
+use std::env;
+use tauri::State;
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+use crate::{ApiResponse, AppState, cache_invalidate};
+use crate::controllers::maintenance_handler::{record_ticket_change, OPEN_STATUSES};
+use crate::controllers::maintenance_sla_handler::escalate_sla_breaches;
+use crate::controllers::notification_handler::insert_notification;
+use crate::controllers::session_handler::authorize;
+
+const DEFAULT_SCHEDULER_INTERVAL_SECONDS: u64 = 300;
+
+// Longest span the calendar will project plans over in one call.
+const MAX_CALENDAR_DAYS: i64 = 92;
+
+// Due times are stored to the second in UTC so they compare correctly as strings.
+fn format_due(at: DateTime<Utc>) -> String {
+    at.to_rfc3339_opts(SecondsFormat::Secs, false)
+}
+
+fn parse_due(at: &str) -> Result<DateTime<Utc>, String> {
+    DateTime::parse_from_rfc3339(at)
+        .map(|at| at.with_timezone(&Utc))
+        .map_err(|_| format!("Invalid due time {}", at))
+}
+
+// Monthly plans land on `anchor_day`, or the last day of months too short for it, so a plan
+// anchored on the 31st comes back to the 31st after February.
+fn next_occurrence(
+    due: DateTime<Utc>,
+    frequency: MaintenanceFrequency,
+    interval: i32,
+    anchor_day: i32,
+) -> Option<DateTime<Utc>> {
+    let interval = interval.max(1);
+    match frequency {
+        MaintenanceFrequency::Daily => due.checked_add_signed(Duration::days(i64::from(interval))),
+        MaintenanceFrequency::Weekly => due.checked_add_signed(Duration::weeks(i64::from(interval))),
+        MaintenanceFrequency::Monthly => {
+            let month = due.date_naive().with_day(1)?.checked_add_months(Months::new(interval.unsigned_abs()))?;
+            let last_day = month.checked_add_months(Months::new(1))?.pred_opt()?.day();
+            let day = anchor_day.unsigned_abs().clamp(1, last_day);
+            Some(month.with_day(day)?.and_time(due.time()).and_utc())
+        }
+    }
+}
+
+fn validate_plan(frequency_interval: i32, lead_hours: i32) -> Result<(), String> {
+    if frequency_interval <= 0 {
+        return Err("A plan must repeat at least once per period".to_string());
+    }
+
+    if lead_hours < 0 {
+        return Err("Lead time cannot be negative".to_string());
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn view_maintenance_plans(
+    state: State<'_, AppState>,
+    session_token: String,
+    ride_id: Option<String>,
+) -> Result<ApiResponse<Vec<maintenance_plan::Model>>, String> {
+    if let Err(message) = authorize(&state, &session_token, "view_maintenance_plans").await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    let mut query = MaintenancePlan::find();
+    if let Some(ride_id) = ride_id {
+        query = query.filter(maintenance_plan::Column::RideId.eq(ride_id));
+    }
+
+    match query.order_by_asc(maintenance_plan::Column::NextDueAt).all(&state.db).await {
+        Ok(plans) => Ok(ApiResponse::success(plans)),
+        Err(err) => Ok(ApiResponse::error(format!("Database error: {}", err))),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct CreateMaintenancePlanRequest {
+    pub ride_id: String,
+    pub name: String,
+    pub r#type: String,
+    pub description: String,
+    pub frequency: MaintenanceFrequency,
+    pub frequency_interval: Option<i32>,
+    pub lead_hours: Option<i32>,
+    pub first_due_at: String,
+}
+
+#[tauri::command]
+pub async fn create_maintenance_plan(
+    state: State<'_, AppState>,
+    session_token: String,
+    payload: CreateMaintenancePlanRequest,
+) -> Result<ApiResponse<maintenance_plan::Model>, String> {
+    let session = match authorize(&state, &session_token, "create_maintenance_plan").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    let frequency_interval = payload.frequency_interval.unwrap_or(1);
+    let lead_hours = payload.lead_hours.unwrap_or(0);
+    if let Err(message) = validate_plan(frequency_interval, lead_hours) {
+        return Ok(ApiResponse::error(message));
+    }
+
+    let first_due_at = match parse_due(&payload.first_due_at) {
+        Ok(first_due_at) => first_due_at,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    match Ride::find_by_id(payload.ride_id.clone()).one(&state.db).await {
+        Ok(Some(_)) => {}
+        Ok(None) => return Ok(ApiResponse::error(format!("No ride found with ID: {}", payload.ride_id))),
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    }
+
+    let new_plan = MaintenancePlanActiveModel {
+        plan_id: Set(format!("MP-{}", Uuid::new_v4())),
+        ride_id: Set(payload.ride_id),
+        name: Set(payload.name),
+        r#type: Set(payload.r#type),
+        description: Set(payload.description),
+        frequency: Set(payload.frequency),
+        frequency_interval: Set(frequency_interval),
+        lead_hours: Set(lead_hours),
+        next_due_at: Set(format_due(first_due_at)),
+        anchor_day: Set(first_due_at.day() as i32),
+        is_active: Set(true),
+        created_by: Set(session.user_id),
+        created_at: Set(Utc::now().to_rfc3339()),
+    };
+
+    match new_plan.insert(&state.db).await {
+        Ok(plan) => Ok(ApiResponse::success(plan)),
+        Err(err) => Ok(ApiResponse::error(format!("Failed to create maintenance plan: {}", err))),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct UpdateMaintenancePlanRequest {
+    pub plan_id: String,
+    pub name: String,
+    pub r#type: String,
+    pub description: String,
+    pub frequency: MaintenanceFrequency,
+    pub frequency_interval: i32,
+    pub lead_hours: i32,
+    pub next_due_at: String,
+    pub is_active: bool,
+}
+
+#[tauri::command]
+pub async fn update_maintenance_plan(
+    state: State<'_, AppState>,
+    session_token: String,
+    payload: UpdateMaintenancePlanRequest,
+) -> Result<ApiResponse<maintenance_plan::Model>, String> {
+    if let Err(message) = authorize(&state, &session_token, "update_maintenance_plan").await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    if let Err(message) = validate_plan(payload.frequency_interval, payload.lead_hours) {
+        return Ok(ApiResponse::error(message));
+    }
+
+    let next_due_at = match parse_due(&payload.next_due_at) {
+        Ok(next_due_at) => next_due_at,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    let plan = match MaintenancePlan::find_by_id(payload.plan_id.clone()).one(&state.db).await {
+        Ok(Some(plan)) => plan,
+        Ok(None) => return Ok(ApiResponse::error(format!("No maintenance plan found with ID: {}", payload.plan_id))),
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    let mut active_plan: MaintenancePlanActiveModel = plan.into();
+    active_plan.name = Set(payload.name);
+    active_plan.r#type = Set(payload.r#type);
+    active_plan.description = Set(payload.description);
+    active_plan.frequency = Set(payload.frequency);
+    active_plan.frequency_interval = Set(payload.frequency_interval);
+    active_plan.lead_hours = Set(payload.lead_hours);
+    active_plan.next_due_at = Set(format_due(next_due_at));
+    active_plan.anchor_day = Set(next_due_at.day() as i32);
+    active_plan.is_active = Set(payload.is_active);
+
+    match active_plan.update(&state.db).await {
+        Ok(plan) => Ok(ApiResponse::success(plan)),
+        Err(err) => Ok(ApiResponse::error(format!("Failed to update maintenance plan: {}", err))),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct DeleteMaintenancePlanRequest {
+    pub plan_id: String,
+}
+
+// Tickets already raised from the plan are kept and simply lose their link to it.
+#[tauri::command]
+pub async fn delete_maintenance_plan(
+    state: State<'_, AppState>,
+    session_token: String,
+    payload: DeleteMaintenancePlanRequest,
+) -> Result<ApiResponse<()>, String> {
+    if let Err(message) = authorize(&state, &session_token, "delete_maintenance_plan").await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    match MaintenancePlan::delete_by_id(payload.plan_id.clone()).exec(&state.db).await {
+        Ok(result) if result.rows_affected == 0 => {
+            Ok(ApiResponse::error(format!("No maintenance plan found with ID: {}", payload.plan_id)))
+        }
+        Ok(_) => {
+            cache_invalidate(&state.redis_pool, &["maintenance"]).await;
+            Ok(ApiResponse::success(()))
+        }
+        Err(err) => Ok(ApiResponse::error(format!("Failed to delete maintenance plan: {}", err))),
+    }
+}
+
+#[derive(Serialize)]
+pub struct MaintenanceCalendarEntry {
+    pub due_at: String,
+    pub ride_id: String,
+    pub plan_id: Option<String>,
+    pub title: String,
+    pub r#type: String,
+    // Set once a ticket exists; projected occurrences of a plan have none yet.
+    pub maintenance_id: Option<String>,
+    pub status: Option<MaintenanceStatus>,
+    pub maintenance_staff_id: Option<String>,
+    pub is_overdue: bool,
+}
+
+// Scheduled work between two dates (inclusive): tickets already raised, open tickets that are
+// overdue, and the future occurrences of every active plan.
+#[tauri::command]
+pub async fn view_maintenance_calendar(
+    state: State<'_, AppState>,
+    session_token: String,
+    start_date: String,
+    end_date: String,
+    ride_id: Option<String>,
+) -> Result<ApiResponse<Vec<MaintenanceCalendarEntry>>, String> {
+    if let Err(message) = authorize(&state, &session_token, "view_maintenance_calendar").await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    let parse = |date: &str| {
+        NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map(|day| day.and_time(Default::default()).and_utc())
+            .map_err(|_| format!("Invalid date {}, expected YYYY-MM-DD", date))
+    };
+    let (start, end) = match (parse(&start_date), parse(&end_date)) {
+        (Ok(start), Ok(end)) => (start, end + Duration::days(1)),
+        (Err(message), _) | (_, Err(message)) => return Ok(ApiResponse::error(message)),
+    };
+
+    if start >= end || end - start > Duration::days(MAX_CALENDAR_DAYS) {
+        return Ok(ApiResponse::error(format!(
+            "Calendar range must run forwards and cover at most {} days",
+            MAX_CALENDAR_DAYS
+        )));
+    }
+
+    let mut ticket_query = Maintenance::find().filter(
+        Condition::any()
+            .add(
+                Condition::all()
+                    .add(maintenance::Column::DueAt.gte(format_due(start)))
+                    .add(maintenance::Column::DueAt.lt(format_due(end))),
+            )
+            .add(
+                Condition::all()
+                    .add(maintenance::Column::IsOverdue.eq(true))
+                    .add(maintenance::Column::Status.is_in(OPEN_STATUSES)),
+            ),
+    );
+    let mut plan_query = MaintenancePlan::find()
+        .filter(maintenance_plan::Column::IsActive.eq(true))
+        .filter(maintenance_plan::Column::NextDueAt.lt(format_due(end)));
+    if let Some(ride_id) = &ride_id {
+        ticket_query = ticket_query.filter(maintenance::Column::RideId.eq(ride_id));
+        plan_query = plan_query.filter(maintenance_plan::Column::RideId.eq(ride_id));
+    }
+
+    let tickets = match ticket_query.all(&state.db).await {
+        Ok(tickets) => tickets,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+    let plans = match plan_query.all(&state.db).await {
+        Ok(plans) => plans,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    let mut entries: Vec<MaintenanceCalendarEntry> = tickets
+        .into_iter()
+        .map(|ticket| MaintenanceCalendarEntry {
+            due_at: ticket.due_at.unwrap_or(ticket.date),
+            ride_id: ticket.ride_id,
+            plan_id: ticket.plan_id,
+            title: ticket.issue,
+            r#type: ticket.r#type,
+            maintenance_id: Some(ticket.maintenance_id),
+            status: Some(ticket.status),
+            maintenance_staff_id: ticket.maintenance_staff_id,
+            is_overdue: ticket.is_overdue,
+        })
+        .collect();
+
+    for plan in plans {
+        let mut due = match parse_due(&plan.next_due_at) {
+            Ok(due) => due,
+            Err(_) => continue,
+        };
+
+        while due < end {
+            if due >= start {
+                entries.push(MaintenanceCalendarEntry {
+                    due_at: format_due(due),
+                    ride_id: plan.ride_id.clone(),
+                    plan_id: Some(plan.plan_id.clone()),
+                    title: plan.name.clone(),
+                    r#type: plan.r#type.clone(),
+                    maintenance_id: None,
+                    status: None,
+                    maintenance_staff_id: None,
+                    is_overdue: false,
+                });
+            }
+
+            match next_occurrence(due, plan.frequency, plan.frequency_interval, plan.anchor_day) {
+                Some(next) => due = next,
+                None => break,
+            }
+        }
+    }
+
+    entries.sort_by(|a, b| a.due_at.cmp(&b.due_at));
+    Ok(ApiResponse::success(entries))
+}
+
+pub async fn notify_maintenance_managers<C: ConnectionTrait>(
+    db: &C,
+    title: &str,
+    message: &str,
+) -> Result<Vec<String>, String> {
+    let managers: Vec<String> = User::find()
+        .select_only()
+        .column(user::Column::UserId)
+        .filter(user::Column::Role.eq("Maintenance Manager"))
+        .into_tuple()
+        .all(db)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    for manager_id in &managers {
+        insert_notification(db, manager_id.clone(), title.to_string(), message.to_string(), "Maintenance".to_string())
+            .await?;
+    }
+
+    Ok(managers)
+}
+
+// The occurrence to schedule once `due` has been handled. It always moves past `due`, even when
+// the ticket was raised early inside the lead window, and skips occurrences missed while no
+// scheduler was running rather than raising them late.
+fn occurrence_after(
+    due: DateTime<Utc>,
+    frequency: MaintenanceFrequency,
+    interval: i32,
+    anchor_day: i32,
+    now: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    let mut next_due = next_occurrence(due, frequency, interval, anchor_day)?;
+    while next_due <= now {
+        next_due = next_occurrence(next_due, frequency, interval, anchor_day)?;
+    }
+    Some(next_due)
+}
+
+// Raises the plan's ticket if it has come due and moves the plan to its next occurrence.
+// The plan row is locked so several running app instances never raise the same occurrence twice.
+async fn raise_plan_ticket(db: &DatabaseConnection, plan_id: &str, now: DateTime<Utc>) -> Result<Vec<String>, String> {
+    let txn = db.begin().await.map_err(|e| format!("Database error: {}", e))?;
+
+    let Some(plan) = MaintenancePlan::find_by_id(plan_id.to_string())
+        .lock_exclusive()
+        .one(&txn)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+    else {
+        return Ok(Vec::new());
+    };
+
+    let due = parse_due(&plan.next_due_at)?;
+    if !plan.is_active || due - Duration::hours(i64::from(plan.lead_hours)) > now {
+        return Ok(Vec::new());
+    }
+
+    // An unfinished ticket from the last occurrence stands in for this one rather than piling up.
+    let still_open = Maintenance::find()
+        .filter(maintenance::Column::PlanId.eq(&plan.plan_id))
+        .filter(maintenance::Column::Status.is_in(OPEN_STATUSES))
+        .count(&txn)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let mut notified = Vec::new();
+    if still_open == 0 {
+        let ticket = MaintenanceActiveModel {
+            maintenance_id: Set(format!("PM-{}", Uuid::new_v4())),
+            ride_id: Set(plan.ride_id.clone()),
+            r#type: Set(plan.r#type.clone()),
+            issue: Set(format!("{}: {}", plan.name, plan.description)),
+            date: Set(now.format("%Y-%m-%d %H:%M:%S").to_string()),
+            status: Set(MaintenanceStatus::Pending),
+            maintenance_staff_id: Set(None),
+            sender_id: Set(plan.created_by.clone()),
+            plan_id: Set(Some(plan.plan_id.clone())),
+            due_at: Set(Some(plan.next_due_at.clone())),
+            reported_at: Set(Some(format_due(now))),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await
+        .map_err(|e| format!("Failed to raise planned maintenance: {}", e))?;
+
+        record_ticket_change(
+            &txn,
+            &ticket.maintenance_id,
+            None,
+            MaintenanceStatus::Pending,
+            &plan.created_by,
+            Some(format!("Raised from maintenance plan {}", plan.name)),
+        )
+        .await?;
+
+        notified = notify_maintenance_managers(
+            &txn,
+            "Planned maintenance due",
+            &format!("{} is due on ride {} at {}", plan.name, plan.ride_id, plan.next_due_at),
+        )
+        .await?;
+    }
+
+    let next_due = occurrence_after(due, plan.frequency, plan.frequency_interval, plan.anchor_day, now)
+        .ok_or_else(|| format!("Maintenance plan {} cannot be scheduled any further", plan.plan_id))?;
+
+    let mut active_plan: MaintenancePlanActiveModel = plan.into();
+    active_plan.next_due_at = Set(format_due(next_due));
+    active_plan
+        .update(&txn)
+        .await
+        .map_err(|e| format!("Failed to update maintenance plan: {}", e))?;
+
+    txn.commit().await.map_err(|e| format!("Database error: {}", e))?;
+    Ok(notified)
+}
+
+// Flags open tickets past their due time and tells whoever is responsible for them.
+async fn flag_overdue_tickets(db: &DatabaseConnection, now: DateTime<Utc>) -> Result<Vec<String>, String> {
+    let txn = db.begin().await.map_err(|e| format!("Database error: {}", e))?;
+
+    let overdue = Maintenance::find()
+        .filter(maintenance::Column::IsOverdue.eq(false))
+        .filter(maintenance::Column::Status.is_in(OPEN_STATUSES))
+        .filter(maintenance::Column::DueAt.lt(format_due(now)))
+        .lock_exclusive()
+        .all(&txn)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let mut notified = Vec::new();
+    for ticket in overdue {
+        let message = format!("Maintenance ticket {} on ride {} is overdue", ticket.maintenance_id, ticket.ride_id);
+        match ticket.maintenance_staff_id.clone() {
+            Some(staff_id) => {
+                insert_notification(&txn, staff_id.clone(), "Maintenance overdue".to_string(), message, "Maintenance".to_string())
+                    .await?;
+                notified.push(staff_id);
+            }
+            None => notified.extend(notify_maintenance_managers(&txn, "Maintenance overdue", &message).await?),
+        }
+
+        let mut active_ticket: maintenance::ActiveModel = ticket.into();
+        active_ticket.is_overdue = Set(true);
+        active_ticket
+            .update(&txn)
+            .await
+            .map_err(|e| format!("Failed to flag overdue maintenance: {}", e))?;
+    }
+
+    txn.commit().await.map_err(|e| format!("Database error: {}", e))?;
+    Ok(notified)
+}
+
+pub async fn run_maintenance_schedule(db: &DatabaseConnection, redis_pool: &RedisPool) -> Result<(), String> {
+    let now = Utc::now();
+
+    let plan_ids: Vec<String> = MaintenancePlan::find()
+        .select_only()
+        .column(maintenance_plan::Column::PlanId)
+        .filter(maintenance_plan::Column::IsActive.eq(true))
+        .into_tuple()
+        .all(db)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let mut notified = Vec::new();
+    for plan_id in plan_ids {
+        // One broken plan shouldn't stop the rest from being raised.
+        match raise_plan_ticket(db, &plan_id, now).await {
+            Ok(managers) => notified.extend(managers),
+            Err(err) => eprintln!("Maintenance scheduler error for plan {}: {}", plan_id, err),
+        }
+    }
+    match flag_overdue_tickets(db, now).await {
+        Ok(notified_overdue) => notified.extend(notified_overdue),
+        Err(err) => eprintln!("Maintenance scheduler error while flagging overdue tickets: {}", err),
+    }
+    notified.extend(escalate_sla_breaches(db, now).await?);
+
+    let notification_tags: Vec<String> = notified.iter().map(|user_id| format!("notification:{}", user_id)).collect();
+    let mut tags: Vec<&str> = vec!["maintenance"];
+    tags.extend(notification_tags.iter().map(String::as_str));
+    cache_invalidate(redis_pool, &tags).await;
+
+    Ok(())
+}
+
+// Runs the schedule on a fixed interval for as long as the app is up.
+pub fn spawn_maintenance_scheduler(db: DatabaseConnection, redis_pool: RedisPool) -> JoinHandle<()> {
+    let every = env::var("MAINTENANCE_SCHEDULER_INTERVAL_SECONDS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .filter(|seconds| *seconds > 0)
+        .unwrap_or(DEFAULT_SCHEDULER_INTERVAL_SECONDS);
+
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(std::time::Duration::from_secs(every));
+        loop {
+            ticker.tick().await;
+            if let Err(err) = run_maintenance_schedule(&db, &redis_pool).await {
+                eprintln!("Maintenance scheduler error: {}", err);
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(value: &str) -> DateTime<Utc> {
+        parse_due(value).unwrap()
+    }
+
+    #[test]
+    fn raised_early_moves_past_the_raised_occurrence() {
+        let due = at("2026-10-20T08:00:00+00:00");
+        let now = at("2026-10-19T20:00:00+00:00");
+
+        let next = occurrence_after(due, MaintenanceFrequency::Daily, 1, 20, now);
+
+        assert_eq!(next, Some(at("2026-10-21T08:00:00+00:00")));
+    }
+
+    #[test]
+    fn skips_occurrences_missed_while_stopped() {
+        let due = at("2026-10-01T08:00:00+00:00");
+        let now = at("2026-10-18T09:00:00+00:00");
+
+        let next = occurrence_after(due, MaintenanceFrequency::Weekly, 1, 1, now);
+
+        assert_eq!(next, Some(at("2026-10-22T08:00:00+00:00")));
+    }
+
+    #[test]
+    fn monthly_plans_clamp_to_short_months() {
+        let due = at("2026-01-31T08:00:00+00:00");
+        let now = at("2026-01-31T08:00:00+00:00");
+
+        let next = occurrence_after(due, MaintenanceFrequency::Monthly, 1, 31, now);
+
+        assert_eq!(next, Some(at("2026-02-28T08:00:00+00:00")));
+    }
+
+    #[test]
+    fn monthly_plans_keep_their_day() {
+        let mut due = at("2026-01-31T08:00:00+00:00");
+        let mut seen = Vec::new();
+        for _ in 0..4 {
+            due = next_occurrence(due, MaintenanceFrequency::Monthly, 1, 31).unwrap();
+            seen.push(due);
+        }
+
+        assert_eq!(
+            seen,
+            vec![
+                at("2026-02-28T08:00:00+00:00"),
+                at("2026-03-31T08:00:00+00:00"),
+                at("2026-04-30T08:00:00+00:00"),
+                at("2026-05-31T08:00:00+00:00"),
+            ]
+        );
+    }
+
+    #[test]
+    fn monthly_intervals_skip_whole_months() {
+        let due = at("2026-10-15T08:00:00+00:00");
+
+        let next = next_occurrence(due, MaintenanceFrequency::Monthly, 3, 15);
+
+        assert_eq!(next, Some(at("2027-01-15T08:00:00+00:00")));
+    }
+}
@@ -0,0 +1,317 @@
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use entity::maintenance::{self, Entity as Maintenance};
+use entity::maintenance_sla_policy::{self, Entity as MaintenanceSlaPolicy};
+use entity::user::{self, Entity as User};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, TransactionTrait, ActiveValue::Set,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tauri::State;
+use crate::{ApiResponse, AppState, cache_invalidate};
+use crate::controllers::maintenance_handler::{MaintenanceResponse, OPEN_STATUSES};
+use crate::controllers::maintenance_plan_handler::notify_maintenance_managers;
+use crate::controllers::notification_handler::insert_notification;
+use crate::controllers::session_handler::authorize;
+
+// A breach goes to the maintenance managers first, then to the COO if it is still open after
+// the policy's escalation window.
+const ESCALATED_TO_MANAGERS: i32 = 1;
+const ESCALATED_TO_COO: i32 = 2;
+
+// Same shape as plan due times so report times compare correctly as strings.
+pub fn format_reported_at(at: DateTime<Utc>) -> String {
+    at.to_rfc3339_opts(SecondsFormat::Secs, false)
+}
+
+fn parse_time(value: Option<&str>) -> Option<DateTime<Utc>> {
+    value
+        .and_then(|value| DateTime::parse_from_rfc3339(value).ok())
+        .map(|at| at.with_timezone(&Utc))
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct TicketSla {
+    pub response_due_at: String,
+    pub resolution_due_at: String,
+    pub response_breached: bool,
+    pub resolution_breached: bool,
+    pub breached_at: Option<String>,
+}
+
+struct SlaClock {
+    response_due: DateTime<Utc>,
+    resolution_due: DateTime<Utc>,
+    response_breached: bool,
+    resolution_breached: bool,
+}
+
+impl SlaClock {
+    // Response is met once someone is assigned, resolution once the work is completed.
+    fn new(
+        policy: &maintenance_sla_policy::Model,
+        reported_at: DateTime<Utc>,
+        assigned_at: Option<DateTime<Utc>>,
+        completed_at: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let response_due = reported_at + Duration::minutes(i64::from(policy.response_minutes));
+        let resolution_due = reported_at + Duration::minutes(i64::from(policy.resolution_minutes));
+        SlaClock {
+            response_due,
+            resolution_due,
+            response_breached: assigned_at.unwrap_or(now) > response_due,
+            resolution_breached: completed_at.unwrap_or(now) > resolution_due,
+        }
+    }
+
+    fn breached_at(&self) -> Option<DateTime<Utc>> {
+        match (self.response_breached, self.resolution_breached) {
+            (true, _) => Some(self.response_due),
+            (false, true) => Some(self.resolution_due),
+            (false, false) => None,
+        }
+    }
+}
+
+pub async fn load_sla_policies<C: ConnectionTrait>(
+    db: &C,
+) -> Result<HashMap<String, maintenance_sla_policy::Model>, String> {
+    Ok(MaintenanceSlaPolicy::find()
+        .all(db)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .into_iter()
+        .map(|policy| (policy.maintenance_type.clone(), policy))
+        .collect())
+}
+
+// SLA status of a reactive ticket; planned work is tracked against its own due time instead.
+pub fn ticket_sla(
+    policies: &HashMap<String, maintenance_sla_policy::Model>,
+    ticket: &MaintenanceResponse,
+    now: DateTime<Utc>,
+) -> Option<TicketSla> {
+    if ticket.plan_id.is_some() {
+        return None;
+    }
+
+    let policy = policies.get(&ticket.r#type)?;
+    let reported_at = parse_time(ticket.reported_at.as_deref())?;
+    let clock = SlaClock::new(
+        policy,
+        reported_at,
+        parse_time(ticket.assigned_at.as_deref()),
+        parse_time(ticket.completed_at.as_deref()),
+        now,
+    );
+
+    Some(TicketSla {
+        response_due_at: format_reported_at(clock.response_due),
+        resolution_due_at: format_reported_at(clock.resolution_due),
+        response_breached: clock.response_breached,
+        resolution_breached: clock.resolution_breached,
+        breached_at: clock.breached_at().map(format_reported_at),
+    })
+}
+
+async fn notify_coo<C: ConnectionTrait>(db: &C, title: &str, message: &str) -> Result<Vec<String>, String> {
+    let officers: Vec<String> = User::find()
+        .select_only()
+        .column(user::Column::UserId)
+        .filter(user::Column::Role.eq("COO"))
+        .into_tuple()
+        .all(db)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    for officer_id in &officers {
+        insert_notification(db, officer_id.clone(), title.to_string(), message.to_string(), "Maintenance".to_string())
+            .await?;
+    }
+
+    Ok(officers)
+}
+
+// Moves each breached open ticket up the escalation ladder. Tickets are locked so several
+// running app instances never send the same escalation twice.
+pub async fn escalate_sla_breaches(db: &DatabaseConnection, now: DateTime<Utc>) -> Result<Vec<String>, String> {
+    let txn = db.begin().await.map_err(|e| format!("Database error: {}", e))?;
+
+    let policies = load_sla_policies(&txn).await?;
+
+    let tickets = Maintenance::find()
+        .filter(maintenance::Column::PlanId.is_null())
+        .filter(maintenance::Column::Status.is_in(OPEN_STATUSES))
+        .filter(maintenance::Column::EscalationLevel.lt(ESCALATED_TO_COO))
+        .filter(maintenance::Column::ReportedAt.is_not_null())
+        .lock_exclusive()
+        .all(&txn)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let mut notified = Vec::new();
+    for ticket in tickets {
+        let (Some(policy), Some(reported_at)) = (policies.get(&ticket.r#type), parse_time(ticket.reported_at.as_deref()))
+        else {
+            continue;
+        };
+
+        let clock = SlaClock::new(
+            policy,
+            reported_at,
+            parse_time(ticket.assigned_at.as_deref()),
+            parse_time(ticket.completed_at.as_deref()),
+            now,
+        );
+        let Some(breached_at) = clock.breached_at() else {
+            continue;
+        };
+
+        let target = if now >= breached_at + Duration::minutes(i64::from(policy.escalation_minutes)) {
+            ESCALATED_TO_COO
+        } else {
+            ESCALATED_TO_MANAGERS
+        };
+        if ticket.escalation_level >= target {
+            continue;
+        }
+
+        let missed = if clock.response_breached { "response" } else { "resolution" };
+        let message = format!(
+            "{} maintenance ticket {} on ride {} missed its {} target of {}",
+            ticket.r#type,
+            ticket.maintenance_id,
+            ticket.ride_id,
+            missed,
+            format_reported_at(breached_at)
+        );
+
+        if ticket.escalation_level < ESCALATED_TO_MANAGERS {
+            notified.extend(notify_maintenance_managers(&txn, "Maintenance SLA breached", &message).await?);
+        }
+        if target == ESCALATED_TO_COO {
+            notified.extend(notify_coo(&txn, "Maintenance SLA escalated", &message).await?);
+        }
+
+        let mut active_ticket: maintenance::ActiveModel = ticket.into();
+        active_ticket.escalation_level = Set(target);
+        active_ticket.escalated_at = Set(Some(format_reported_at(now)));
+        active_ticket
+            .update(&txn)
+            .await
+            .map_err(|e| format!("Failed to escalate maintenance: {}", e))?;
+    }
+
+    txn.commit().await.map_err(|e| format!("Database error: {}", e))?;
+    Ok(notified)
+}
+
+#[tauri::command]
+pub async fn view_sla_policies(
+    state: State<'_, AppState>,
+    session_token: String,
+) -> Result<ApiResponse<Vec<maintenance_sla_policy::Model>>, String> {
+    if let Err(message) = authorize(&state, &session_token, "view_sla_policies").await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    match MaintenanceSlaPolicy::find()
+        .order_by_asc(maintenance_sla_policy::Column::ResponseMinutes)
+        .all(&state.db)
+        .await
+    {
+        Ok(policies) => Ok(ApiResponse::success(policies)),
+        Err(err) => Ok(ApiResponse::error(format!("Database error: {}", err))),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct SaveSlaPolicyRequest {
+    pub maintenance_type: String,
+    pub response_minutes: i32,
+    pub resolution_minutes: i32,
+    pub escalation_minutes: i32,
+}
+
+// Creates the policy for a ticket type or replaces its targets. New targets apply to open
+// tickets straight away; escalations already sent are not repeated.
+#[tauri::command]
+pub async fn save_sla_policy(
+    state: State<'_, AppState>,
+    session_token: String,
+    payload: SaveSlaPolicyRequest,
+) -> Result<ApiResponse<maintenance_sla_policy::Model>, String> {
+    if let Err(message) = authorize(&state, &session_token, "save_sla_policy").await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    let maintenance_type = payload.maintenance_type.trim().to_string();
+    if maintenance_type.is_empty() {
+        return Ok(ApiResponse::error("An SLA policy needs a maintenance type".to_string()));
+    }
+
+    if payload.response_minutes <= 0 || payload.resolution_minutes <= 0 || payload.escalation_minutes <= 0 {
+        return Ok(ApiResponse::error("SLA targets must be a positive number of minutes".to_string()));
+    }
+
+    if payload.resolution_minutes < payload.response_minutes {
+        return Ok(ApiResponse::error("Resolution target cannot be shorter than the response target".to_string()));
+    }
+
+    let existing = match MaintenanceSlaPolicy::find_by_id(maintenance_type.clone()).one(&state.db).await {
+        Ok(existing) => existing,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    let saved = match existing {
+        Some(policy) => {
+            let mut active_policy: maintenance_sla_policy::ActiveModel = policy.into();
+            active_policy.response_minutes = Set(payload.response_minutes);
+            active_policy.resolution_minutes = Set(payload.resolution_minutes);
+            active_policy.escalation_minutes = Set(payload.escalation_minutes);
+            active_policy.update(&state.db).await
+        }
+        None => {
+            maintenance_sla_policy::ActiveModel {
+                maintenance_type: Set(maintenance_type),
+                response_minutes: Set(payload.response_minutes),
+                resolution_minutes: Set(payload.resolution_minutes),
+                escalation_minutes: Set(payload.escalation_minutes),
+            }
+            .insert(&state.db)
+            .await
+        }
+    };
+
+    match saved {
+        Ok(policy) => {
+            cache_invalidate(&state.redis_pool, &["maintenance"]).await;
+            Ok(ApiResponse::success(policy))
+        }
+        Err(err) => Ok(ApiResponse::error(format!("Failed to save SLA policy: {}", err))),
+    }
+}
+
+#[tauri::command]
+pub async fn delete_sla_policy(
+    state: State<'_, AppState>,
+    session_token: String,
+    maintenance_type: String,
+) -> Result<ApiResponse<String>, String> {
+    if let Err(message) = authorize(&state, &session_token, "delete_sla_policy").await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    match MaintenanceSlaPolicy::delete_by_id(maintenance_type.clone()).exec(&state.db).await {
+        Ok(result) if result.rows_affected == 0 => {
+            Ok(ApiResponse::error(format!("No SLA policy found for type: {}", maintenance_type)))
+        }
+        Ok(_) => {
+            cache_invalidate(&state.redis_pool, &["maintenance"]).await;
+            Ok(ApiResponse::success(format!("SLA policy for {} removed", maintenance_type)))
+        }
+        Err(err) => Ok(ApiResponse::error(format!("Failed to delete SLA policy: {}", err))),
+    }
+}
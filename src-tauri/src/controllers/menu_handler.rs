@@ -1,11 +1,12 @@
 use anyhow::Result;
-use base64::encode;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
 use entity::menu::{self, ActiveModel as MenuActiveModel, Entity as Menu};
 use sea_orm::{ActiveModelTrait, EntityTrait, QueryOrder, ActiveValue::Set};
 use serde::Deserialize;
 use serde::Serialize;
 use tauri::State;
-use crate::{ApiResponse, cache_get, cache_set, cache_delete, AppState};
+use crate::{ApiResponse, cache_get, cache_set_tagged, cache_invalidate, AppState};
+use crate::controllers::session_handler::authorize;
 
 #[derive(Serialize, Deserialize)]
 pub struct MenuResponse {
@@ -31,7 +32,12 @@ pub struct MenuCache {
 #[tauri::command]
 pub async fn view_all_menus(
     state: State<'_, AppState>,
+    session_token: String,
 ) -> Result<ApiResponse<Vec<MenuResponse>>, String> {
+    if let Err(message) = authorize(&state, &session_token, "view_all_menus").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     let cache_key = "get_all_menus_cache";
 
     if let Some(cached_menus) = cache_get::<Vec<MenuCache>>(&state.redis_pool, cache_key).await {
@@ -46,7 +52,7 @@ pub async fn view_all_menus(
                     description: cache.description,
                     price: cache.price,
                     available_quantity: cache.available_quantity,
-                    image: encode(&model.image),
+                    image: STANDARD.encode(&model.image),
                 });
             }
         }
@@ -72,7 +78,7 @@ pub async fn view_all_menus(
                     description: m.description.clone(),
                     price: m.price,
                     available_quantity: m.available_quantity,
-                    image: encode(&m.image),
+                    image: STANDARD.encode(&m.image),
                 })
                 .collect();
 
@@ -88,7 +94,7 @@ pub async fn view_all_menus(
                 })
                 .collect();
 
-            cache_set(&state.redis_pool, cache_key, &cache_data, 60).await;
+            cache_set_tagged(&state.redis_pool, cache_key, &cache_data, 60, &["menu"]).await;
 
             Ok(ApiResponse::success(formatted_menus))
         }
@@ -102,8 +108,13 @@ pub async fn view_all_menus(
 #[tauri::command]
 pub async fn view_menu(
     state: State<'_, AppState>,
+    session_token: String,
     menu_id: String
 ) -> Result<ApiResponse<MenuResponse>, String> {
+    if let Err(message) = authorize(&state, &session_token, "view_menu").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     let cache_key = format!("menu_{}", menu_id);
 
     if let Some(cached_menu) = cache_get::<MenuResponse>(&state.redis_pool, &cache_key).await {
@@ -119,10 +130,10 @@ pub async fn view_menu(
                 description: menu.description,
                 price: menu.price,
                 available_quantity: menu.available_quantity,
-                image: encode(&menu.image),
+                image: STANDARD.encode(&menu.image),
             };
 
-            cache_set(&state.redis_pool, &cache_key, &formatted_menu, 60).await;
+            cache_set_tagged(&state.redis_pool, &cache_key, &formatted_menu, 60, &[&format!("menu:{}", menu_id)]).await;
 
             Ok(ApiResponse::success(formatted_menu))
         }
@@ -145,8 +156,13 @@ pub struct CreateMenuRequest {
 #[tauri::command]
 pub async fn create_menu(
     state: State<'_, AppState>,
+    session_token: String,
     payload: CreateMenuRequest,
 ) -> Result<ApiResponse<menu::Model>, String> {
+    if let Err(message) = authorize(&state, &session_token, "create_menu").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     let new_menu = MenuActiveModel {
         menu_id: Set(payload.menu_id),
         restaurant_id: Set(payload.restaurant_id),
@@ -155,12 +171,11 @@ pub async fn create_menu(
         description: Set(payload.description),
         price: Set(payload.price),
         available_quantity: Set(payload.available_quantity),
-        ..Default::default()
     };
 
     match new_menu.insert(&state.db).await {
         Ok(menu) => {
-            cache_delete(&state.redis_pool, "get_all_menus_cache").await;
+            cache_invalidate(&state.redis_pool, &["menu"]).await;
             Ok(ApiResponse::success(menu))
         }
         Err(err) => Ok(ApiResponse::error(format!("Failed to create menu: {}", err))),
@@ -181,8 +196,13 @@ pub struct UpdateMenuRequest {
 #[tauri::command]
 pub async fn update_menu(
     state: State<'_, AppState>,
+    session_token: String,
     payload: UpdateMenuRequest,
 ) -> Result<ApiResponse<menu::Model>, String> {
+    if let Err(message) = authorize(&state, &session_token, "update_menu").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     match Menu::find_by_id(payload.menu_id.clone()).one(&state.db).await {
         Ok(Some(existing_menu)) => {
             let mut active_menu: MenuActiveModel = existing_menu.into();
@@ -195,7 +215,7 @@ pub async fn update_menu(
 
             match active_menu.update(&state.db).await {
                 Ok(updated_menu) => {
-                    cache_delete(&state.redis_pool, "get_all_menus_cache").await;
+                    cache_invalidate(&state.redis_pool, &["menu", &format!("menu:{}", updated_menu.menu_id)]).await;
                     Ok(ApiResponse::success(updated_menu))
                 }
                 Err(err) => Ok(ApiResponse::error(format!("Failed to update menu: {}", err))),
@@ -215,8 +235,13 @@ pub struct UpdateMenuQuantityRequest {
 #[tauri::command]
 pub async fn update_menu_quantity(
     state: State<'_, AppState>,
+    session_token: String,
     payload: UpdateMenuQuantityRequest,
 ) -> Result<ApiResponse<menu::Model>, String> {
+    if let Err(message) = authorize(&state, &session_token, "update_menu_quantity").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     match Menu::find_by_id(payload.menu_id.clone()).one(&state.db).await {
         Ok(Some(existing_menu)) => {
             let mut active_menu: MenuActiveModel = existing_menu.into();
@@ -224,7 +249,7 @@ pub async fn update_menu_quantity(
 
             match active_menu.update(&state.db).await {
                 Ok(updated_menu) => {
-                    cache_delete(&state.redis_pool, "get_all_menus_cache").await;
+                    cache_invalidate(&state.redis_pool, &["menu", &format!("menu:{}", updated_menu.menu_id)]).await;
                     Ok(ApiResponse::success(updated_menu))
                 }
                 Err(err) => Ok(ApiResponse::error(format!("Failed to update menu: {}", err))),
@@ -243,14 +268,19 @@ pub struct DeleteMenuRequest {
 #[tauri::command]
 pub async fn delete_menu(
     state: State<'_, AppState>,
+    session_token: String,
     payload: DeleteMenuRequest,
 ) -> Result<ApiResponse<()>, String> {
+    if let Err(message) = authorize(&state, &session_token, "delete_menu").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     match Menu::delete_by_id(payload.menu_id.clone()).exec(&state.db).await {
         Ok(delete_result) => {
             if delete_result.rows_affected == 0 {
                 return Ok(ApiResponse::error(format!("No menu found with ID: {}", payload.menu_id)));
             }
-            cache_delete(&state.redis_pool, "get_all_menus_cache").await;
+            cache_invalidate(&state.redis_pool, &["menu", &format!("menu:{}", payload.menu_id)]).await;
             Ok(ApiResponse::success(()))
         }
         Err(err) => Ok(ApiResponse::error(format!("Failed to delete menu: {}", err))),
@@ -15,4 +15,13 @@ pub mod proposal_handler;
 pub mod ride_manager_handler;
 pub mod maintenance_handler;
 pub mod official_chat_maintenance_handler;
-pub mod fnb_supervisor_handler;
\ No newline at end of file
+pub mod fnb_supervisor_handler;
+pub mod session_handler;
+pub mod wallet_handler;
+pub mod wait_time_estimator;
+pub mod fast_pass_handler;
+pub mod ride_availability_handler;
+pub mod maintenance_plan_handler;
+pub mod checklist_handler;
+pub mod spare_part_handler;
+pub mod maintenance_sla_handler;
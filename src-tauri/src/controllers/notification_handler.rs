@@ -1,8 +1,12 @@
 use anyhow::Result;
-use sea_orm::{EntityTrait, QueryFilter, ColumnTrait, ActiveModelTrait, Set, DatabaseConnection};
+use sea_orm::{
+    sea_query::Expr, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect, ColumnTrait, Condition,
+    ActiveModelTrait, Set,
+};
 use serde::Serialize;
 use tauri::State;
-use crate::{ApiResponse, cache_get, cache_set, AppState};
+use crate::{ApiResponse, cache_get, cache_set_tagged, cache_invalidate, page_limit, AppState};
+use crate::controllers::session_handler::authorize;
 use entity::notification::{self, Entity as Notification, ActiveModel as NotificationActiveModel};
 use chrono::Utc;
 use uuid::Uuid;
@@ -17,15 +21,16 @@ pub struct NotificationResponse {
     pub r#type: String,
 }
 
-#[tauri::command]
-pub async fn send_notification(
-    state: State<'_, AppState>,
+// Stores a notification for one recipient. Callers invalidate the recipient's
+// "notification:{id}" cache tag once their transaction, if any, has committed.
+pub async fn insert_notification<C: ConnectionTrait>(
+    db: &C,
     recipient_id: String,
     title: String,
     message: String,
     notif_type: String,
-) -> Result<ApiResponse<notification::Model>, String> {
-    let new_notification = NotificationActiveModel {
+) -> Result<notification::Model, String> {
+    NotificationActiveModel {
         notification_id: Set(Uuid::new_v4().to_string()),
         recipient_id: Set(recipient_id),
         title: Set(title),
@@ -33,35 +38,94 @@ pub async fn send_notification(
         date: Set(Utc::now().to_rfc3339()),
         is_read: Set(false),
         r#type: Set(notif_type),
-    };
+    }
+    .insert(db)
+    .await
+    .map_err(|err| format!("Failed to send notification: {}", err))
+}
+
+#[tauri::command]
+pub async fn send_notification(
+    state: State<'_, AppState>,
+    session_token: String,
+    recipient_id: String,
+    title: String,
+    message: String,
+    notif_type: String,
+) -> Result<ApiResponse<notification::Model>, String> {
+    if let Err(message) = authorize(&state, &session_token, "send_notification").await {
+        return Ok(ApiResponse::error(message));
+    }
 
-    match new_notification.insert(&state.db).await {
-        Ok(saved) => Ok(ApiResponse::success(saved)),
-        Err(err) => Ok(ApiResponse::error(format!("Failed to send notification: {}", err))),
+    match insert_notification(&state.db, recipient_id, title, message, notif_type).await {
+        Ok(saved) => {
+            cache_invalidate(&state.redis_pool, &[&format!("notification:{}", saved.recipient_id)]).await;
+            Ok(ApiResponse::success(saved))
+        }
+        Err(message) => Ok(ApiResponse::error(message)),
     }
 }
 
+// Notifications are paged newest first; `cursor` is the notification_id of the last one on the previous page.
 #[tauri::command]
 pub async fn view_notification(
     state: State<'_, AppState>,
-    user_id: String
+    session_token: String,
+    user_id: String,
+    limit: Option<u64>,
+    cursor: Option<String>,
 ) -> Result<ApiResponse<Vec<NotificationResponse>>, String> {
-    let cache_key = format!("notifications_{}", user_id);
+    let session = match authorize(&state, &session_token, "view_notification").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
 
-    if let Some(cached_notifications) = cache_get::<Vec<notification::Model>>(&state.redis_pool, &cache_key).await {
-        let formatted_notifications = cached_notifications.iter().map(|n| NotificationResponse {
-            notification_id: n.notification_id.clone(),
-            title: n.title.clone(),
-            message: n.message.clone(),
-            date: n.date.clone(),
-            is_read: n.is_read,
-            r#type: n.r#type.clone(),
-        }).collect();
-        return Ok(ApiResponse::success(formatted_notifications));
+    if !session.can_act_for(&user_id) {
+        return Ok(ApiResponse::error("Customers can only view their own notifications".to_string()));
     }
 
-    match Notification::find()
-        .filter(notification::Column::RecipientId.eq(user_id.clone()))
+    let page_size = page_limit(limit);
+    let cache_key = format!("notifications_{}_{}", user_id, page_size);
+
+    if cursor.is_none() {
+        if let Some(cached_notifications) = cache_get::<Vec<notification::Model>>(&state.redis_pool, &cache_key).await {
+            let formatted_notifications = cached_notifications.iter().map(|n| NotificationResponse {
+                notification_id: n.notification_id.clone(),
+                title: n.title.clone(),
+                message: n.message.clone(),
+                date: n.date.clone(),
+                is_read: n.is_read,
+                r#type: n.r#type.clone(),
+            }).collect();
+            return Ok(ApiResponse::success(formatted_notifications));
+        }
+    }
+
+    let mut query = Notification::find()
+        .filter(notification::Column::RecipientId.eq(user_id.clone()));
+
+    if let Some(cursor_id) = &cursor {
+        let last = match Notification::find_by_id(cursor_id.clone()).one(&state.db).await {
+            Ok(Some(last)) => last,
+            Ok(None) => return Ok(ApiResponse::error("Invalid cursor".to_string())),
+            Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+        };
+
+        query = query.filter(
+            Condition::any()
+                .add(notification::Column::Date.lt(last.date.clone()))
+                .add(
+                    Condition::all()
+                        .add(notification::Column::Date.eq(last.date))
+                        .add(notification::Column::NotificationId.lt(last.notification_id)),
+                ),
+        );
+    }
+
+    match query
+        .order_by_desc(notification::Column::Date)
+        .order_by_desc(notification::Column::NotificationId)
+        .limit(page_size)
         .all(&state.db)
         .await
     {
@@ -74,7 +138,9 @@ pub async fn view_notification(
                 is_read: n.is_read,
                 r#type: n.r#type.clone(),
             }).collect();
-            cache_set(&state.redis_pool, &cache_key, &notifications, 600).await;
+            if cursor.is_none() {
+                cache_set_tagged(&state.redis_pool, &cache_key, &notifications, 600, &[&format!("notification:{}", user_id)]).await;
+            }
             Ok(ApiResponse::success(formatted_notifications))
         }
         Err(err) => Ok(ApiResponse::error(format!("Failed to fetch notifications: {}", err))),
@@ -84,24 +150,29 @@ pub async fn view_notification(
 #[tauri::command]
 pub async fn mark_all_notifications_read(
     state: State<'_, AppState>,
+    session_token: String,
     user_id: String
 ) -> Result<ApiResponse<()>, String> {
-    let db: &DatabaseConnection = &state.db;
-    match Notification::find()
+    let session = match authorize(&state, &session_token, "mark_all_notifications_read").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    if !session.can_act_for(&user_id) {
+        return Ok(ApiResponse::error("Customers can only update their own notifications".to_string()));
+    }
+
+    match Notification::update_many()
+        .col_expr(notification::Column::IsRead, Expr::value(true))
         .filter(notification::Column::RecipientId.eq(user_id.clone()))
-        .all(db)
+        .filter(notification::Column::IsRead.eq(false))
+        .exec(&state.db)
         .await
     {
-        Ok(notifications) => {
-            for notification in notifications {
-                let mut active_model: NotificationActiveModel = notification.into();
-                active_model.is_read = Set(true);
-                if let Err(err) = active_model.update(db).await {
-                    return Ok(ApiResponse::error(format!("Failed to mark notifications as read: {}", err)));
-                }
-            }
+        Ok(_) => {
+            cache_invalidate(&state.redis_pool, &[&format!("notification:{}", user_id)]).await;
             Ok(ApiResponse::success(()))
         }
-        Err(err) => Ok(ApiResponse::error(format!("Failed to fetch notifications: {}", err))),
+        Err(err) => Ok(ApiResponse::error(format!("Failed to mark notifications as read: {}", err))),
     }
 }
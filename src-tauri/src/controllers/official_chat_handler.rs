@@ -1,4 +1,5 @@
 use crate::AppState;
+use crate::controllers::session_handler::authorize;
 use firestore::{
     FirestoreDb, FirestoreListenEvent, FirestoreListenerTarget,
     FirestoreMemListenStateStorage,
@@ -23,8 +24,14 @@ pub struct OfficialChatMessage {
 pub async fn listen_to_official_chat(
     app: AppHandle,
     state: State<'_, AppState>,
+    session_token: String,
     customer_id: String,
 ) -> Result<(), String> {
+    let session = authorize(&state, &session_token, "listen_to_official_chat").await?;
+    if !session.can_act_for(&customer_id) {
+        return Err("Customers can only access their own chat".to_string());
+    }
+
     let firestore = {
         let fs_lock = state.firestore.lock().await;
         fs_lock.clone().ok_or("Firestore instance not initialized")?
@@ -115,10 +122,19 @@ pub async fn listen_to_official_chat(
 #[tauri::command]
 pub async fn send_official_chat_message(
     state: State<'_, AppState>,
+    session_token: String,
     customer_id: String,
     sender_id: String,
     content: String,
 ) -> Result<(), String> {
+    let session = authorize(&state, &session_token, "send_official_chat_message").await?;
+    if !session.can_act_for(&customer_id) {
+        return Err("Customers can only access their own chat".to_string());
+    }
+    if sender_id != session.user_id {
+        return Err("Sender does not match the signed-in user".to_string());
+    }
+
     let firestore = {
         let fs_lock = state.firestore.lock().await;
         fs_lock.clone().ok_or("Firestore instance not initialized")?
@@ -177,7 +193,10 @@ pub struct CustomerInfo {
 #[tauri::command]
 pub async fn fetch_official_chat_customers(
     state: State<'_, AppState>,
+    session_token: String,
 ) -> Result<Vec<CustomerInfo>, String> {
+    authorize(&state, &session_token, "fetch_official_chat_customers").await?;
+
     let firestore = {
         let fs_lock = state.firestore.lock().await;
         fs_lock.clone().ok_or("Firestore instance not initialized")?
@@ -211,8 +230,14 @@ pub async fn fetch_official_chat_customers(
 #[tauri::command]
 pub async fn fetch_official_chat_messages(
     state: State<'_, AppState>,
+    session_token: String,
     customer_id: String,
 ) -> Result<Vec<OfficialChatMessage>, String> {
+    let session = authorize(&state, &session_token, "fetch_official_chat_messages").await?;
+    if !session.can_act_for(&customer_id) {
+        return Err("Customers can only access their own chat".to_string());
+    }
+
     let firestore = {
         let fs_lock = state.firestore.lock().await;
         fs_lock.clone().ok_or("Firestore instance not initialized")?
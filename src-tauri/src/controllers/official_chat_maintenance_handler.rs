@@ -1,4 +1,5 @@
 use crate::AppState;
+use crate::controllers::session_handler::authorize;
 use firestore::{
     FirestoreDb, FirestoreListenEvent, FirestoreListenerTarget,
     FirestoreMemListenStateStorage,
@@ -31,8 +32,11 @@ pub struct CustomerInfo {
 pub async fn listen_to_maintenance_chat(
     app: AppHandle,
     state: State<'_, AppState>,
+    session_token: String,
     ride_manager_id: String,
 ) -> Result<(), String> {
+    authorize(&state, &session_token, "listen_to_maintenance_chat").await?;
+
     let firestore = {
         let fs_lock = state.firestore.lock().await;
         fs_lock.clone().ok_or("Firestore instance not initialized")?
@@ -121,10 +125,16 @@ pub async fn listen_to_maintenance_chat(
 #[tauri::command]
 pub async fn send_maintenance_chat_message(
     state: State<'_, AppState>,
+    session_token: String,
     ride_manager_id: String,
     sender_id: String,
     content: String,
 ) -> Result<(), String> {
+    let session = authorize(&state, &session_token, "send_maintenance_chat_message").await?;
+    if sender_id != session.user_id {
+        return Err("Sender does not match the signed-in user".to_string());
+    }
+
     let firestore = {
         let fs_lock = state.firestore.lock().await;
         fs_lock.clone().ok_or("Firestore instance not initialized")?
@@ -173,7 +183,10 @@ pub async fn send_maintenance_chat_message(
 #[tauri::command]
 pub async fn fetch_maintenance_chat_ride_managers(
     state: State<'_, AppState>,
+    session_token: String,
 ) -> Result<Vec<CustomerInfo>, String> {
+    authorize(&state, &session_token, "fetch_maintenance_chat_ride_managers").await?;
+
     let firestore = {
         let fs_lock = state.firestore.lock().await;
         fs_lock.clone().ok_or("Firestore instance not initialized")?
@@ -205,8 +218,11 @@ pub async fn fetch_maintenance_chat_ride_managers(
 #[tauri::command]
 pub async fn fetch_maintenance_chat_messages(
     state: State<'_, AppState>,
+    session_token: String,
     ride_manager_id: String,
 ) -> Result<Vec<OfficialChatMessage>, String> {
+    authorize(&state, &session_token, "fetch_maintenance_chat_messages").await?;
+
     let firestore = {
         let fs_lock = state.firestore.lock().await;
         fs_lock.clone().ok_or("Firestore instance not initialized")?
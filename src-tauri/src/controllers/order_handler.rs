@@ -1,97 +1,370 @@
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use entity::order::{self, ActiveModel as OrderActiveModel, Entity as Order};
-use sea_orm::{ActiveModelTrait, EntityTrait, QueryOrder, ActiveValue::Set};
+use entity::order_line::{self, Entity as OrderLine};
+use entity::order_status_history::{self, Entity as OrderStatusHistory};
+use entity::menu::{self, Entity as Menu};
+use entity::ride::Entity as Ride;
+use entity::sea_orm_active_enums::{OrderStatus, WalletTransactionKind};
+use entity::souvenir::{self, Entity as Souvenir};
+use sea_orm::{
+    ActiveEnum, ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, Select, TransactionTrait, ActiveValue::Set,
+};
 use serde::{Serialize, Deserialize};
-use tauri::State;
-use crate::{ApiResponse, cache_get, cache_set, cache_delete, AppState};
+use tauri::{AppHandle, Emitter, State};
+use std::collections::HashMap;
+use uuid::Uuid;
+use crate::{ApiResponse, cache_get, cache_set_tagged, cache_invalidate, page_limit, AppState};
+use crate::controllers::session_handler::{authorize, Session};
+use crate::controllers::wallet_handler::{post_wallet_entry, to_major_units, to_minor_units};
+
+#[derive(Serialize, Deserialize)]
+pub struct OrderLineResponse {
+    pub line_id: String,
+    pub item_id: String,
+    pub item_name: String,
+    pub unit_price: f64,
+    pub quantity: i32,
+    pub subtotal: f64,
+}
 
 #[derive(Serialize, Deserialize)]
 pub struct OrderResponse {
     pub order_id: String,
     pub customer_id: String,
     pub item_type: String,
-    pub item_id: String,
-    pub quantity: i32,
     pub date: String,
     pub is_paid: bool,
-    pub status: Option<String>,
+    pub status: Option<OrderStatus>,
+    pub restaurant_id: Option<String>,
+    pub lines: Vec<OrderLineResponse>,
+    pub total: f64,
 }
 
-#[tauri::command]
-pub async fn view_all_orders(
-    state: State<'_, AppState>,
-) -> Result<ApiResponse<Vec<OrderResponse>>, String> {
-    let cache_key = "get_all_orders_cache";
+fn order_total(lines: &[order_line::Model]) -> i64 {
+    lines.iter().map(|l| l.unit_price * i64::from(l.quantity)).sum()
+}
+
+fn to_order_response(order: order::Model, lines: Vec<order_line::Model>) -> OrderResponse {
+    let total = order_total(&lines);
 
-    if let Some(cached_orders) = cache_get::<Vec<OrderResponse>>(&state.redis_pool, cache_key).await {
-        return Ok(ApiResponse::success(cached_orders));
+    OrderResponse {
+        order_id: order.order_id,
+        customer_id: order.customer_id,
+        item_type: order.item_type,
+        date: order.date,
+        is_paid: order.is_paid,
+        status: order.status,
+        restaurant_id: order.restaurant_id,
+        lines: lines
+            .into_iter()
+            .map(|l| OrderLineResponse {
+                subtotal: to_major_units(l.unit_price * i64::from(l.quantity)),
+                unit_price: to_major_units(l.unit_price),
+                line_id: l.line_id,
+                item_id: l.item_id,
+                item_name: l.item_name,
+                quantity: l.quantity,
+            })
+            .collect(),
+        total: to_major_units(total),
     }
+}
 
-    match Order::find()
+async fn load_order_lines<C: ConnectionTrait>(db: &C, order_id: &str) -> Result<Vec<order_line::Model>, String> {
+    OrderLine::find()
+        .filter(order_line::Column::OrderId.eq(order_id))
+        .order_by_asc(order_line::Column::LineId)
+        .all(db)
+        .await
+        .map_err(|e| format!("Database error: {}", e))
+}
+
+// Kitchen lifecycle of restaurant orders: each legal move and the roles allowed to make it.
+const ORDER_TRANSITIONS: &[(OrderStatus, OrderStatus, &[&str])] = &[
+    (OrderStatus::Placed, OrderStatus::Cooking, &["Chef"]),
+    (OrderStatus::Cooking, OrderStatus::Ready, &["Chef"]),
+    (OrderStatus::Ready, OrderStatus::Served, &["Waiter"]),
+    (OrderStatus::Ready, OrderStatus::PickedUp, &["Waiter", "Customer"]),
+    (OrderStatus::Placed, OrderStatus::Cancelled, &["Customer", "Chef", "Waiter"]),
+    (OrderStatus::Cooking, OrderStatus::Cancelled, &["Chef"]),
+    // Recalls of tickets bumped by mistake.
+    (OrderStatus::Ready, OrderStatus::Cooking, &["Chef"]),
+    (OrderStatus::Served, OrderStatus::Ready, &["Waiter"]),
+];
+
+fn transition_roles(from: OrderStatus, to: OrderStatus) -> Option<&'static [&'static str]> {
+    ORDER_TRANSITIONS
+        .iter()
+        .find(|(f, t, _)| *f == from && *t == to)
+        .map(|(_, _, roles)| *roles)
+}
+
+async fn record_status_change<C: ConnectionTrait>(
+    db: &C,
+    order_id: &str,
+    from_status: Option<OrderStatus>,
+    to_status: OrderStatus,
+    actor_id: &str,
+) -> Result<(), String> {
+    order_status_history::ActiveModel {
+        history_id: Set(format!("OSH-{}", Uuid::new_v4())),
+        order_id: Set(order_id.to_string()),
+        from_status: Set(from_status),
+        to_status: Set(to_status),
+        actor_id: Set(actor_id.to_string()),
+        changed_at: Set(Utc::now().to_rfc3339()),
+    }
+    .insert(db)
+    .await
+    .map_err(|e| format!("Failed to record status change: {}", e))?;
+
+    Ok(())
+}
+
+#[derive(Serialize, Clone)]
+struct OrderEvent {
+    kind: &'static str,
+    order_id: String,
+    customer_id: String,
+    restaurant_id: Option<String>,
+    status: Option<OrderStatus>,
+    is_paid: bool,
+}
+
+// Pushes an order change to the customer's screens and, for restaurant orders, to that
+// restaurant's kitchen screens so neither has to poll.
+fn emit_order_event(app: &AppHandle, kind: &'static str, order: &order::Model) {
+    let event = OrderEvent {
+        kind,
+        order_id: order.order_id.clone(),
+        customer_id: order.customer_id.clone(),
+        restaurant_id: order.restaurant_id.clone(),
+        status: order.status,
+        is_paid: order.is_paid,
+    };
+
+    if let Err(e) = app.emit(&format!("order-update:customer:{}", order.customer_id), event.clone()) {
+        eprintln!("❌ Emit error: {:?}", e);
+    }
+
+    if let Some(restaurant_id) = &order.restaurant_id {
+        if let Err(e) = app.emit(&format!("order-update:restaurant:{}", restaurant_id), event) {
+            eprintln!("❌ Emit error: {:?}", e);
+        }
+    }
+}
+
+// Drops the staff order lists, the customer's own lists and, when lines were paid for or
+// refunded, the customer's wallet and the stock caches of the items on those lines.
+async fn clear_order_cache(state: &AppState, order: &order::Model, settled_lines: &[order_line::Model]) {
+    let mut tags = vec!["order".to_string(), format!("order:{}", order.customer_id)];
+
+    if !settled_lines.is_empty() {
+        tags.push("user".to_string());
+        tags.push(format!("user:{}", order.customer_id));
+
+        let item_tag = match order.item_type.as_str() {
+            "restaurant" => Some("menu"),
+            "store" => Some("souvenir"),
+            _ => None,
+        };
+        if let Some(item_tag) = item_tag {
+            tags.push(item_tag.to_string());
+            tags.extend(settled_lines.iter().map(|l| format!("{}:{}", item_tag, l.item_id)));
+        }
+    }
+
+    let tags: Vec<&str> = tags.iter().map(String::as_str).collect();
+    cache_invalidate(&state.redis_pool, &tags).await;
+}
+
+// Reads one page of `query` oldest first, keyed on (date, order_id) so it stays stable while
+// orders are added, and attaches each order's lines.
+async fn load_order_page(
+    state: &AppState,
+    mut query: Select<Order>,
+    page_size: u64,
+    cursor: Option<&String>,
+) -> Result<Vec<OrderResponse>, String> {
+    if let Some(cursor_id) = cursor {
+        let last = Order::find_by_id(cursor_id.clone())
+            .one(&state.db)
+            .await
+            .map_err(|e| format!("Database error: {}", e))?
+            .ok_or_else(|| "Invalid cursor".to_string())?;
+
+        query = query.filter(
+            Condition::any()
+                .add(order::Column::Date.gt(last.date.clone()))
+                .add(
+                    Condition::all()
+                        .add(order::Column::Date.eq(last.date))
+                        .add(order::Column::OrderId.gt(last.order_id)),
+                ),
+        );
+    }
+
+    let orders = query
+        .order_by_asc(order::Column::Date)
         .order_by_asc(order::Column::OrderId)
+        .limit(page_size)
         .all(&state.db)
         .await
-    {
-        Ok(orders) => {
-            let formatted_orders: Vec<OrderResponse> = orders
-                .iter()
-                .map(|o| OrderResponse {
-                    order_id: o.order_id.clone(),
-                    customer_id: o.customer_id.clone(),
-                    item_type: o.item_type.clone(),
-                    item_id: o.item_id.clone(),
-                    date: o.date.clone(),
-                    quantity: o.quantity,
-                    is_paid: o.is_paid,
-                    status: o.status.clone(),
-                })
-                .collect();
+        .map_err(|e| format!("Failed to fetch orders: {}", e))?;
+
+    let order_ids: Vec<String> = orders.iter().map(|o| o.order_id.clone()).collect();
+    let lines = OrderLine::find()
+        .filter(order_line::Column::OrderId.is_in(order_ids))
+        .order_by_asc(order_line::Column::LineId)
+        .all(&state.db)
+        .await
+        .map_err(|e| format!("Failed to fetch order lines: {}", e))?;
+
+    let mut lines_by_order: HashMap<String, Vec<order_line::Model>> = HashMap::new();
+    for line in lines {
+        lines_by_order.entry(line.order_id.clone()).or_default().push(line);
+    }
+
+    Ok(orders
+        .into_iter()
+        .map(|o| {
+            let lines = lines_by_order.remove(&o.order_id).unwrap_or_default();
+            to_order_response(o, lines)
+        })
+        .collect())
+}
+
+// Staff listing across customers, paged like view_orders; `order_type` and `restaurant_id`
+// narrow it down when given.
+#[tauri::command]
+pub async fn view_all_orders(
+    state: State<'_, AppState>,
+    session_token: String,
+    order_type: Option<String>,
+    restaurant_id: Option<String>,
+    limit: Option<u64>,
+    cursor: Option<String>,
+) -> Result<ApiResponse<Vec<OrderResponse>>, String> {
+    if let Err(message) = authorize(&state, &session_token, "view_all_orders").await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    let page_size = page_limit(limit);
+    let cache_key = format!(
+        "all_orders_{}_{}_{}",
+        order_type.as_deref().unwrap_or("any"),
+        restaurant_id.as_deref().unwrap_or("any"),
+        page_size
+    );
 
-            cache_set(&state.redis_pool, cache_key, &formatted_orders, 60).await;
-            Ok(ApiResponse::success(formatted_orders))
+    if cursor.is_none() {
+        if let Some(cached_orders) = cache_get::<Vec<OrderResponse>>(&state.redis_pool, &cache_key).await {
+            return Ok(ApiResponse::success(cached_orders));
         }
-        Err(err) => Ok(ApiResponse::error(format!("Database error: {}", err))),
     }
+
+    let mut query = Order::find();
+    if let Some(order_type) = &order_type {
+        query = query.filter(order::Column::ItemType.eq(order_type));
+    }
+    if let Some(restaurant_id) = &restaurant_id {
+        query = query.filter(order::Column::RestaurantId.eq(restaurant_id));
+    }
+
+    let formatted_orders = match load_order_page(&state, query, page_size, cursor.as_ref()).await {
+        Ok(formatted_orders) => formatted_orders,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    if cursor.is_none() {
+        cache_set_tagged(&state.redis_pool, &cache_key, &formatted_orders, 60, &["order"]).await;
+    }
+    Ok(ApiResponse::success(formatted_orders))
 }
 
+// Orders are paged oldest first; `cursor` is the order_id of the last order on the previous page.
 #[tauri::command]
 pub async fn view_orders(
     state: State<'_, AppState>,
+    session_token: String,
     customer_id: String,
-    order_type: String
+    order_type: String,
+    limit: Option<u64>,
+    cursor: Option<String>,
 ) -> Result<ApiResponse<Vec<OrderResponse>>, String> {
-    let cache_key = format!("orders_{}_{}", customer_id, order_type);
+    let session = match authorize(&state, &session_token, "view_orders").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
 
-    if let Some(cached_orders) = cache_get::<Vec<OrderResponse>>(&state.redis_pool, &cache_key).await {
-        return Ok(ApiResponse::success(cached_orders));
+    if !session.can_act_for(&customer_id) {
+        return Ok(ApiResponse::error("Customers can only view their own orders".to_string()));
     }
 
-    match Order::find()
-        .order_by_asc(order::Column::OrderId)
-        .all(&state.db)
-        .await
-    {
-        Ok(orders) => {
-            let formatted_orders: Vec<OrderResponse> = orders
-                .iter()
-                .filter(|o| o.customer_id == customer_id && o.item_type == order_type)
-                .map(|o| OrderResponse {
-                    order_id: o.order_id.clone(),
-                    customer_id: o.customer_id.clone(),
-                    item_type: o.item_type.clone(),
-                    item_id: o.item_id.clone(),
-                    date: o.date.clone(),
-                    quantity: o.quantity,
-                    is_paid: o.is_paid,
-                    status: o.status.clone(),
-                })
-                .collect();
+    let page_size = page_limit(limit);
+    let cache_key = format!("orders_{}_{}_{}", customer_id, order_type, page_size);
 
-            cache_set(&state.redis_pool, &cache_key, &formatted_orders, 60).await;
-            Ok(ApiResponse::success(formatted_orders))
+    // Only first pages are cached; deeper pages are cheap keyset reads.
+    if cursor.is_none() {
+        if let Some(cached_orders) = cache_get::<Vec<OrderResponse>>(&state.redis_pool, &cache_key).await {
+            return Ok(ApiResponse::success(cached_orders));
         }
-        Err(err) => Ok(ApiResponse::error(format!("Failed to fetch orders: {}", err))),
     }
+
+    let query = Order::find()
+        .filter(order::Column::CustomerId.eq(&customer_id))
+        .filter(order::Column::ItemType.eq(&order_type));
+
+    let formatted_orders = match load_order_page(&state, query, page_size, cursor.as_ref()).await {
+        Ok(formatted_orders) => formatted_orders,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    if cursor.is_none() {
+        let customer_tag = format!("order:{}", customer_id);
+        cache_set_tagged(&state.redis_pool, &cache_key, &formatted_orders, 60, &[&customer_tag]).await;
+    }
+    Ok(ApiResponse::success(formatted_orders))
+}
+
+struct PricedItem {
+    name: String,
+    unit_price: i64,
+    restaurant_id: Option<String>,
+}
+
+// Returns the name and unit price (minor units) of a catalogue item so order lines can
+// snapshot them at the moment they are added to the cart.
+async fn price_item<C: ConnectionTrait>(db: &C, item_type: &str, item_id: &str) -> Result<PricedItem, String> {
+    match item_type {
+        "restaurant" => Menu::find_by_id(item_id.to_string())
+            .one(db)
+            .await
+            .map_err(|e| format!("Database error: {}", e))?
+            .map(|m| PricedItem { name: m.name, unit_price: to_minor_units(m.price), restaurant_id: Some(m.restaurant_id) })
+            .ok_or_else(|| format!("No menu found with ID: {}", item_id)),
+        "store" => Souvenir::find_by_id(item_id.to_string())
+            .one(db)
+            .await
+            .map_err(|e| format!("Database error: {}", e))?
+            .map(|s| PricedItem { name: s.name, unit_price: to_minor_units(s.price), restaurant_id: None })
+            .ok_or_else(|| format!("No souvenir found with ID: {}", item_id)),
+        "ride" => Ride::find_by_id(item_id.to_string())
+            .one(db)
+            .await
+            .map_err(|e| format!("Database error: {}", e))?
+            .map(|r| PricedItem { name: r.name, unit_price: to_minor_units(r.price), restaurant_id: None })
+            .ok_or_else(|| format!("No ride found with ID: {}", item_id)),
+        other => Err(format!("Unknown order item type: {}", other)),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct OrderLineRequest {
+    pub item_id: String,
+    pub quantity: i32,
 }
 
 #[derive(Deserialize)]
@@ -99,141 +372,738 @@ pub struct CreateOrderRequest {
     pub order_id: String,
     pub customer_id: String,
     pub item_type: String,
-    pub item_id: String,
-    pub date: String,
-    pub quantity: i32,
-    pub is_paid: bool,
+    pub lines: Vec<OrderLineRequest>,
 }
 
 #[tauri::command]
 pub async fn create_order(
+    app: AppHandle,
     state: State<'_, AppState>,
+    session_token: String,
     payload: CreateOrderRequest,
-) -> Result<ApiResponse<order::Model>, String> {
+) -> Result<ApiResponse<OrderResponse>, String> {
+    let session = match authorize(&state, &session_token, "create_order").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    if !session.can_act_for(&payload.customer_id) {
+        return Ok(ApiResponse::error("Customers can only place orders for themselves".to_string()));
+    }
+
+    if payload.lines.is_empty() {
+        return Ok(ApiResponse::error("An order needs at least one item".to_string()));
+    }
+
+    if payload.lines.iter().any(|l| l.quantity <= 0) {
+        return Ok(ApiResponse::error("Quantity must be at least 1".to_string()));
+    }
+
+    // Only restaurant orders go through the kitchen lifecycle.
     let status = if payload.item_type == "restaurant" {
-        Some("Waiting for Cooking".to_string())
+        Some(OrderStatus::Placed)
     } else {
         None
     };
 
+    let mut priced_lines = Vec::with_capacity(payload.lines.len());
+    for line in payload.lines {
+        match price_item(&state.db, &payload.item_type, &line.item_id).await {
+            Ok(priced) => priced_lines.push((line, priced)),
+            Err(message) => return Ok(ApiResponse::error(message)),
+        }
+    }
+
+    // A restaurant cart is cooked by a single kitchen, so every line must come from it.
+    let restaurant_id = priced_lines.first().and_then(|(_, priced)| priced.restaurant_id.clone());
+    if priced_lines.iter().any(|(_, priced)| priced.restaurant_id != restaurant_id) {
+        return Ok(ApiResponse::error("All items in an order must come from the same restaurant".to_string()));
+    }
+
+    let txn = match state.db.begin().await {
+        Ok(txn) => txn,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
     let new_order = OrderActiveModel {
-        order_id: Set(payload.order_id),
-        customer_id: Set(payload.customer_id),
-        item_type: Set(payload.item_type),
-        item_id: Set(payload.item_id),
-        date: Set(payload.date),
-        quantity: Set(payload.quantity),
-        is_paid: Set(payload.is_paid),
+        order_id: Set(payload.order_id.clone()),
+        customer_id: Set(payload.customer_id.clone()),
+        item_type: Set(payload.item_type.clone()),
+        date: Set(Utc::now().to_rfc3339()),
+        // Orders are only marked paid by checkout_order once the wallet has been debited.
+        is_paid: Set(false),
         status: Set(status),
-        ..Default::default()
+        restaurant_id: Set(restaurant_id),
+    };
+
+    let order = match new_order.insert(&txn).await {
+        Ok(order) => order,
+        Err(err) => return Ok(ApiResponse::error(format!("Failed to create order: {}", err))),
     };
 
-    match new_order.insert(&state.db).await {
-        Ok(order) => {
-            cache_delete(&state.redis_pool, "get_all_orders_cache").await;
-            Ok(ApiResponse::success(order))
+    if let Some(status) = order.status {
+        if let Err(message) = record_status_change(&txn, &order.order_id, None, status, &session.user_id).await {
+            return Ok(ApiResponse::error(message));
         }
-        Err(err) => Ok(ApiResponse::error(format!("Failed to create order: {}", err))),
     }
+
+    let mut lines = Vec::with_capacity(priced_lines.len());
+    for (line, priced) in priced_lines {
+        let new_line = order_line::ActiveModel {
+            line_id: Set(format!("OL-{}", Uuid::new_v4())),
+            order_id: Set(order.order_id.clone()),
+            item_id: Set(line.item_id),
+            item_name: Set(priced.name),
+            unit_price: Set(priced.unit_price),
+            quantity: Set(line.quantity),
+        };
+
+        match new_line.insert(&txn).await {
+            Ok(created) => lines.push(created),
+            Err(err) => return Ok(ApiResponse::error(format!("Failed to create order: {}", err))),
+        }
+    }
+
+    if let Err(err) = txn.commit().await {
+        return Ok(ApiResponse::error(format!("Failed to create order: {}", err)));
+    }
+
+    clear_order_cache(&state, &order, &[]).await;
+    emit_order_event(&app, "created", &order);
+    Ok(ApiResponse::success(to_order_response(order, lines)))
 }
 
 #[derive(Deserialize)]
 pub struct UpdateOrderStatusRequest {
     pub order_id: String,
-    pub status: String,
+    pub status: OrderStatus,
+}
+
+// Moves a restaurant order along its lifecycle inside one transaction. `choose_next` picks the
+// target status from the current one, which lets bump/recall share the checks with
+// update_order_status.
+async fn move_order_status(
+    state: &AppState,
+    session: &Session,
+    order_id: &str,
+    choose_next: impl FnOnce(OrderStatus) -> Result<OrderStatus, String>,
+) -> Result<order::Model, String> {
+    let txn = state.db.begin().await.map_err(|e| format!("Database error: {}", e))?;
+
+    let existing_order = Order::find_by_id(order_id.to_string())
+        .lock_exclusive()
+        .one(&txn)
+        .await
+        .map_err(|e| format!("Database error while updating order: {}", e))?
+        .ok_or_else(|| format!("No order found with ID: {}", order_id))?;
+
+    if !session.can_act_for(&existing_order.customer_id) {
+        return Err("Customers can only modify their own orders".to_string());
+    }
+
+    // Kitchen staff only work the tickets of the restaurant they are assigned to.
+    if !session.is_customer() {
+        match &session.restaurant_id {
+            None => return Err("You are not assigned to a restaurant".to_string()),
+            Some(restaurant_id) if existing_order.restaurant_id.as_ref() != Some(restaurant_id) => {
+                return Err("This order belongs to another restaurant".to_string());
+            }
+            Some(_) => {}
+        }
+    }
+
+    let current = existing_order
+        .status
+        .ok_or_else(|| "Only restaurant orders have a kitchen status".to_string())?;
+    let next = choose_next(current)?;
+
+    match transition_roles(current, next) {
+        None => {
+            return Err(format!("Cannot move an order from {} to {}", current.to_value(), next.to_value()));
+        }
+        Some(roles) if !roles.contains(&session.role.as_str()) => {
+            return Err(format!(
+                "Role {} cannot move an order from {} to {}",
+                session.role,
+                current.to_value(),
+                next.to_value()
+            ));
+        }
+        Some(_) => {}
+    }
+
+    if next == OrderStatus::Cooking && !existing_order.is_paid {
+        return Err("Order has not been paid yet".to_string());
+    }
+
+    // Cancelling a paid order gives the portions back to the menu and refunds the customer.
+    let mut refunded_lines = Vec::new();
+    if next == OrderStatus::Cancelled && existing_order.is_paid {
+        let mut lines = load_order_lines(&txn, &existing_order.order_id).await?;
+        // Same lock order as checkout_order.
+        lines.sort_by(|a, b| a.item_id.cmp(&b.item_id));
+
+        for line in &lines {
+            let menu = match Menu::find_by_id(line.item_id.clone())
+                .lock_exclusive()
+                .one(&txn)
+                .await
+                .map_err(|e| format!("Database error: {}", e))?
+            {
+                Some(menu) => menu,
+                None => continue,
+            };
+
+            let restocked = menu.available_quantity + line.quantity;
+            let mut active_menu: menu::ActiveModel = menu.into();
+            active_menu.available_quantity = Set(restocked);
+            active_menu
+                .update(&txn)
+                .await
+                .map_err(|e| format!("Failed to update menu quantity: {}", e))?;
+        }
+
+        post_wallet_entry(
+            &txn,
+            &existing_order.customer_id,
+            WalletTransactionKind::Refund,
+            order_total(&lines),
+            Some(existing_order.order_id.clone()),
+            format!("Refund for cancelled order {}", existing_order.order_id),
+        )
+        .await?;
+
+        refunded_lines = lines;
+    }
+
+    let mut active_order: OrderActiveModel = existing_order.into();
+    active_order.status = Set(Some(next));
+
+    let updated_order = active_order
+        .update(&txn)
+        .await
+        .map_err(|e| format!("Failed to update order: {}", e))?;
+
+    record_status_change(&txn, &updated_order.order_id, Some(current), next, &session.user_id).await?;
+
+    txn.commit().await.map_err(|e| format!("Failed to update order: {}", e))?;
+
+    clear_order_cache(state, &updated_order, &refunded_lines).await;
+
+    Ok(updated_order)
 }
 
 #[tauri::command]
 pub async fn update_order_status(
+    app: AppHandle,
     state: State<'_, AppState>,
+    session_token: String,
     payload: UpdateOrderStatusRequest,
 ) -> Result<ApiResponse<order::Model>, String> {
-    match Order::find_by_id(payload.order_id.clone()).one(&state.db).await {
-        Ok(Some(existing_order)) => {
-            let mut active_order: OrderActiveModel = existing_order.into();
-
-            active_order.status = Set(Some(payload.status));
-
-            match active_order.update(&state.db).await {
-                Ok(updated_order) => {
-                    cache_delete(&state.redis_pool, "get_all_orders_cache").await;
-                    Ok(ApiResponse::success(updated_order))
-                }
-                Err(err) => Ok(ApiResponse::error(format!("Failed to update order: {}", err))),
-            }
+    let session = match authorize(&state, &session_token, "update_order_status").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    match move_order_status(&state, &session, &payload.order_id, |_| Ok(payload.status)).await {
+        Ok(updated_order) => {
+            emit_order_event(&app, "status_changed", &updated_order);
+            Ok(ApiResponse::success(updated_order))
         }
-        Ok(None) => Ok(ApiResponse::error(format!("No order found with ID: {}", payload.order_id))),
-        Err(err) => Ok(ApiResponse::error(format!("Database error while updating order: {}", err))),
+        Err(message) => Ok(ApiResponse::error(message)),
+    }
+}
+
+#[derive(Serialize)]
+pub struct KitchenTicketLine {
+    pub item_name: String,
+    pub quantity: i32,
+}
+
+#[derive(Serialize)]
+pub struct KitchenTicket {
+    pub order_id: String,
+    pub customer_id: String,
+    pub status: OrderStatus,
+    pub placed_at: String,
+    pub elapsed_seconds: i64,
+    pub lines: Vec<KitchenTicketLine>,
+}
+
+// Tickets still on the pass, i.e. not yet served, picked up or cancelled.
+const KITCHEN_ACTIVE_STATUSES: [OrderStatus; 3] = [OrderStatus::Placed, OrderStatus::Cooking, OrderStatus::Ready];
+
+#[tauri::command]
+pub async fn view_kitchen_queue(
+    state: State<'_, AppState>,
+    session_token: String,
+) -> Result<ApiResponse<Vec<KitchenTicket>>, String> {
+    let session = match authorize(&state, &session_token, "view_kitchen_queue").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    let restaurant_id = match session.restaurant_id {
+        Some(restaurant_id) => restaurant_id,
+        None => return Ok(ApiResponse::error("You are not assigned to a restaurant".to_string())),
+    };
+
+    match Order::find()
+        .filter(order::Column::RestaurantId.eq(&restaurant_id))
+        .filter(order::Column::Status.is_in(KITCHEN_ACTIVE_STATUSES))
+        .find_with_related(OrderLine)
+        .order_by_asc(order::Column::Date)
+        .all(&state.db)
+        .await
+    {
+        Ok(orders) => {
+            let now = Utc::now();
+            let tickets = orders
+                .into_iter()
+                .filter_map(|(o, lines)| {
+                    let elapsed_seconds = DateTime::parse_from_rfc3339(&o.date)
+                        .map(|placed| (now - placed.with_timezone(&Utc)).num_seconds())
+                        .unwrap_or(0);
+
+                    Some(KitchenTicket {
+                        status: o.status?,
+                        order_id: o.order_id,
+                        customer_id: o.customer_id,
+                        placed_at: o.date,
+                        elapsed_seconds,
+                        lines: lines
+                            .into_iter()
+                            .map(|l| KitchenTicketLine { item_name: l.item_name, quantity: l.quantity })
+                            .collect(),
+                    })
+                })
+                .collect();
+            Ok(ApiResponse::success(tickets))
+        }
+        Err(err) => Ok(ApiResponse::error(format!("Database error: {}", err))),
+    }
+}
+
+// Bump advances a ticket to the next kitchen step; recall undoes the last bump.
+#[tauri::command]
+pub async fn bump_kitchen_ticket(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    session_token: String,
+    order_id: String,
+) -> Result<ApiResponse<order::Model>, String> {
+    let session = match authorize(&state, &session_token, "bump_kitchen_ticket").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    let bumped = move_order_status(&state, &session, &order_id, |current| match current {
+        OrderStatus::Placed => Ok(OrderStatus::Cooking),
+        OrderStatus::Cooking => Ok(OrderStatus::Ready),
+        OrderStatus::Ready => Ok(OrderStatus::Served),
+        other => Err(format!("A {} ticket cannot be bumped", other.to_value())),
+    })
+    .await;
+
+    match bumped {
+        Ok(updated_order) => {
+            emit_order_event(&app, "status_changed", &updated_order);
+            Ok(ApiResponse::success(updated_order))
+        }
+        Err(message) => Ok(ApiResponse::error(message)),
+    }
+}
+
+#[tauri::command]
+pub async fn recall_kitchen_ticket(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    session_token: String,
+    order_id: String,
+) -> Result<ApiResponse<order::Model>, String> {
+    let session = match authorize(&state, &session_token, "recall_kitchen_ticket").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    let recalled = move_order_status(&state, &session, &order_id, |current| match current {
+        OrderStatus::Ready => Ok(OrderStatus::Cooking),
+        OrderStatus::Served => Ok(OrderStatus::Ready),
+        other => Err(format!("A {} ticket cannot be recalled", other.to_value())),
+    })
+    .await;
+
+    match recalled {
+        Ok(updated_order) => {
+            emit_order_event(&app, "status_changed", &updated_order);
+            Ok(ApiResponse::success(updated_order))
+        }
+        Err(message) => Ok(ApiResponse::error(message)),
+    }
+}
+
+#[derive(Serialize)]
+pub struct OrderStatusHistoryResponse {
+    pub from_status: Option<OrderStatus>,
+    pub to_status: OrderStatus,
+    pub actor_id: String,
+    pub changed_at: String,
+}
+
+#[tauri::command]
+pub async fn view_order_status_history(
+    state: State<'_, AppState>,
+    session_token: String,
+    order_id: String,
+) -> Result<ApiResponse<Vec<OrderStatusHistoryResponse>>, String> {
+    let session = match authorize(&state, &session_token, "view_order_status_history").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    match Order::find_by_id(order_id.clone()).one(&state.db).await {
+        Ok(Some(existing_order)) if !session.can_act_for(&existing_order.customer_id) => {
+            return Ok(ApiResponse::error("Customers can only view their own orders".to_string()));
+        }
+        Ok(Some(_)) => {}
+        Ok(None) => return Ok(ApiResponse::error(format!("No order found with ID: {}", order_id))),
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    }
+
+    match OrderStatusHistory::find()
+        .filter(order_status_history::Column::OrderId.eq(&order_id))
+        .order_by_asc(order_status_history::Column::ChangedAt)
+        .all(&state.db)
+        .await
+    {
+        Ok(history) => Ok(ApiResponse::success(
+            history
+                .into_iter()
+                .map(|h| OrderStatusHistoryResponse {
+                    from_status: h.from_status,
+                    to_status: h.to_status,
+                    actor_id: h.actor_id,
+                    changed_at: h.changed_at,
+                })
+                .collect(),
+        )),
+        Err(err) => Ok(ApiResponse::error(format!("Database error: {}", err))),
     }
 }
 
 #[derive(Deserialize)]
 pub struct UpdateOrderRequest {
     pub order_id: String,
-    pub quantity: Option<i32>,
-    pub is_paid: Option<bool>,
+    pub line_id: String,
+    pub quantity: i32,
 }
 
 #[tauri::command]
 pub async fn update_order(
+    app: AppHandle,
     state: State<'_, AppState>,
+    session_token: String,
     payload: UpdateOrderRequest,
-) -> Result<ApiResponse<order::Model>, String> {
-    match Order::find_by_id(payload.order_id.clone()).one(&state.db).await {
-        Ok(Some(existing_order)) => {
-            let mut active_order: OrderActiveModel = existing_order.into();
+) -> Result<ApiResponse<OrderResponse>, String> {
+    let session = match authorize(&state, &session_token, "update_order").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    if payload.quantity <= 0 {
+        return Ok(ApiResponse::error("Quantity must be at least 1".to_string()));
+    }
+
+    let txn = match state.db.begin().await {
+        Ok(txn) => txn,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    // Locking the order keeps a concurrent checkout from charging the old total for new quantities.
+    let existing_order = match Order::find_by_id(payload.order_id.clone()).lock_exclusive().one(&txn).await {
+        Ok(Some(existing_order)) => existing_order,
+        Ok(None) => return Ok(ApiResponse::error(format!("No order found with ID: {}", payload.order_id))),
+        Err(err) => return Ok(ApiResponse::error(format!("Database error while updating order: {}", err))),
+    };
+
+    if !session.can_act_for(&existing_order.customer_id) {
+        return Ok(ApiResponse::error("Customers can only modify their own orders".to_string()));
+    }
 
-            if let Some(quantity) = payload.quantity {
-                active_order.quantity = Set(quantity);
+    if existing_order.is_paid {
+        return Ok(ApiResponse::error("Paid orders can no longer be changed".to_string()));
+    }
+
+    match OrderLine::find_by_id(payload.line_id.clone()).one(&txn).await {
+        Ok(Some(line)) if line.order_id == existing_order.order_id => {
+            let mut active_line: order_line::ActiveModel = line.into();
+            active_line.quantity = Set(payload.quantity);
+
+            if let Err(err) = active_line.update(&txn).await {
+                return Ok(ApiResponse::error(format!("Failed to update order: {}", err)));
             }
+        }
+        Ok(_) => return Ok(ApiResponse::error(format!("No order line found with ID: {}", payload.line_id))),
+        Err(err) => return Ok(ApiResponse::error(format!("Database error while updating order: {}", err))),
+    }
+
+    let lines = match load_order_lines(&txn, &existing_order.order_id).await {
+        Ok(lines) => lines,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    if let Err(err) = txn.commit().await {
+        return Ok(ApiResponse::error(format!("Failed to update order: {}", err)));
+    }
+
+    clear_order_cache(&state, &existing_order, &[]).await;
+    emit_order_event(&app, "updated", &existing_order);
+    Ok(ApiResponse::success(to_order_response(existing_order, lines)))
+}
 
-            if let Some(is_paid) = payload.is_paid {
-                active_order.is_paid = Set(is_paid);
+// Locks the purchased item and takes the quantity out of its stock. Rides carry no stock.
+async fn reserve_stock<C: ConnectionTrait>(
+    db: &C,
+    item_type: &str,
+    item_id: &str,
+    quantity: i32,
+) -> Result<(), String> {
+    match item_type {
+        "restaurant" => {
+            let menu = Menu::find_by_id(item_id.to_string())
+                .lock_exclusive()
+                .one(db)
+                .await
+                .map_err(|e| format!("Database error: {}", e))?
+                .ok_or_else(|| format!("No menu found with ID: {}", item_id))?;
+
+            if menu.available_quantity < quantity {
+                return Err(format!("Only {} {} left", menu.available_quantity, menu.name));
             }
 
-            match active_order.update(&state.db).await {
-                Ok(updated_order) => {
-                    cache_delete(&state.redis_pool, "get_all_orders_cache").await;
-                    Ok(ApiResponse::success(updated_order))
-                }
-                Err(err) => Ok(ApiResponse::error(format!("Failed to update order: {}", err))),
+            let remaining = menu.available_quantity - quantity;
+            let mut active_menu: menu::ActiveModel = menu.into();
+            active_menu.available_quantity = Set(remaining);
+            active_menu
+                .update(db)
+                .await
+                .map_err(|e| format!("Failed to update menu quantity: {}", e))?;
+
+            Ok(())
+        }
+        "store" => {
+            let souvenir = Souvenir::find_by_id(item_id.to_string())
+                .lock_exclusive()
+                .one(db)
+                .await
+                .map_err(|e| format!("Database error: {}", e))?
+                .ok_or_else(|| format!("No souvenir found with ID: {}", item_id))?;
+
+            if souvenir.stock < quantity {
+                return Err(format!("Only {} {} left", souvenir.stock, souvenir.name));
             }
+
+            let remaining = souvenir.stock - quantity;
+            let mut active_souvenir: souvenir::ActiveModel = souvenir.into();
+            active_souvenir.stock = Set(remaining);
+            active_souvenir
+                .update(db)
+                .await
+                .map_err(|e| format!("Failed to update souvenir stock: {}", e))?;
+
+            Ok(())
         }
-        Ok(None) => Ok(ApiResponse::error(format!("No order found with ID: {}", payload.order_id))),
-        Err(err) => Ok(ApiResponse::error(format!("Database error while updating order: {}", err))),
+        "ride" => Ok(()),
+        other => Err(format!("Unknown order item type: {}", other)),
     }
 }
 
 #[derive(Deserialize)]
 pub struct CheckoutOrderRequest {
     pub order_id: String,
-    pub is_paid: Option<bool>,
 }
 
+// Takes every line of the cart out of stock and debits the wallet for the snapshotted total in a
+// single transaction. Row locks on the order, items and customer keep concurrent checkouts from
+// overselling.
 #[tauri::command]
 pub async fn checkout_order(
+    app: AppHandle,
     state: State<'_, AppState>,
+    session_token: String,
     payload: CheckoutOrderRequest,
-) -> Result<ApiResponse<order::Model>, String> {
-    match Order::find_by_id(payload.order_id.clone()).one(&state.db).await {
-        Ok(Some(existing_order)) => {
-            let mut active_order: OrderActiveModel = existing_order.into();
+) -> Result<ApiResponse<OrderResponse>, String> {
+    let session = match authorize(&state, &session_token, "checkout_order").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
 
-            if let Some(is_paid) = payload.is_paid {
-                active_order.is_paid = Set(is_paid);
-            }
+    let txn = match state.db.begin().await {
+        Ok(txn) => txn,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
 
-            match active_order.update(&state.db).await {
-                Ok(updated_order) => {
-                    cache_delete(&state.redis_pool, "get_all_orders_cache").await;
-                    Ok(ApiResponse::success(updated_order))
-                }
-                Err(err) => Ok(ApiResponse::error(format!("Failed to update order: {}", err))),
-            }
+    let existing_order = match Order::find_by_id(payload.order_id.clone()).lock_exclusive().one(&txn).await {
+        Ok(Some(existing_order)) => existing_order,
+        Ok(None) => return Ok(ApiResponse::error(format!("No order found with ID: {}", payload.order_id))),
+        Err(err) => return Ok(ApiResponse::error(format!("Database error while updating order: {}", err))),
+    };
+
+    if !session.can_act_for(&existing_order.customer_id) {
+        return Ok(ApiResponse::error("Customers can only modify their own orders".to_string()));
+    }
+
+    if existing_order.is_paid {
+        return Ok(ApiResponse::error("Order has already been paid".to_string()));
+    }
+
+    let lines = match load_order_lines(&txn, &existing_order.order_id).await {
+        Ok(lines) if !lines.is_empty() => lines,
+        Ok(_) => return Ok(ApiResponse::error("Order has no items to check out".to_string())),
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    // Stock rows are locked in item order so two carts sharing items can't deadlock each other.
+    let mut lock_order: Vec<&order_line::Model> = lines.iter().collect();
+    lock_order.sort_by(|a, b| a.item_id.cmp(&b.item_id));
+
+    for line in lock_order {
+        if let Err(message) = reserve_stock(&txn, &existing_order.item_type, &line.item_id, line.quantity).await {
+            return Ok(ApiResponse::error(message));
+        }
+    }
+
+    if let Err(message) = post_wallet_entry(
+        &txn,
+        &existing_order.customer_id,
+        WalletTransactionKind::Purchase,
+        -order_total(&lines),
+        Some(existing_order.order_id.clone()),
+        format!("Payment for order {}", existing_order.order_id),
+    )
+    .await
+    {
+        return Ok(ApiResponse::error(message));
+    }
+
+    let mut active_order: OrderActiveModel = existing_order.into();
+    active_order.is_paid = Set(true);
+
+    let updated_order = match active_order.update(&txn).await {
+        Ok(updated_order) => updated_order,
+        Err(err) => return Ok(ApiResponse::error(format!("Failed to update order: {}", err))),
+    };
+
+    if let Err(err) = txn.commit().await {
+        return Ok(ApiResponse::error(format!("Failed to complete checkout: {}", err)));
+    }
+
+    clear_order_cache(&state, &updated_order, &lines).await;
+
+    emit_order_event(&app, "paid", &updated_order);
+    Ok(ApiResponse::success(to_order_response(updated_order, lines)))
+}
+
+#[derive(Deserialize)]
+pub struct RefundStoreOrderRequest {
+    pub order_id: String,
+}
+
+// Store orders have no kitchen lifecycle to cancel through, so retail staff reverse a paid one
+// here: the souvenirs go back on the shelf, the customer is refunded and the order is marked
+// cancelled so it can't be refunded twice.
+#[tauri::command]
+pub async fn refund_store_order(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    session_token: String,
+    payload: RefundStoreOrderRequest,
+) -> Result<ApiResponse<OrderResponse>, String> {
+    let session = match authorize(&state, &session_token, "refund_store_order").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    let txn = match state.db.begin().await {
+        Ok(txn) => txn,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    let existing_order = match Order::find_by_id(payload.order_id.clone()).lock_exclusive().one(&txn).await {
+        Ok(Some(existing_order)) => existing_order,
+        Ok(None) => return Ok(ApiResponse::error(format!("No order found with ID: {}", payload.order_id))),
+        Err(err) => return Ok(ApiResponse::error(format!("Database error while refunding order: {}", err))),
+    };
+
+    if existing_order.item_type != "store" {
+        return Ok(ApiResponse::error("Only store orders can be refunded here".to_string()));
+    }
+
+    if !existing_order.is_paid {
+        return Ok(ApiResponse::error("Order has not been paid yet".to_string()));
+    }
+
+    if existing_order.status == Some(OrderStatus::Cancelled) {
+        return Ok(ApiResponse::error("Order has already been refunded".to_string()));
+    }
+
+    let mut lines = match load_order_lines(&txn, &existing_order.order_id).await {
+        Ok(lines) => lines,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+    // Same lock order as checkout_order.
+    lines.sort_by(|a, b| a.item_id.cmp(&b.item_id));
+
+    for line in &lines {
+        let souvenir = match Souvenir::find_by_id(line.item_id.clone()).lock_exclusive().one(&txn).await {
+            Ok(Some(souvenir)) => souvenir,
+            Ok(None) => continue,
+            Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+        };
+
+        let restocked = souvenir.stock + line.quantity;
+        let mut active_souvenir: souvenir::ActiveModel = souvenir.into();
+        active_souvenir.stock = Set(restocked);
+
+        if let Err(err) = active_souvenir.update(&txn).await {
+            return Ok(ApiResponse::error(format!("Failed to update souvenir stock: {}", err)));
         }
-        Ok(None) => Ok(ApiResponse::error(format!("No order found with ID: {}", payload.order_id))),
-        Err(err) => Ok(ApiResponse::error(format!("Database error while updating order: {}", err))),
     }
+
+    if let Err(message) = post_wallet_entry(
+        &txn,
+        &existing_order.customer_id,
+        WalletTransactionKind::Refund,
+        order_total(&lines),
+        Some(existing_order.order_id.clone()),
+        format!("Refund for store order {}", existing_order.order_id),
+    )
+    .await
+    {
+        return Ok(ApiResponse::error(message));
+    }
+
+    let mut active_order: OrderActiveModel = existing_order.into();
+    active_order.status = Set(Some(OrderStatus::Cancelled));
+
+    let updated_order = match active_order.update(&txn).await {
+        Ok(updated_order) => updated_order,
+        Err(err) => return Ok(ApiResponse::error(format!("Failed to refund order: {}", err))),
+    };
+
+    if let Err(message) =
+        record_status_change(&txn, &updated_order.order_id, None, OrderStatus::Cancelled, &session.user_id).await
+    {
+        return Ok(ApiResponse::error(message));
+    }
+
+    if let Err(err) = txn.commit().await {
+        return Ok(ApiResponse::error(format!("Failed to refund order: {}", err)));
+    }
+
+    clear_order_cache(&state, &updated_order, &lines).await;
+    emit_order_event(&app, "refunded", &updated_order);
+    Ok(ApiResponse::success(to_order_response(updated_order, lines)))
 }
 
 #[derive(Deserialize)]
@@ -243,17 +1113,90 @@ pub struct DeleteOrderRequest {
 
 #[tauri::command]
 pub async fn delete_order(
+    app: AppHandle,
     state: State<'_, AppState>,
+    session_token: String,
     payload: DeleteOrderRequest,
 ) -> Result<ApiResponse<()>, String> {
-    match Order::delete_by_id(payload.order_id.clone()).exec(&state.db).await {
-        Ok(delete_result) => {
-            if delete_result.rows_affected == 0 {
-                return Ok(ApiResponse::error(format!("No order found with ID: {}", payload.order_id)));
-            }
-            cache_delete(&state.redis_pool, "get_all_orders_cache").await;
-            Ok(ApiResponse::success(()))
+    let session = match authorize(&state, &session_token, "delete_order").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    let txn = match state.db.begin().await {
+        Ok(txn) => txn,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    // Locked so a checkout can't pay for the order between the check and the delete.
+    let existing_order = match Order::find_by_id(payload.order_id.clone()).lock_exclusive().one(&txn).await {
+        Ok(Some(existing_order)) => existing_order,
+        Ok(None) => return Ok(ApiResponse::error(format!("No order found with ID: {}", payload.order_id))),
+        Err(err) => return Ok(ApiResponse::error(format!("Database error while deleting order: {}", err))),
+    };
+
+    if !session.can_act_for(&existing_order.customer_id) {
+        return Ok(ApiResponse::error("Customers can only modify their own orders".to_string()));
+    }
+
+    // Paid orders keep their lines and ledger entries; they are cancelled, which refunds them.
+    if existing_order.is_paid {
+        return Ok(ApiResponse::error("Paid orders cannot be deleted, cancel them instead".to_string()));
+    }
+
+    if let Err(err) = Order::delete_by_id(payload.order_id.clone()).exec(&txn).await {
+        return Ok(ApiResponse::error(format!("Failed to delete order: {}", err)));
+    }
+
+    if let Err(err) = txn.commit().await {
+        return Ok(ApiResponse::error(format!("Failed to delete order: {}", err)));
+    }
+
+    clear_order_cache(&state, &existing_order, &[]).await;
+    emit_order_event(&app, "deleted", &existing_order);
+    Ok(ApiResponse::success(()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kitchen_moves_forward_one_step_at_a_time() {
+        assert_eq!(transition_roles(OrderStatus::Placed, OrderStatus::Cooking), Some(&["Chef"][..]));
+        assert_eq!(transition_roles(OrderStatus::Cooking, OrderStatus::Ready), Some(&["Chef"][..]));
+        assert_eq!(transition_roles(OrderStatus::Ready, OrderStatus::Served), Some(&["Waiter"][..]));
+        assert_eq!(transition_roles(OrderStatus::Placed, OrderStatus::Ready), None);
+        assert_eq!(transition_roles(OrderStatus::Placed, OrderStatus::Served), None);
+    }
+
+    #[test]
+    fn customers_can_only_cancel_before_cooking_and_pick_up() {
+        let customer_moves: Vec<(OrderStatus, OrderStatus)> = ORDER_TRANSITIONS
+            .iter()
+            .filter(|(_, _, roles)| roles.contains(&"Customer"))
+            .map(|(from, to, _)| (*from, *to))
+            .collect();
+
+        assert_eq!(
+            customer_moves,
+            vec![(OrderStatus::Ready, OrderStatus::PickedUp), (OrderStatus::Placed, OrderStatus::Cancelled)]
+        );
+    }
+
+    #[test]
+    fn finished_orders_are_final() {
+        for status in [OrderStatus::PickedUp, OrderStatus::Cancelled] {
+            assert!(ORDER_TRANSITIONS.iter().all(|(from, _, _)| *from != status));
+        }
+    }
+
+    #[test]
+    fn every_move_has_a_role_and_is_listed_once() {
+        for (i, (from, to, roles)) in ORDER_TRANSITIONS.iter().enumerate() {
+            assert_ne!(from, to);
+            assert!(!roles.is_empty());
+            assert!(ORDER_TRANSITIONS[i + 1..].iter().all(|(f, t, _)| (f, t) != (from, to)));
         }
-        Err(err) => Ok(ApiResponse::error(format!("Failed to delete order: {}", err))),
     }
 }
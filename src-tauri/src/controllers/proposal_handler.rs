@@ -1,12 +1,11 @@
-use sea_orm::{
-    ActiveModelTrait, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter,
-    ActiveValue::Set,
-};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use sea_orm::{ActiveModelTrait, EntityTrait, ActiveValue::Set};
 use tauri::State;
 use serde::{Deserialize, Serialize};
 use entity::proposal::{self, ActiveModel as ProposalActiveModel, Entity as Proposal};
 
-use crate::{AppState, ApiResponse, cache_delete, cache_get, cache_set};
+use crate::{AppState, ApiResponse, cache_invalidate, cache_get, cache_set_tagged};
+use crate::controllers::session_handler::authorize;
 
 #[derive(Deserialize)]
 pub struct CreateProposalRequest {
@@ -22,8 +21,18 @@ pub struct CreateProposalRequest {
 #[tauri::command]
 pub async fn create_proposal(
     state: State<'_, AppState>,
+    session_token: String,
     payload: CreateProposalRequest,
 ) -> Result<ApiResponse<proposal::Model>, String> {
+    let session = match authorize(&state, &session_token, "create_proposal").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    if payload.sender_id != session.user_id {
+        return Ok(ApiResponse::error("Sender does not match the signed-in user".to_string()));
+    }
+
     use chrono::Utc;
 
     let current_date = Utc::now().format("%B %d, %Y").to_string(); // e.g., "April 2, 2025"
@@ -43,7 +52,7 @@ pub async fn create_proposal(
 
     match new_proposal.insert(&state.db).await {
         Ok(proposal) => {
-            cache_delete(&state.redis_pool, "all_proposals_cache").await;
+            cache_invalidate(&state.redis_pool, &["proposal"]).await;
             Ok(ApiResponse::success(proposal))
         }
         Err(err) => Err(format!("Failed to create proposal: {}", err)),
@@ -79,7 +88,12 @@ struct ProposalCache {
 #[tauri::command]
 pub async fn view_all_proposal(
     state: State<'_, AppState>,
+    session_token: String,
 ) -> Result<ApiResponse<Vec<ProposalResponse>>, String> {
+    if let Err(message) = authorize(&state, &session_token, "view_all_proposal").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     let cache_key = "all_proposals_cache";
 
     if let Some(cached) = cache_get::<Vec<ProposalCache>>(&state.redis_pool, cache_key).await {
@@ -92,7 +106,7 @@ pub async fn view_all_proposal(
                     title: p.title,
                     r#type: p.r#type,
                     cost: p.cost,
-                    image: base64::encode(&model.image),
+                    image: STANDARD.encode(&model.image),
                     description: p.description,
                     status: p.status,
                     sender_id: p.sender_id,
@@ -113,7 +127,7 @@ pub async fn view_all_proposal(
                     title: p.title.clone(),
                     r#type: p.r#type.clone(),
                     cost: p.cost,
-                    image: base64::encode(&p.image),
+                    image: STANDARD.encode(&p.image),
                     description: p.description.clone(),
                     status: p.status.clone(),
                     sender_id: p.sender_id.clone(),
@@ -135,9 +149,53 @@ pub async fn view_all_proposal(
                 })
                 .collect();
 
-            cache_set(&state.redis_pool, cache_key, &cache_only, 60).await;
+            cache_set_tagged(&state.redis_pool, cache_key, &cache_only, 60, &["proposal"]).await;
             Ok(ApiResponse::success(result))
         }
         Err(err) => Err(format!("Failed to retrieve proposals: {}", err)),
     }
 }
+
+#[derive(Deserialize)]
+pub struct ReviewProposalRequest {
+    pub proposal_id: String,
+    pub status: String,
+    pub feedback: Option<String>,
+}
+
+#[tauri::command]
+pub async fn review_proposal(
+    state: State<'_, AppState>,
+    session_token: String,
+    payload: ReviewProposalRequest,
+) -> Result<ApiResponse<proposal::Model>, String> {
+    if let Err(message) = authorize(&state, &session_token, "review_proposal").await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    if !matches!(payload.status.as_str(), "Approved" | "Declined") {
+        return Ok(ApiResponse::error("A proposal can only be Approved or Declined".to_string()));
+    }
+
+    let existing = match Proposal::find_by_id(payload.proposal_id.clone()).one(&state.db).await {
+        Ok(Some(existing)) => existing,
+        Ok(None) => return Ok(ApiResponse::error(format!("No proposal found with ID: {}", payload.proposal_id))),
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    if existing.status != "Pending" {
+        return Ok(ApiResponse::error(format!("This proposal has already been {}", existing.status.to_lowercase())));
+    }
+
+    let mut active_proposal: ProposalActiveModel = existing.into();
+    active_proposal.status = Set(payload.status);
+    active_proposal.feedback = Set(payload.feedback);
+
+    match active_proposal.update(&state.db).await {
+        Ok(proposal) => {
+            cache_invalidate(&state.redis_pool, &["proposal"]).await;
+            Ok(ApiResponse::success(proposal))
+        }
+        Err(err) => Ok(ApiResponse::error(format!("Failed to review proposal: {}", err))),
+    }
+}
@@ -1,11 +1,124 @@
 use anyhow::Result;
+use chrono::{NaiveDate, Utc};
+use entity::fast_pass::{self, Entity as FastPass};
 use entity::queue::{self, ActiveModel as QueueActiveModel, Entity as Queue};
+use entity::queue_party_member::{self, ActiveModel as QueuePartyMemberActiveModel, Entity as QueuePartyMember};
+use entity::ride::{self, Entity as Ride};
+use entity::ride_cycle::{self, ActiveModel as RideCycleActiveModel};
+use entity::sea_orm_active_enums::{FastPassStatus, RideMaintenanceStatus, RideStatus, WalletTransactionKind};
+use entity::user::{self, Entity as User};
+use entity::wallet_transaction::{self, Entity as WalletTransaction};
 use sea_orm::{
-    ActiveModelTrait, EntityTrait, QueryOrder, ActiveValue::Set, ColumnTrait, QueryFilter,
+    sea_query::{Alias, Expr}, ActiveModelTrait, ConnectionTrait, EntityTrait, QueryOrder, QuerySelect, ActiveValue::Set,
+    ColumnTrait, Condition, PaginatorTrait, QueryFilter, TransactionTrait,
 };
 use serde::{Deserialize, Serialize};
+use std::env;
 use tauri::State;
-use crate::{ApiResponse, cache_get, cache_set, cache_delete, AppState};
+use uuid::Uuid;
+use crate::{ApiResponse, cache_get, cache_set_tagged, cache_invalidate, page_limit, AppState};
+use crate::controllers::fast_pass_handler::expire_fast_passes;
+use crate::controllers::notification_handler::insert_notification;
+use crate::controllers::session_handler::{authorize, Session};
+use crate::controllers::wait_time_estimator::WaitEstimator;
+use crate::controllers::wallet_handler::{post_wallet_entry, to_major_units, to_minor_units};
+
+// What happens to a ride's line when the ride stops being operational.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum DowntimeAction {
+    // Keep everyone's place and freeze the line until the ride reopens.
+    Hold,
+    // Clear the line and refund whatever was charged on joining.
+    Evict,
+}
+
+// Rules applied to ride queues, read from the environment at startup.
+pub struct QueuePolicy {
+    pub max_active_queues: u64,
+    pub charge_on_join: bool,
+    // Share of each ride cycle's seats reserved for the fast-pass priority lane.
+    pub fast_pass_ratio: f64,
+    pub fast_pass_slot_minutes: i64,
+    pub downtime_action: DowntimeAction,
+}
+
+impl QueuePolicy {
+    pub fn from_env() -> Self {
+        QueuePolicy {
+            max_active_queues: env::var("QUEUE_MAX_ACTIVE_PER_CUSTOMER")
+                .ok()
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(3),
+            charge_on_join: env::var("QUEUE_CHARGE_ON_JOIN").is_ok_and(|value| value == "true"),
+            fast_pass_ratio: env::var("FAST_PASS_MERGE_RATIO")
+                .ok()
+                .and_then(|value| value.parse::<f64>().ok())
+                .filter(|ratio| ratio.is_finite())
+                .map_or(0.5, |ratio| ratio.clamp(0.0, 1.0)),
+            fast_pass_slot_minutes: env::var("FAST_PASS_SLOT_MINUTES")
+                .ok()
+                .and_then(|value| value.parse().ok())
+                .filter(|minutes| *minutes > 0)
+                .unwrap_or(30),
+            downtime_action: match env::var("QUEUE_DOWNTIME_ACTION").as_deref() {
+                Ok("evict") => DowntimeAction::Evict,
+                _ => DowntimeAction::Hold,
+            },
+        }
+    }
+}
+
+// Rides that are closed or waiting on repairs can't take new riders.
+pub fn ride_is_operational(ride: &ride::Model) -> bool {
+    ride.status == RideStatus::Operational && ride.maintenance_status == RideMaintenanceStatus::Available
+}
+
+// Ride staff work the ride they are assigned to; managers may work any ride.
+pub fn can_operate_ride(session: &Session, ride: &ride::Model) -> bool {
+    session.role != "Ride Staff" || ride.staff_id.as_deref() == Some(session.user_id.as_str())
+}
+
+// Every reason the rider can't board the ride, so they can all be explained at once.
+fn eligibility_problems(ride: &ride::Model, rider: &user::Model, today: NaiveDate) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    if let Some(min_age) = ride.min_age {
+        match NaiveDate::parse_from_str(&rider.dob, "%Y-%m-%d").ok().and_then(|dob| today.years_since(dob)) {
+            Some(age) if age >= min_age.unsigned_abs() => {}
+            Some(_) => problems.push(format!("riders must be at least {} years old", min_age)),
+            None => problems.push("a valid date of birth is needed to check the age limit".to_string()),
+        }
+    }
+
+    if ride.min_height_cm.is_some() || ride.max_height_cm.is_some() {
+        match rider.height_cm {
+            Some(height) => {
+                if let Some(min_height) = ride.min_height_cm.filter(|min_height| height < *min_height) {
+                    problems.push(format!("riders must be at least {} cm tall", min_height));
+                }
+                if let Some(max_height) = ride.max_height_cm.filter(|max_height| height > *max_height) {
+                    problems.push(format!("riders must be no taller than {} cm", max_height));
+                }
+            }
+            None => problems.push("height must be measured by staff before riding".to_string()),
+        }
+    }
+
+    if rider.uses_wheelchair && !ride.wheelchair_accessible {
+        problems.push("the ride is not wheelchair accessible".to_string());
+    }
+
+    problems
+}
+
+pub fn check_eligibility(ride: &ride::Model, rider: &user::Model) -> Result<(), String> {
+    let problems = eligibility_problems(ride, rider, Utc::now().date_naive());
+    if problems.is_empty() {
+        return Ok(());
+    }
+
+    Err(format!("{} cannot ride {}: {}", rider.name, ride.name, problems.join("; ")))
+}
 
 // Updated QueueResponse to include position
 #[derive(Serialize)]
@@ -15,13 +128,21 @@ pub struct QueueResponse {
     customer_id: String,
     joined_at: String,
     position: i32,  // Added position
+    party_size: i32,
+    on_hold: bool,
+    estimated_wait_seconds: Option<i64>,
 }
 
 // View all queues, sorted by position
 #[tauri::command]
 pub async fn view_all_queues(
     state: State<'_, AppState>,
+    session_token: String,
 ) -> Result<ApiResponse<Vec<QueueResponse>>, String> {
+    if let Err(message) = authorize(&state, &session_token, "view_all_queues").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     let cache_key = "get_all_queues_cache";
 
     if let Some(cached_queues) = cache_get::<Vec<queue::Model>>(&state.redis_pool, cache_key).await {
@@ -33,6 +154,9 @@ pub async fn view_all_queues(
                 customer_id: q.customer_id,
                 joined_at: q.joined_at,
                 position: q.position,  // Include position
+                party_size: q.party_size,
+                on_hold: q.on_hold,
+                estimated_wait_seconds: None,
             })
             .collect();
 
@@ -53,10 +177,13 @@ pub async fn view_all_queues(
                     customer_id: q.customer_id,
                     joined_at: q.joined_at,
                     position: q.position,  // Include position
+                    party_size: q.party_size,
+                    on_hold: q.on_hold,
+                    estimated_wait_seconds: None,
                 })
                 .collect();
 
-            cache_set(&state.redis_pool, cache_key, &formatted_queues, 60).await;
+            cache_set_tagged(&state.redis_pool, cache_key, &formatted_queues, 60, &["queue"]).await;
             Ok(ApiResponse::success(formatted_queues))
         }
         Err(err) => {
@@ -66,48 +193,281 @@ pub async fn view_all_queues(
     }
 }
 
+// Locks the ride row so queue mutations on the same line run one at a time, then returns
+// the line in position order.
+async fn lock_ride_queue<C: ConnectionTrait>(
+    db: &C,
+    ride_id: &str,
+) -> Result<(ride::Model, Vec<queue::Model>), String> {
+    let ride = Ride::find_by_id(ride_id.to_string())
+        .lock_exclusive()
+        .one(db)
+        .await
+        .map_err(|err| format!("Database error: {}", err))?
+        .ok_or_else(|| format!("No ride found with ID: {}", ride_id))?;
+
+    let entries = Queue::find()
+        .filter(queue::Column::RideId.eq(ride_id))
+        .order_by_asc(queue::Column::Position)
+        .all(db)
+        .await
+        .map_err(|err| format!("Database error: {}", err))?;
+
+    Ok((ride, entries))
+}
+
+// Party members riding with the given queue entries; the lead customer is the entry's own customer_id.
+async fn load_party_members<C: ConnectionTrait>(
+    db: &C,
+    queue_ids: Vec<String>,
+) -> Result<Vec<queue_party_member::Model>, String> {
+    QueuePartyMember::find()
+        .filter(queue_party_member::Column::QueueId.is_in(queue_ids))
+        .all(db)
+        .await
+        .map_err(|err| format!("Database error: {}", err))
+}
+
+// Ride queues a customer holds a place in, as a party lead or as a member.
+async fn active_queue_count<C: ConnectionTrait>(db: &C, customer_id: &str) -> Result<u64, String> {
+    let as_lead = Queue::find()
+        .filter(queue::Column::CustomerId.eq(customer_id))
+        .count(db)
+        .await
+        .map_err(|err| format!("Database error: {}", err))?;
+
+    let as_member = QueuePartyMember::find()
+        .filter(queue_party_member::Column::CustomerId.eq(customer_id))
+        .count(db)
+        .await
+        .map_err(|err| format!("Database error: {}", err))?;
+
+    Ok(as_lead + as_member)
+}
+
+fn seats_for(entry: &queue::Model) -> usize {
+    usize::try_from(entry.party_size.max(1)).unwrap_or(1)
+}
+
+// Gives the lead back whatever they paid to join with this entry, under the policy in force at
+// the time, less anything already refunded against it, and returns the amount. Call before the
+// entry is deleted.
+async fn refund_join_charge<C: ConnectionTrait>(db: &C, entry: &queue::Model, ride_name: &str) -> Result<i64, String> {
+    let charged: i64 = -WalletTransaction::find()
+        .filter(wallet_transaction::Column::ReferenceId.eq(&entry.queue_id))
+        .filter(wallet_transaction::Column::UserId.eq(&entry.customer_id))
+        .filter(wallet_transaction::Column::Kind.is_in([WalletTransactionKind::Purchase, WalletTransactionKind::Refund]))
+        .all(db)
+        .await
+        .map_err(|err| format!("Database error: {}", err))?
+        .iter()
+        .map(|posted| posted.amount)
+        .sum::<i64>();
+
+    if charged > 0 {
+        post_wallet_entry(
+            db,
+            &entry.customer_id,
+            WalletTransactionKind::Refund,
+            charged,
+            Some(entry.queue_id.clone()),
+            format!("Refund for {} queue entry", ride_name),
+        )
+        .await?;
+    }
+
+    Ok(charged)
+}
+
+// Numbers the given entries 1..n in slice order. Moved rows are parked on negative positions
+// first so the unique (ride_id, position) index never sees two rows on the same spot.
+async fn renumber_queue<C: ConnectionTrait>(db: &C, entries: &[queue::Model]) -> Result<(), String> {
+    let moved: Vec<(&queue::Model, i32)> = entries
+        .iter()
+        .zip(1..)
+        .filter(|(entry, position)| entry.position != *position)
+        .collect();
+
+    for (entry, _) in &moved {
+        Queue::update_many()
+            .col_expr(queue::Column::Position, Expr::value(-entry.position))
+            .filter(queue::Column::QueueId.eq(&entry.queue_id))
+            .exec(db)
+            .await
+            .map_err(|err| format!("Failed to update queue: {}", err))?;
+    }
+
+    for (entry, position) in moved {
+        Queue::update_many()
+            .col_expr(queue::Column::Position, Expr::value(position))
+            .filter(queue::Column::QueueId.eq(&entry.queue_id))
+            .exec(db)
+            .await
+            .map_err(|err| format!("Failed to update queue: {}", err))?;
+    }
+
+    Ok(())
+}
+
 // Request struct for creating a queue
 #[derive(Deserialize)]
 pub struct CreateQueueRequest {
-    pub queue_id: String,
     pub ride_id: String,
     pub customer_id: String,
     pub joined_at: String,
+    // Other customers riding with `customer_id`; the whole party boards in the same cycle.
+    // Only staff can fill this in, once they have seen the group together at the entrance.
+    #[serde(default)]
+    pub party_member_ids: Vec<String>,
 }
 
-// Create a new queue with an incremented position
+// Create a new queue entry at the back of the line
 #[tauri::command]
 pub async fn create_queue(
     state: State<'_, AppState>,
+    session_token: String,
     payload: CreateQueueRequest,
 ) -> Result<ApiResponse<queue::Model>, String> {
-    // Fetch the latest position for the given ride_id
-    let last_position = Queue::find()
-        .filter(queue::Column::RideId.eq(payload.ride_id.clone()))
-        .order_by_desc(queue::Column::Position)
-        .one(&state.db)
-        .await
-        .map_err(|err| format!("Database error: {}", err))?
-        .map_or(0, |q| q.position);  // Default to 0 if no queues exist
+    let session = match authorize(&state, &session_token, "create_queue").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    if !session.can_act_for(&payload.customer_id) {
+        return Ok(ApiResponse::error("Customers can only join queues for themselves".to_string()));
+    }
+
+    // A customer can't sign other people up, their wallet would pay for joins nobody agreed to.
+    if session.is_customer() && !payload.party_member_ids.is_empty() {
+        return Ok(ApiResponse::error("Ask ride staff at the entrance to add party members".to_string()));
+    }
+
+    let txn = match state.db.begin().await {
+        Ok(txn) => txn,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    let (ride, entries) = match lock_ride_queue(&txn, &payload.ride_id).await {
+        Ok(locked) => locked,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    if !ride_is_operational(&ride) {
+        return Ok(ApiResponse::error(format!("{} is not accepting riders right now", ride.name)));
+    }
+
+    let mut party: Vec<String> = vec![payload.customer_id.clone()];
+    party.extend(payload.party_member_ids.iter().cloned());
+    party.sort();
+    party.dedup();
+
+    if party.len() != payload.party_member_ids.len() + 1 {
+        return Ok(ApiResponse::error("Each party member can only be listed once".to_string()));
+    }
 
-    let new_position = last_position + 1;
+    if party.len() > usize::try_from(ride.capacity).unwrap_or(0) {
+        return Ok(ApiResponse::error(format!(
+            "A party of {} can't board {}, which seats {}",
+            party.len(),
+            ride.name,
+            ride.capacity
+        )));
+    }
+
+    let members = match load_party_members(&txn, entries.iter().map(|q| q.queue_id.clone()).collect()).await {
+        Ok(members) => members,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    let in_line = entries
+        .iter()
+        .map(|q| &q.customer_id)
+        .chain(members.iter().map(|m| &m.customer_id))
+        .find(|customer_id| party.contains(customer_id));
+
+    if let Some(customer_id) = in_line {
+        return Ok(ApiResponse::error(format!("{} is already in the queue for this ride", customer_id)));
+    }
+
+    // Locking the customer rows (in a stable order) serialises joins across rides so the
+    // limit below can't be raced.
+    for customer_id in &party {
+        match User::find_by_id(customer_id.clone()).lock_exclusive().one(&txn).await {
+            Ok(Some(customer)) if customer.role == "Customer" => {
+                if let Err(message) = check_eligibility(&ride, &customer) {
+                    return Ok(ApiResponse::error(message));
+                }
+            }
+            Ok(Some(_)) => return Ok(ApiResponse::error("Only customers can join ride queues".to_string())),
+            Ok(None) => return Ok(ApiResponse::error(format!("Customer not found: {}", customer_id))),
+            Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+        }
+
+        match active_queue_count(&txn, customer_id).await {
+            Ok(count) if count >= state.queue_policy.max_active_queues => {
+                return Ok(ApiResponse::error(format!(
+                    "{} is already in {} ride queues, the most allowed at once",
+                    customer_id, count
+                )));
+            }
+            Ok(_) => {}
+            Err(message) => return Ok(ApiResponse::error(message)),
+        }
+    }
 
     let new_queue = QueueActiveModel {
-        queue_id: Set(payload.queue_id),
+        queue_id: Set(format!("QUE-{}", Uuid::new_v4())),
         ride_id: Set(payload.ride_id),
         customer_id: Set(payload.customer_id),
         joined_at: Set(payload.joined_at),
-        position: Set(new_position),  // Set the new position
-        ..Default::default()
+        position: Set(entries.len() as i32 + 1),
+        party_size: Set(party.len() as i32),
+        on_hold: Set(false),
+    };
+
+    let queue = match new_queue.insert(&txn).await {
+        Ok(queue) => queue,
+        Err(err) => return Ok(ApiResponse::error(format!("Failed to create queue: {}", err))),
     };
 
-    match new_queue.insert(&state.db).await {
-        Ok(queue) => {
-            cache_delete(&state.redis_pool, "get_all_queues_cache").await;
-            Ok(ApiResponse::success(queue))
+    if !payload.party_member_ids.is_empty() {
+        let party_members = payload.party_member_ids.iter().map(|customer_id| QueuePartyMemberActiveModel {
+            queue_id: Set(queue.queue_id.clone()),
+            customer_id: Set(customer_id.clone()),
+        });
+
+        if let Err(err) = QueuePartyMember::insert_many(party_members).exec(&txn).await {
+            return Ok(ApiResponse::error(format!("Failed to create queue: {}", err)));
         }
-        Err(err) => Ok(ApiResponse::error(format!("Failed to create queue: {}", err))),
     }
+
+    let mut charged = false;
+    if state.queue_policy.charge_on_join && ride.price > 0.0 {
+        if let Err(message) = post_wallet_entry(
+            &txn,
+            &queue.customer_id,
+            WalletTransactionKind::Purchase,
+            -to_minor_units(ride.price) * i64::from(queue.party_size),
+            Some(queue.queue_id.clone()),
+            format!("Queue entry for {} (party of {})", ride.name, queue.party_size),
+        )
+        .await
+        {
+            return Ok(ApiResponse::error(message));
+        }
+        charged = true;
+    }
+
+    if let Err(err) = txn.commit().await {
+        return Ok(ApiResponse::error(format!("Failed to create queue: {}", err)));
+    }
+
+    if charged {
+        cache_invalidate(&state.redis_pool, &["queue", "user", &format!("user:{}", queue.customer_id)]).await;
+    } else {
+        cache_invalidate(&state.redis_pool, &["queue"]).await;
+    }
+    Ok(ApiResponse::success(queue))
 }
 
 // Updated EditQueueRequest to modify position instead of joined_at
@@ -117,29 +477,62 @@ pub struct EditQueueRequest {
     pub new_position: i32,  // Change to new_position
 }
 
-// Edit queue to update position
+// Move an entry to a new spot in its line, shifting everyone in between
 #[tauri::command]
 pub async fn edit_queue(
     state: State<'_, AppState>,
+    session_token: String,
     payload: EditQueueRequest,
 ) -> Result<ApiResponse<queue::Model>, String> {
-    let queue = Queue::find_by_id(payload.queue_id.clone())
-        .one(&state.db)
-        .await
-        .map_err(|err| format!("Database error: {}", err))?;
+    if let Err(message) = authorize(&state, &session_token, "edit_queue").await {
+        return Ok(ApiResponse::error(message));
+    }
 
-    if let Some(queue) = queue {
-        let mut active_queue: QueueActiveModel = queue.into();
-        active_queue.position = Set(payload.new_position);  // Update position
-        let updated_queue = active_queue.update(&state.db).await
-            .map_err(|err| format!("Failed to update queue: {}", err))?;
-        
-        cache_delete(&state.redis_pool, "get_all_queues_cache").await;
-        
-        Ok(ApiResponse::success(updated_queue))
-    } else {
-        Ok(ApiResponse::error("Queue entry not found".to_string()))
+    let txn = match state.db.begin().await {
+        Ok(txn) => txn,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    let ride_id = match Queue::find_by_id(payload.queue_id.clone()).one(&txn).await {
+        Ok(Some(queue)) => queue.ride_id,
+        Ok(None) => return Ok(ApiResponse::error("Queue entry not found".to_string())),
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    let mut entries = match lock_ride_queue(&txn, &ride_id).await {
+        Ok((_, entries)) => entries,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    if payload.new_position < 1 || payload.new_position as usize > entries.len() {
+        return Ok(ApiResponse::error(format!(
+            "Position must be between 1 and {}",
+            entries.len()
+        )));
+    }
+
+    let Some(index) = entries.iter().position(|q| q.queue_id == payload.queue_id) else {
+        return Ok(ApiResponse::error("Queue entry not found".to_string()));
+    };
+    let moved = entries.remove(index);
+    entries.insert(payload.new_position as usize - 1, moved);
+
+    if let Err(message) = renumber_queue(&txn, &entries).await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    let updated_queue = match Queue::find_by_id(payload.queue_id.clone()).one(&txn).await {
+        Ok(Some(queue)) => queue,
+        Ok(None) => return Ok(ApiResponse::error("Queue entry not found".to_string())),
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    if let Err(err) = txn.commit().await {
+        return Ok(ApiResponse::error(format!("Failed to update queue: {}", err)));
     }
+
+    cache_invalidate(&state.redis_pool, &["queue"]).await;
+    Ok(ApiResponse::success(updated_queue))
 }
 
 // Delete queue request remains unchanged
@@ -148,56 +541,578 @@ pub struct DeleteQueueRequest {
     pub queue_id: String,
 }
 
-// Delete queue (no changes needed)
+// Remove an entry and close the gap it leaves in the line
 #[tauri::command]
 pub async fn delete_queue(
     state: State<'_, AppState>,
+    session_token: String,
     payload: DeleteQueueRequest,
 ) -> Result<ApiResponse<()>, String> {
-    match Queue::delete_by_id(payload.queue_id.clone()).exec(&state.db).await {
-        Ok(delete_result) => {
-            if delete_result.rows_affected == 0 {
-                return Ok(ApiResponse::error(format!(
-                    "No queue found with ID: {}",
-                    payload.queue_id
-                )));
-            }
-            cache_delete(&state.redis_pool, "get_all_queues_cache").await;
-            Ok(ApiResponse::success(()))
+    let session = match authorize(&state, &session_token, "delete_queue").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    let txn = match state.db.begin().await {
+        Ok(txn) => txn,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    let existing_queue = match Queue::find_by_id(payload.queue_id.clone()).one(&txn).await {
+        Ok(Some(existing_queue)) => existing_queue,
+        Ok(None) => {
+            return Ok(ApiResponse::error(format!(
+                "No queue found with ID: {}",
+                payload.queue_id
+            )));
+        }
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    if !session.can_act_for(&existing_queue.customer_id) {
+        return Ok(ApiResponse::error("Customers can only leave their own queue entries".to_string()));
+    }
+
+    let (ride, mut entries) = match lock_ride_queue(&txn, &existing_queue.ride_id).await {
+        Ok(locked) => locked,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    // Leaving the line before boarding gives back whatever was paid to join it.
+    let refunded = match refund_join_charge(&txn, &existing_queue, &ride.name).await {
+        Ok(refunded) => refunded,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    match Queue::delete_by_id(payload.queue_id.clone()).exec(&txn).await {
+        Ok(delete_result) if delete_result.rows_affected == 0 => {
+            return Ok(ApiResponse::error(format!(
+                "No queue found with ID: {}",
+                payload.queue_id
+            )));
         }
-        Err(err) => Ok(ApiResponse::error(format!("Failed to delete queue: {}", err))),
+        Ok(_) => {}
+        Err(err) => return Ok(ApiResponse::error(format!("Failed to delete queue: {}", err))),
+    }
+
+    entries.retain(|q| q.queue_id != payload.queue_id);
+    if let Err(message) = renumber_queue(&txn, &entries).await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    if let Err(err) = txn.commit().await {
+        return Ok(ApiResponse::error(format!("Failed to delete queue: {}", err)));
+    }
+
+    if refunded > 0 {
+        cache_invalidate(&state.redis_pool, &["queue", "user", &format!("user:{}", existing_queue.customer_id)]).await;
+    } else {
+        cache_invalidate(&state.redis_pool, &["queue"]).await;
     }
+    Ok(ApiResponse::success(()))
 }
 
-// Get queues by ride, sorted by position
+// Get queues by ride, sorted by position; `cursor` is the queue_id of the last entry on the previous page
 #[tauri::command]
 pub async fn get_queues_by_ride(
     state: State<'_, AppState>,
+    session_token: String,
     ride_id: String,
+    limit: Option<u64>,
+    cursor: Option<String>,
 ) -> Result<ApiResponse<Vec<QueueResponse>>, String> {
-    match Queue::find()
-        .filter(queue::Column::RideId.eq(ride_id.clone()))
+    let session = match authorize(&state, &session_token, "get_queues_by_ride").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    let estimator = match Ride::find_by_id(ride_id.clone()).one(&state.db).await {
+        Ok(Some(ride)) => match WaitEstimator::for_ride(&state.db, &ride).await {
+            Ok(estimator) => estimator,
+            Err(message) => return Ok(ApiResponse::error(message)),
+        },
+        Ok(None) => return Ok(ApiResponse::error(format!("No ride found with ID: {}", ride_id))),
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    let mut query = Queue::find().filter(queue::Column::RideId.eq(ride_id.clone()));
+
+    if let Some(cursor_id) = &cursor {
+        let last = match Queue::find_by_id(cursor_id.clone()).one(&state.db).await {
+            Ok(Some(last)) => last,
+            Ok(None) => return Ok(ApiResponse::error("Invalid cursor".to_string())),
+            Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+        };
+
+        query = query.filter(
+            Condition::any()
+                .add(queue::Column::Position.gt(last.position))
+                .add(
+                    Condition::all()
+                        .add(queue::Column::Position.eq(last.position))
+                        .add(queue::Column::QueueId.gt(last.queue_id)),
+                ),
+        );
+    }
+
+    let queues = match query
         .order_by_asc(queue::Column::Position)  // Sort by position
+        .order_by_asc(queue::Column::QueueId)
+        .limit(page_limit(limit))
         .all(&state.db)
         .await
     {
-        Ok(queues) => {
-            let formatted_queues: Vec<QueueResponse> = queues
-                .into_iter()
-                .map(|q| QueueResponse {
-                    queue_id: q.queue_id,
-                    ride_id: q.ride_id,
-                    customer_id: q.customer_id,
-                    joined_at: q.joined_at,
-                    position: q.position,  // Include position
-                })
-                .collect();
+        Ok(queues) => queues,
+        Err(err) => {
+            return Ok(ApiResponse::error(format!(
+                "Failed to fetch queue for ride {}: {}",
+                ride_id, err
+            )))
+        }
+    };
 
-            Ok(ApiResponse::success(formatted_queues))
+    // Waits are measured in seats, so the page needs the seats taken by everyone before it.
+    let mut seats_ahead = match queues.first() {
+        Some(first) => match Queue::find()
+            .select_only()
+            .column_as(queue::Column::PartySize.sum().cast_as(Alias::new("bigint")), "seats")
+            .filter(queue::Column::RideId.eq(&ride_id))
+            .filter(queue::Column::Position.lt(first.position))
+            .into_tuple::<Option<i64>>()
+            .one(&state.db)
+            .await
+        {
+            Ok(seats) => seats.flatten().unwrap_or(0),
+            Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+        },
+        None => 0,
+    };
+
+    let members = match load_party_members(&state.db, queues.iter().map(|q| q.queue_id.clone()).collect()).await {
+        Ok(members) => members,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    let formatted_queues: Vec<QueueResponse> = queues
+        .into_iter()
+        .map(|q| {
+            // Customers only get an estimate for an entry they are part of.
+            let in_party = session.can_act_for(&q.customer_id)
+                || members
+                    .iter()
+                    .any(|m| m.queue_id == q.queue_id && session.can_act_for(&m.customer_id));
+            let estimated_wait_seconds = in_party.then(|| estimator.wait_for_seats_ahead(seats_ahead));
+            seats_ahead += i64::from(q.party_size);
+
+            QueueResponse {
+                queue_id: q.queue_id,
+                ride_id: q.ride_id,
+                customer_id: q.customer_id,
+                joined_at: q.joined_at,
+                position: q.position,  // Include position
+                party_size: q.party_size,
+                on_hold: q.on_hold,
+                estimated_wait_seconds,
+            }
+        })
+        .collect();
+
+    Ok(ApiResponse::success(formatted_queues))
+}
+
+#[derive(Deserialize)]
+pub struct DispatchRideCycleRequest {
+    pub ride_id: String,
+}
+
+// Boards the next cycle in one transaction: redeemed fast passes and the head of the standby
+// line fill up to `capacity` seats, the cycle is logged for the wait estimator, and the next
+// standby batch is told they are up soon.
+#[tauri::command]
+pub async fn dispatch_ride_cycle(
+    state: State<'_, AppState>,
+    session_token: String,
+    payload: DispatchRideCycleRequest,
+) -> Result<ApiResponse<ride_cycle::Model>, String> {
+    let session = match authorize(&state, &session_token, "dispatch_ride_cycle").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    let txn = match state.db.begin().await {
+        Ok(txn) => txn,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    let (ride, mut entries) = match lock_ride_queue(&txn, &payload.ride_id).await {
+        Ok(locked) => locked,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    if !can_operate_ride(&session, &ride) {
+        return Ok(ApiResponse::error("Ride staff can only dispatch the ride they are assigned to".to_string()));
+    }
+
+    if !ride_is_operational(&ride) {
+        return Ok(ApiResponse::error(format!("{} is not operational and can't be dispatched", ride.name)));
+    }
+
+    if let Err(message) = expire_fast_passes(&txn, Utc::now()).await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    let priority_lane = match FastPass::find()
+        .filter(fast_pass::Column::RideId.eq(&ride.ride_id))
+        .filter(fast_pass::Column::Status.eq(FastPassStatus::Redeemed))
+        .order_by_asc(fast_pass::Column::RedeemedAt)
+        .all(&txn)
+        .await
+    {
+        Ok(passes) => passes,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    // The priority lane gets its share of the seats first; seats either lane can't fill
+    // go to the other one so a cycle never leaves with empty seats and people waiting.
+    let capacity = usize::try_from(ride.capacity.max(1)).unwrap_or(1);
+    let priority_share = (capacity as f64 * state.queue_policy.fast_pass_ratio).ceil() as usize;
+
+    // A head party bigger than the standby share borrows priority seats, otherwise a steady
+    // priority lane could hold it back forever.
+    let standby_seats = (capacity - priority_lane.len().min(priority_share))
+        .max(entries.first().map_or(0, seats_for))
+        .min(capacity);
+
+    // Parties board whole: the line stops at the first party that doesn't fit this cycle.
+    let mut standby_count = 0;
+    let mut standby_seats_used = 0;
+    for entry in &entries {
+        if standby_seats_used + seats_for(entry) > standby_seats {
+            break;
+        }
+        standby_seats_used += seats_for(entry);
+        standby_count += 1;
+    }
+
+    let priority_count = priority_lane.len().min(capacity - standby_seats_used);
+
+    let boarded: Vec<queue::Model> = entries.drain(..standby_count).collect();
+
+    if !boarded.is_empty() {
+        let boarded_ids: Vec<String> = boarded.iter().map(|q| q.queue_id.clone()).collect();
+        if let Err(err) = Queue::delete_many()
+            .filter(queue::Column::QueueId.is_in(boarded_ids))
+            .exec(&txn)
+            .await
+        {
+            return Ok(ApiResponse::error(format!("Failed to dispatch ride: {}", err)));
+        }
+    }
+
+    if priority_count > 0 {
+        let pass_ids: Vec<String> = priority_lane[..priority_count].iter().map(|p| p.fast_pass_id.clone()).collect();
+        if let Err(err) = FastPass::update_many()
+            .col_expr(fast_pass::Column::Status, Expr::value(FastPassStatus::Boarded))
+            .filter(fast_pass::Column::FastPassId.is_in(pass_ids))
+            .exec(&txn)
+            .await
+        {
+            return Ok(ApiResponse::error(format!("Failed to dispatch ride: {}", err)));
+        }
+    }
+
+    if let Err(message) = renumber_queue(&txn, &entries).await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    let cycle = match (RideCycleActiveModel {
+        cycle_id: Set(format!("RC-{}", Uuid::new_v4())),
+        ride_id: Set(ride.ride_id.clone()),
+        staff_id: Set(session.user_id.clone()),
+        rider_count: Set((standby_seats_used + priority_count) as i32),
+        dispatched_at: Set(Utc::now().to_rfc3339()),
+    })
+    .insert(&txn)
+    .await
+    {
+        Ok(cycle) => cycle,
+        Err(err) => return Ok(ApiResponse::error(format!("Failed to record ride cycle: {}", err))),
+    };
+
+    // The parties that fill the next cycle, lead customers and members alike.
+    let mut next_seats = 0;
+    let next_entries: Vec<&queue::Model> = entries
+        .iter()
+        .take_while(|entry| {
+            next_seats += seats_for(entry);
+            next_seats <= capacity
+        })
+        .collect();
+
+    let next_members = match load_party_members(&txn, next_entries.iter().map(|q| q.queue_id.clone()).collect()).await {
+        Ok(members) => members,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    let next_up: Vec<String> = next_entries
+        .iter()
+        .map(|q| q.customer_id.clone())
+        .chain(next_members.into_iter().map(|m| m.customer_id))
+        .collect();
+
+    for customer_id in &next_up {
+        if let Err(message) = insert_notification(
+            &txn,
+            customer_id.clone(),
+            format!("{} is almost ready for you", ride.name),
+            format!("You are in the next group to board {}. Please make your way to the loading area.", ride.name),
+            "Ride Queue".to_string(),
+        )
+        .await
+        {
+            return Ok(ApiResponse::error(message));
+        }
+    }
+
+    if let Err(err) = txn.commit().await {
+        return Ok(ApiResponse::error(format!("Failed to dispatch ride: {}", err)));
+    }
+
+    let notification_tags: Vec<String> = next_up.iter().map(|id| format!("notification:{}", id)).collect();
+    let mut tags: Vec<&str> = notification_tags.iter().map(String::as_str).collect();
+    tags.push("queue");
+    cache_invalidate(&state.redis_pool, &tags).await;
+
+    Ok(ApiResponse::success(cycle))
+}
+
+fn downtime_reason(ride: &ride::Model) -> String {
+    let reason = match (ride.status, ride.maintenance_status) {
+        (RideStatus::Maintenance, _) | (_, RideMaintenanceStatus::Pending | RideMaintenanceStatus::InProgress) => {
+            "closed for maintenance"
+        }
+        (RideStatus::WeatherHold, _) => "on hold because of the weather",
+        (RideStatus::UnderConstruction, _) => "under construction",
+        _ => "closed",
+    };
+    format!("{} is {}", ride.name, reason)
+}
+
+// Applies the downtime policy to the line of a ride that has just gone down. Must run in the
+// transaction that changed the ride. Returns everyone who was notified or refunded so the
+// caller can invalidate their caches after committing.
+pub async fn suspend_ride_queue<C: ConnectionTrait>(
+    db: &C,
+    policy: &QueuePolicy,
+    ride: &ride::Model,
+) -> Result<Vec<String>, String> {
+    let entries = Queue::find()
+        .filter(queue::Column::RideId.eq(&ride.ride_id))
+        .order_by_asc(queue::Column::Position)
+        .all(db)
+        .await
+        .map_err(|err| format!("Database error: {}", err))?;
+
+    let members = load_party_members(db, entries.iter().map(|q| q.queue_id.clone()).collect()).await?;
+    let reason = downtime_reason(ride);
+    let mut affected = Vec::new();
+
+    match policy.downtime_action {
+        DowntimeAction::Hold => {
+            Queue::update_many()
+                .col_expr(queue::Column::OnHold, Expr::value(true))
+                .filter(queue::Column::RideId.eq(&ride.ride_id))
+                .exec(db)
+                .await
+                .map_err(|err| format!("Failed to hold queue: {}", err))?;
+
+            for customer_id in entries.iter().map(|q| &q.customer_id).chain(members.iter().map(|m| &m.customer_id)) {
+                insert_notification(
+                    db,
+                    customer_id.clone(),
+                    format!("Your place for {} is on hold", ride.name),
+                    format!("{}. You keep your place in line and we will let you know when it reopens.", reason),
+                    "Ride Queue".to_string(),
+                )
+                .await?;
+                affected.push(customer_id.clone());
+            }
+        }
+        DowntimeAction::Evict => {
+            for entry in &entries {
+                let charged = refund_join_charge(db, entry, &ride.name).await?;
+
+                let party = std::iter::once(&entry.customer_id)
+                    .chain(members.iter().filter(|m| m.queue_id == entry.queue_id).map(|m| &m.customer_id));
+
+                for customer_id in party {
+                    let refund_note = if charged > 0 && *customer_id == entry.customer_id {
+                        format!(" {:.2} has been refunded to your wallet.", to_major_units(charged))
+                    } else {
+                        String::new()
+                    };
+
+                    insert_notification(
+                        db,
+                        customer_id.clone(),
+                        format!("You have been removed from the line for {}", ride.name),
+                        format!("{}, so its queue has been cleared.{}", reason, refund_note),
+                        "Ride Queue".to_string(),
+                    )
+                    .await?;
+                    affected.push(customer_id.clone());
+                }
+            }
+
+            Queue::delete_many()
+                .filter(queue::Column::RideId.eq(&ride.ride_id))
+                .exec(db)
+                .await
+                .map_err(|err| format!("Failed to clear queue: {}", err))?;
         }
-        Err(err) => Ok(ApiResponse::error(format!(
-            "Failed to fetch queue for ride {}: {}",
-            ride_id, err
-        ))),
     }
-}
\ No newline at end of file
+
+    Ok(affected)
+}
+
+// Lifts the hold on a ride's line once the ride is operational again.
+pub async fn resume_ride_queue<C: ConnectionTrait>(db: &C, ride: &ride::Model) -> Result<Vec<String>, String> {
+    let held = Queue::find()
+        .filter(queue::Column::RideId.eq(&ride.ride_id))
+        .filter(queue::Column::OnHold.eq(true))
+        .all(db)
+        .await
+        .map_err(|err| format!("Database error: {}", err))?;
+
+    if held.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    Queue::update_many()
+        .col_expr(queue::Column::OnHold, Expr::value(false))
+        .filter(queue::Column::RideId.eq(&ride.ride_id))
+        .exec(db)
+        .await
+        .map_err(|err| format!("Failed to release queue: {}", err))?;
+
+    let members = load_party_members(db, held.iter().map(|q| q.queue_id.clone()).collect()).await?;
+    let mut affected = Vec::new();
+
+    for customer_id in held.iter().map(|q| &q.customer_id).chain(members.iter().map(|m| &m.customer_id)) {
+        insert_notification(
+            db,
+            customer_id.clone(),
+            format!("{} has reopened", ride.name),
+            format!("{} is running again and your place in line is active.", ride.name),
+            "Ride Queue".to_string(),
+        )
+        .await?;
+        affected.push(customer_id.clone());
+    }
+
+    Ok(affected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ride() -> ride::Model {
+        ride::Model {
+            ride_id: "R-1".to_string(),
+            staff_id: None,
+            name: "Sky Coaster".to_string(),
+            price: 0.0,
+            image: Vec::new(),
+            description: String::new(),
+            location: String::new(),
+            status: RideStatus::Operational,
+            capacity: 20,
+            maintenance_status: RideMaintenanceStatus::Available,
+            cycle_duration: 300,
+            min_age: None,
+            min_height_cm: None,
+            max_height_cm: None,
+            wheelchair_accessible: true,
+            health_advisory: None,
+            ride_type: None,
+        }
+    }
+
+    fn rider(dob: &str, height_cm: Option<i32>) -> user::Model {
+        user::Model {
+            user_id: "U-1".to_string(),
+            name: "Rider".to_string(),
+            email: "rider@example.com".to_string(),
+            password: None,
+            dob: dob.to_string(),
+            role: "Customer".to_string(),
+            balance: 0,
+            status: None,
+            restaurant_id: None,
+            must_change_password: false,
+            password_changed_at: None,
+            height_cm,
+            uses_wheelchair: false,
+        }
+    }
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 10, 18).unwrap()
+    }
+
+    #[test]
+    fn a_ride_without_limits_takes_anyone() {
+        assert!(eligibility_problems(&ride(), &rider("not a date", None), today()).is_empty());
+    }
+
+    #[test]
+    fn age_counts_from_the_birthday() {
+        let ride = ride::Model { min_age: Some(12), ..ride() };
+
+        assert!(eligibility_problems(&ride, &rider("2014-10-18", None), today()).is_empty());
+        assert_eq!(
+            eligibility_problems(&ride, &rider("2014-10-19", None), today()),
+            vec!["riders must be at least 12 years old"]
+        );
+        assert_eq!(
+            eligibility_problems(&ride, &rider("18/10/2014", None), today()),
+            vec!["a valid date of birth is needed to check the age limit"]
+        );
+    }
+
+    #[test]
+    fn height_limits_are_inclusive() {
+        let ride = ride::Model { min_height_cm: Some(120), max_height_cm: Some(195), ..ride() };
+
+        assert!(eligibility_problems(&ride, &rider("2000-01-01", Some(120)), today()).is_empty());
+        assert!(eligibility_problems(&ride, &rider("2000-01-01", Some(195)), today()).is_empty());
+        assert_eq!(
+            eligibility_problems(&ride, &rider("2000-01-01", Some(119)), today()),
+            vec!["riders must be at least 120 cm tall"]
+        );
+        assert_eq!(
+            eligibility_problems(&ride, &rider("2000-01-01", Some(196)), today()),
+            vec!["riders must be no taller than 195 cm"]
+        );
+        assert_eq!(
+            eligibility_problems(&ride, &rider("2000-01-01", None), today()),
+            vec!["height must be measured by staff before riding"]
+        );
+    }
+
+    #[test]
+    fn every_problem_is_reported_at_once() {
+        let ride = ride::Model { min_age: Some(12), min_height_cm: Some(120), wheelchair_accessible: false, ..ride() };
+        let rider = user::Model { uses_wheelchair: true, ..rider("2020-01-01", Some(100)) };
+
+        assert_eq!(
+            eligibility_problems(&ride, &rider, today()),
+            vec![
+                "riders must be at least 12 years old",
+                "riders must be at least 120 cm tall",
+                "the ride is not wheelchair accessible",
+            ]
+        );
+    }
+}
@@ -3,8 +3,9 @@ use entity::restaurant::{self, ActiveModel as RestaurantActiveModel, Entity as R
 use sea_orm::{ActiveModelTrait, EntityTrait, QueryOrder, ActiveValue::Set};
 use serde::{Deserialize, Serialize};
 use tauri::State;
-use crate::{ApiResponse, cache_get, cache_set, cache_delete, AppState};
-use base64::encode;
+use crate::{ApiResponse, cache_get, cache_set_tagged, cache_invalidate, AppState};
+use crate::controllers::session_handler::authorize;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
 
 #[derive(Serialize)]
 pub struct RestaurantResponse {
@@ -38,7 +39,12 @@ struct RestaurantCache {
 #[tauri::command]
 pub async fn view_all_restaurants(
     state: State<'_, AppState>,
+    session_token: String,
 ) -> Result<ApiResponse<Vec<RestaurantResponse>>, String> {
+    if let Err(message) = authorize(&state, &session_token, "view_all_restaurants").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     let cache_key = "get_all_restaurants_cache";
 
     if let Some(cached_restaurants) = cache_get::<Vec<RestaurantCache>>(&state.redis_pool, cache_key).await {
@@ -51,7 +57,7 @@ pub async fn view_all_restaurants(
                     name: cache.name,
                     description: cache.description,
                     cuisine_type: cache.cuisine_type,
-                    image: encode(&model.image),
+                    image: STANDARD.encode(&model.image),
                     location: cache.location,
                     required_waiter: cache.required_waiter,
                     required_chef: cache.required_chef,
@@ -78,7 +84,7 @@ pub async fn view_all_restaurants(
                     name: r.name.clone(),
                     description: r.description.clone(),
                     cuisine_type: r.cuisine_type.clone(),
-                    image: encode(&r.image),
+                    image: STANDARD.encode(&r.image),
                     location: r.location.clone(),
                     required_waiter: r.required_waiter,
                     required_chef: r.required_chef,
@@ -104,7 +110,7 @@ pub async fn view_all_restaurants(
                 })
                 .collect();
 
-            cache_set(&state.redis_pool, cache_key, &cache_data, 60).await;
+            cache_set_tagged(&state.redis_pool, cache_key, &cache_data, 60, &["restaurant"]).await;
 
             Ok(ApiResponse::success(full_response))
         }
@@ -134,8 +140,13 @@ pub struct CreateRestaurantRequest {
 #[tauri::command]
 pub async fn create_restaurant(
     state: State<'_, AppState>,
+    session_token: String,
     payload: CreateRestaurantRequest,
 ) -> Result<ApiResponse<restaurant::Model>, String> {
+    if let Err(message) = authorize(&state, &session_token, "create_restaurant").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     let new_restaurant = RestaurantActiveModel {
         restaurant_id: Set(payload.restaurant_id),
         name: Set(payload.name),
@@ -148,12 +159,11 @@ pub async fn create_restaurant(
         operational_status: Set(payload.operational_status),
         operational_start_hours: Set(payload.operational_start_hours),
         operational_end_hours: Set(payload.operational_end_hours),
-        ..Default::default()
     };
 
     match new_restaurant.insert(&state.db).await {
         Ok(restaurant) => {
-            cache_delete(&state.redis_pool, "get_all_restaurants_cache").await;
+            cache_invalidate(&state.redis_pool, &["restaurant"]).await;
             Ok(ApiResponse::success(restaurant))
         }
         Err(err) => Ok(ApiResponse::error(format!("Failed to create restaurant: {}", err))),
@@ -169,14 +179,19 @@ pub struct DeleteRestaurantRequest {
 #[tauri::command]
 pub async fn delete_restaurant(
     state: State<'_, AppState>,
+    session_token: String,
     payload: DeleteRestaurantRequest,
 ) -> Result<ApiResponse<()>, String> {
+    if let Err(message) = authorize(&state, &session_token, "delete_restaurant").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     match Restaurant::delete_by_id(payload.restaurant_id.clone()).exec(&state.db).await {
         Ok(delete_result) => {
             if delete_result.rows_affected == 0 {
                 return Ok(ApiResponse::error(format!("No restaurant found with ID: {}", payload.restaurant_id)));
             }
-            cache_delete(&state.redis_pool, "get_all_restaurants_cache").await;
+            cache_invalidate(&state.redis_pool, &["restaurant"]).await;
             Ok(ApiResponse::success(()))
         }
         Err(err) => Ok(ApiResponse::error(format!("Failed to delete restaurant: {}", err))),
@@ -202,8 +217,13 @@ pub struct UpdateRestaurantRequest {
 #[tauri::command]
 pub async fn update_restaurant(
     state: State<'_, AppState>,
+    session_token: String,
     payload: UpdateRestaurantRequest,
 ) -> Result<ApiResponse<restaurant::Model>, String> {
+    if let Err(message) = authorize(&state, &session_token, "update_restaurant").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     match Restaurant::find_by_id(payload.restaurant_id.clone()).one(&state.db).await {
         Ok(Some(existing_restaurant)) => {
             let mut active_restaurant: RestaurantActiveModel = existing_restaurant.into();
@@ -220,7 +240,7 @@ pub async fn update_restaurant(
 
             match active_restaurant.update(&state.db).await {
                 Ok(updated_restaurant) => {
-                    cache_delete(&state.redis_pool, "get_all_restaurants_cache").await;
+                    cache_invalidate(&state.redis_pool, &["restaurant"]).await;
                     Ok(ApiResponse::success(updated_restaurant))
                 }
                 Err(err) => Ok(ApiResponse::error(format!("Failed to update restaurant: {}", err))),
@@ -0,0 +1,389 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use entity::maintenance::{self, Entity as Maintenance};
+use entity::ride::{self, Entity as Ride};
+use entity::ride_status_history::{self, Entity as RideStatusHistory};
+use entity::sea_orm_active_enums::{MaintenanceStatus, RideMaintenanceStatus, RideStatus};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder};
+use serde::Serialize;
+use std::collections::HashMap;
+use tauri::State;
+use crate::{ApiResponse, AppState};
+use crate::controllers::session_handler::authorize;
+
+// Format `create_maintenance_request` stamps on `maintenance.date`.
+const MAINTENANCE_DATE_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+#[derive(Serialize)]
+pub struct RideAvailability {
+    pub ride_id: String,
+    pub ride_name: String,
+    pub scheduled_seconds: i64,
+    pub uptime_seconds: i64,
+    pub downtime_seconds: i64,
+    pub availability_percent: Option<f64>,
+    pub failures: i64,
+    pub mtbf_seconds: Option<i64>,
+    pub mttr_seconds: Option<i64>,
+    pub maintenance_requests: i64,
+    pub open_maintenance_requests: i64,
+}
+
+// Planned closures are not time the ride was expected to run, so they don't count against it.
+fn is_planned_closure(status: RideStatus) -> bool {
+    matches!(status, RideStatus::Closed | RideStatus::UnderConstruction)
+}
+
+fn is_under_repair(status: RideStatus, maintenance_status: RideMaintenanceStatus) -> bool {
+    !is_planned_closure(status)
+        && (status == RideStatus::Maintenance || maintenance_status != RideMaintenanceStatus::Available)
+}
+
+fn is_up(status: RideStatus, maintenance_status: RideMaintenanceStatus) -> bool {
+    status == RideStatus::Operational && maintenance_status == RideMaintenanceStatus::Available
+}
+
+// Report dates are whole days in UTC; the end date is inclusive and never runs past now.
+fn report_range(start_date: &str, end_date: &str) -> Result<(DateTime<Utc>, DateTime<Utc>), String> {
+    let parse = |date: &str| {
+        NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map(|day| day.and_time(Default::default()).and_utc())
+            .map_err(|_| format!("Invalid date {}, expected YYYY-MM-DD", date))
+    };
+
+    let start = parse(start_date)?;
+    let end = (parse(end_date)? + Duration::days(1)).min(Utc::now());
+
+    if start >= end {
+        return Err("Report range must start before it ends and not lie in the future".to_string());
+    }
+
+    Ok((start, end))
+}
+
+#[derive(Default)]
+struct Timeline {
+    scheduled_seconds: i64,
+    uptime_seconds: i64,
+    failures: i64,
+    repairs: i64,
+    repair_seconds: i64,
+}
+
+// Walks a ride's status history in order. Time before the first recorded status is unknown and
+// left out; a failure is the ride going from running to under repair, and a repair counts once
+// it finishes inside the range.
+fn walk_history(history: &[ride_status_history::Model], start: DateTime<Utc>, end: DateTime<Utc>) -> Timeline {
+    let changes: Vec<(DateTime<Utc>, RideStatus, RideMaintenanceStatus)> = history
+        .iter()
+        .filter_map(|row| {
+            DateTime::parse_from_rfc3339(&row.changed_at)
+                .ok()
+                .map(|at| (at.with_timezone(&Utc), row.to_status, row.to_maintenance_status))
+        })
+        .collect();
+
+    let mut timeline = Timeline::default();
+    let mut was_up = false;
+    let mut repair_started: Option<DateTime<Utc>> = None;
+
+    for (i, (changed_at, status, maintenance_status)) in changes.iter().enumerate() {
+        let until = changes.get(i + 1).map_or(end, |(next, _, _)| *next).min(end);
+        let in_range = *changed_at >= start && *changed_at < end;
+        let up = is_up(*status, *maintenance_status);
+        let under_repair = is_under_repair(*status, *maintenance_status);
+
+        match (repair_started, under_repair) {
+            (None, true) => {
+                repair_started = Some(*changed_at);
+                if was_up && in_range {
+                    timeline.failures += 1;
+                }
+            }
+            (Some(started), false) => {
+                if in_range {
+                    timeline.repairs += 1;
+                    timeline.repair_seconds += (*changed_at - started).num_seconds();
+                }
+                repair_started = None;
+            }
+            _ => {}
+        }
+
+        let overlap = (until - (*changed_at).max(start)).num_seconds().max(0);
+        if !is_planned_closure(*status) {
+            timeline.scheduled_seconds += overlap;
+            if up {
+                timeline.uptime_seconds += overlap;
+            }
+        }
+        was_up = up;
+    }
+
+    timeline
+}
+
+async fn build_report<C: ConnectionTrait>(
+    db: &C,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<Vec<RideAvailability>, String> {
+    let rides = Ride::find()
+        .order_by_asc(ride::Column::Name)
+        .all(db)
+        .await
+        .map_err(|err| format!("Database error: {}", err))?;
+
+    let mut history_by_ride: HashMap<String, Vec<ride_status_history::Model>> = HashMap::new();
+    for row in RideStatusHistory::find()
+        .filter(ride_status_history::Column::ChangedAt.lt(end.to_rfc3339()))
+        .order_by_asc(ride_status_history::Column::ChangedAt)
+        .all(db)
+        .await
+        .map_err(|err| format!("Database error: {}", err))?
+    {
+        history_by_ride.entry(row.ride_id.clone()).or_default().push(row);
+    }
+
+    let mut requests_by_ride: HashMap<String, Vec<maintenance::Model>> = HashMap::new();
+    for request in Maintenance::find()
+        .filter(maintenance::Column::Date.gte(start.format(MAINTENANCE_DATE_FORMAT).to_string()))
+        .filter(maintenance::Column::Date.lt(end.format(MAINTENANCE_DATE_FORMAT).to_string()))
+        .all(db)
+        .await
+        .map_err(|err| format!("Database error: {}", err))?
+    {
+        requests_by_ride.entry(request.ride_id.clone()).or_default().push(request);
+    }
+
+    let report = rides
+        .into_iter()
+        .map(|ride| {
+            let timeline = history_by_ride
+                .get(&ride.ride_id)
+                .map(|history| walk_history(history, start, end))
+                .unwrap_or_default();
+            let requests = requests_by_ride.get(&ride.ride_id).map(Vec::as_slice).unwrap_or_default();
+
+            RideAvailability {
+                availability_percent: (timeline.scheduled_seconds > 0).then(|| {
+                    (timeline.uptime_seconds as f64 * 10000.0 / timeline.scheduled_seconds as f64).round() / 100.0
+                }),
+                mtbf_seconds: (timeline.failures > 0).then(|| timeline.uptime_seconds / timeline.failures),
+                mttr_seconds: (timeline.repairs > 0).then(|| timeline.repair_seconds / timeline.repairs),
+                ride_id: ride.ride_id,
+                ride_name: ride.name,
+                scheduled_seconds: timeline.scheduled_seconds,
+                uptime_seconds: timeline.uptime_seconds,
+                downtime_seconds: timeline.scheduled_seconds - timeline.uptime_seconds,
+                failures: timeline.failures,
+                maintenance_requests: requests.len() as i64,
+                open_maintenance_requests: requests
+                    .iter()
+                    .filter(|request| request.status != MaintenanceStatus::Completed)
+                    .count() as i64,
+            }
+        })
+        .collect();
+
+    Ok(report)
+}
+
+// Spreadsheets run cells starting with these as formulas, so names from the database are
+// quoted into plain text first.
+const FORMULA_PREFIXES: [char; 6] = ['=', '+', '-', '@', '\t', '\r'];
+
+fn csv_field(value: &str) -> String {
+    let value = if value.starts_with(FORMULA_PREFIXES) {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    };
+
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+fn optional_field<T: ToString>(value: Option<T>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+fn report_to_csv(report: &[RideAvailability]) -> String {
+    let mut csv = String::from(
+        "ride_id,ride_name,scheduled_hours,uptime_hours,downtime_hours,availability_percent,failures,\
+         mtbf_hours,mttr_hours,maintenance_requests,open_maintenance_requests\n",
+    );
+    let hours = |seconds: i64| format!("{:.2}", seconds as f64 / 3600.0);
+
+    for row in report {
+        let line = [
+            csv_field(&row.ride_id),
+            csv_field(&row.ride_name),
+            hours(row.scheduled_seconds),
+            hours(row.uptime_seconds),
+            hours(row.downtime_seconds),
+            optional_field(row.availability_percent),
+            row.failures.to_string(),
+            optional_field(row.mtbf_seconds.map(hours)),
+            optional_field(row.mttr_seconds.map(hours)),
+            row.maintenance_requests.to_string(),
+            row.open_maintenance_requests.to_string(),
+        ];
+        csv.push_str(&line.join(","));
+        csv.push('\n');
+    }
+
+    csv
+}
+
+#[tauri::command]
+pub async fn view_ride_availability_report(
+    state: State<'_, AppState>,
+    session_token: String,
+    start_date: String,
+    end_date: String,
+) -> Result<ApiResponse<Vec<RideAvailability>>, String> {
+    if let Err(message) = authorize(&state, &session_token, "view_ride_availability_report").await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    let (start, end) = match report_range(&start_date, &end_date) {
+        Ok(range) => range,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    match build_report(&state.db, start, end).await {
+        Ok(report) => Ok(ApiResponse::success(report)),
+        Err(message) => Ok(ApiResponse::error(message)),
+    }
+}
+
+// Returns the report as CSV text for the frontend to save; durations are in hours.
+#[tauri::command]
+pub async fn export_ride_availability_report(
+    state: State<'_, AppState>,
+    session_token: String,
+    start_date: String,
+    end_date: String,
+) -> Result<ApiResponse<String>, String> {
+    if let Err(message) = authorize(&state, &session_token, "export_ride_availability_report").await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    let (start, end) = match report_range(&start_date, &end_date) {
+        Ok(range) => range,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    match build_report(&state.db, start, end).await {
+        Ok(report) => Ok(ApiResponse::success(report_to_csv(&report))),
+        Err(message) => Ok(ApiResponse::error(message)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: i64 = 3600;
+
+    fn at(value: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(value).unwrap().with_timezone(&Utc)
+    }
+
+    fn history(changes: &[(&str, RideStatus, RideMaintenanceStatus)]) -> Vec<ride_status_history::Model> {
+        changes
+            .iter()
+            .enumerate()
+            .map(|(i, (changed_at, status, maintenance_status))| ride_status_history::Model {
+                history_id: format!("RSH-{}", i),
+                ride_id: "R-1".to_string(),
+                from_status: None,
+                to_status: *status,
+                from_maintenance_status: None,
+                to_maintenance_status: *maintenance_status,
+                actor_id: None,
+                reason: None,
+                changed_at: changed_at.to_string(),
+            })
+            .collect()
+    }
+
+    fn walk(changes: &[(&str, RideStatus, RideMaintenanceStatus)]) -> Timeline {
+        walk_history(&history(changes), at("2026-10-18T00:00:00Z"), at("2026-10-18T10:00:00Z"))
+    }
+
+    #[test]
+    fn a_ride_up_all_day_has_no_downtime() {
+        let timeline = walk(&[("2026-10-17T08:00:00Z", RideStatus::Operational, RideMaintenanceStatus::Available)]);
+
+        assert_eq!(timeline.scheduled_seconds, 10 * HOUR);
+        assert_eq!(timeline.uptime_seconds, 10 * HOUR);
+        assert_eq!(timeline.failures, 0);
+        assert_eq!(timeline.repairs, 0);
+    }
+
+    #[test]
+    fn counts_a_failure_and_its_repair() {
+        let timeline = walk(&[
+            ("2026-10-18T00:00:00Z", RideStatus::Operational, RideMaintenanceStatus::Available),
+            ("2026-10-18T02:00:00Z", RideStatus::Maintenance, RideMaintenanceStatus::Pending),
+            ("2026-10-18T03:00:00Z", RideStatus::Maintenance, RideMaintenanceStatus::InProgress),
+            ("2026-10-18T05:00:00Z", RideStatus::Operational, RideMaintenanceStatus::Available),
+        ]);
+
+        assert_eq!(timeline.scheduled_seconds, 10 * HOUR);
+        assert_eq!(timeline.uptime_seconds, 7 * HOUR);
+        assert_eq!(timeline.failures, 1);
+        assert_eq!(timeline.repairs, 1);
+        assert_eq!(timeline.repair_seconds, 3 * HOUR);
+    }
+
+    #[test]
+    fn planned_closures_are_not_scheduled_time() {
+        let timeline = walk(&[
+            ("2026-10-18T00:00:00Z", RideStatus::Operational, RideMaintenanceStatus::Available),
+            ("2026-10-18T04:00:00Z", RideStatus::Closed, RideMaintenanceStatus::Available),
+        ]);
+
+        assert_eq!(timeline.scheduled_seconds, 4 * HOUR);
+        assert_eq!(timeline.uptime_seconds, 4 * HOUR);
+        assert_eq!(timeline.failures, 0);
+    }
+
+    #[test]
+    fn time_before_the_first_record_is_left_out() {
+        let timeline = walk(&[("2026-10-18T03:00:00Z", RideStatus::Operational, RideMaintenanceStatus::Available)]);
+
+        assert_eq!(timeline.scheduled_seconds, 7 * HOUR);
+        assert_eq!(timeline.uptime_seconds, 7 * HOUR);
+    }
+
+    #[test]
+    fn a_repair_finished_in_range_counts_without_its_failure() {
+        let timeline = walk(&[
+            ("2026-10-17T20:00:00Z", RideStatus::Operational, RideMaintenanceStatus::Available),
+            ("2026-10-17T23:00:00Z", RideStatus::WeatherHold, RideMaintenanceStatus::Pending),
+            ("2026-10-18T02:00:00Z", RideStatus::Operational, RideMaintenanceStatus::Available),
+        ]);
+
+        assert_eq!(timeline.scheduled_seconds, 10 * HOUR);
+        assert_eq!(timeline.uptime_seconds, 8 * HOUR);
+        assert_eq!(timeline.failures, 0);
+        assert_eq!(timeline.repairs, 1);
+        assert_eq!(timeline.repair_seconds, 3 * HOUR);
+    }
+
+    #[test]
+    fn csv_fields_never_start_a_formula() {
+        assert_eq!(csv_field("Sky Coaster"), "Sky Coaster");
+        assert_eq!(csv_field("=HYPERLINK(\"x\")"), "\"'=HYPERLINK(\"\"x\"\")\"");
+        assert_eq!(csv_field("+1"), "'+1");
+        assert_eq!(csv_field("-1"), "'-1");
+        assert_eq!(csv_field("@SUM(A1)"), "'@SUM(A1)");
+        assert_eq!(csv_field("Drop, Tower"), "\"Drop, Tower\"");
+    }
+}
@@ -1,14 +1,206 @@
 use anyhow::Result;
+use chrono::Utc;
 use entity::ride::{self, ActiveModel as RideActiveModel, Entity as Ride};
+use entity::ride_status_history::{self, Entity as RideStatusHistory};
 use entity::queue::{self, Entity as Queue};
+use entity::sea_orm_active_enums::{RideMaintenanceStatus, RideStatus};
 use sea_orm::{
-    ActiveModelTrait, EntityTrait, ActiveValue::Set, ColumnTrait, QueryFilter, PaginatorTrait,
+    sea_query::Alias, ActiveEnum, ActiveModelTrait, ConnectionTrait, EntityTrait, ActiveValue::Set, ColumnTrait,
+    QueryFilter, QueryOrder, QuerySelect, TransactionTrait,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use tauri::State;
-use base64::encode;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use uuid::Uuid;
 
-use crate::{ApiResponse, cache_get, cache_set, cache_delete, AppState};
+use crate::{ApiResponse, cache_get, cache_set_tagged, cache_invalidate, AppState};
+use crate::controllers::session_handler::{authorize, Session};
+use crate::controllers::checklist_handler::failed_required_items;
+use crate::controllers::queue_handler::{
+    can_operate_ride, resume_ride_queue, ride_is_operational, suspend_ride_queue, QueuePolicy,
+};
+use crate::controllers::wait_time_estimator::WaitEstimator;
+
+// Matches the column default for rides created before cycle durations were configurable.
+const DEFAULT_CYCLE_DURATION_SECONDS: i32 = 300;
+
+const RIDE_TRANSITIONS: &[(RideStatus, RideStatus, &[&str])] = &[
+    (RideStatus::Operational, RideStatus::Closed, &["Ride Manager", "CEO", "COO"]),
+    (RideStatus::Operational, RideStatus::Maintenance, &["Ride Manager", "Maintenance Manager"]),
+    (RideStatus::Operational, RideStatus::WeatherHold, &["Ride Manager", "Ride Staff"]),
+    (RideStatus::Closed, RideStatus::Operational, &["Ride Manager", "CEO", "COO"]),
+    (RideStatus::Closed, RideStatus::Maintenance, &["Ride Manager", "Maintenance Manager"]),
+    (RideStatus::Closed, RideStatus::UnderConstruction, &["Ride Manager", "CEO", "COO"]),
+    (RideStatus::Maintenance, RideStatus::Operational, &["Ride Manager", "Maintenance Manager"]),
+    (RideStatus::Maintenance, RideStatus::Closed, &["Ride Manager", "Maintenance Manager"]),
+    (RideStatus::WeatherHold, RideStatus::Operational, &["Ride Manager", "Ride Staff"]),
+    (RideStatus::WeatherHold, RideStatus::Closed, &["Ride Manager"]),
+    (RideStatus::UnderConstruction, RideStatus::Closed, &["Ride Manager", "CEO", "COO"]),
+    (RideStatus::UnderConstruction, RideStatus::Operational, &["Ride Manager", "CEO", "COO"]),
+];
+
+fn validate_eligibility(min_age: Option<i32>, min_height_cm: Option<i32>, max_height_cm: Option<i32>) -> Result<(), String> {
+    if min_age.is_some_and(|age| age < 0) {
+        return Err("Minimum age cannot be negative".to_string());
+    }
+
+    if min_height_cm.is_some_and(|height| height <= 0) || max_height_cm.is_some_and(|height| height <= 0) {
+        return Err("Height limits must be a positive number of centimetres".to_string());
+    }
+
+    if let (Some(min_height), Some(max_height)) = (min_height_cm, max_height_cm) {
+        if min_height > max_height {
+            return Err("Minimum height cannot be above the maximum height".to_string());
+        }
+    }
+
+    Ok(())
+}
+
+// Roles that may flag or clear pending maintenance without moving the ride's status.
+const MAINTENANCE_STATUS_ROLES: &[&str] = &["Ride Manager", "Maintenance Manager", "CEO", "COO"];
+
+fn ride_transition_roles(from: RideStatus, to: RideStatus) -> Option<&'static [&'static str]> {
+    RIDE_TRANSITIONS
+        .iter()
+        .find(|(f, t, _)| *f == from && *t == to)
+        .map(|(_, _, roles)| *roles)
+}
+
+async fn record_ride_status_change<C: ConnectionTrait>(
+    db: &C,
+    ride_id: &str,
+    from: Option<(RideStatus, RideMaintenanceStatus)>,
+    to: (RideStatus, RideMaintenanceStatus),
+    actor_id: &str,
+    reason: Option<String>,
+) -> Result<(), String> {
+    ride_status_history::ActiveModel {
+        history_id: Set(format!("RSH-{}", Uuid::new_v4())),
+        ride_id: Set(ride_id.to_string()),
+        from_status: Set(from.map(|(status, _)| status)),
+        to_status: Set(to.0),
+        from_maintenance_status: Set(from.map(|(_, maintenance_status)| maintenance_status)),
+        to_maintenance_status: Set(to.1),
+        actor_id: Set(Some(actor_id.to_string())),
+        reason: Set(reason.filter(|reason| !reason.trim().is_empty())),
+        changed_at: Set(Utc::now().to_rfc3339()),
+    }
+    .insert(db)
+    .await
+    .map_err(|e| format!("Failed to record ride status change: {}", e))?;
+
+    Ok(())
+}
+
+// Moves a locked ride to a new status, records the transition and holds or resumes its queue.
+// Returns the updated ride and the customers whose queue entries were touched.
+async fn change_ride_state<C: ConnectionTrait>(
+    db: &C,
+    policy: &QueuePolicy,
+    session: &Session,
+    ride: ride::Model,
+    status: RideStatus,
+    maintenance_status: RideMaintenanceStatus,
+    reason: Option<String>,
+) -> Result<(ride::Model, Vec<String>), String> {
+    let from = (ride.status, ride.maintenance_status);
+    if from == (status, maintenance_status) {
+        return Ok((ride, Vec::new()));
+    }
+
+    if ride.status != status {
+        match ride_transition_roles(ride.status, status) {
+            None => {
+                return Err(format!(
+                    "Cannot move a ride from {} to {}",
+                    ride.status.to_value(),
+                    status.to_value()
+                ));
+            }
+            Some(roles) if !roles.contains(&session.role.as_str()) => {
+                return Err(format!(
+                    "Role {} cannot move a ride from {} to {}",
+                    session.role,
+                    ride.status.to_value(),
+                    status.to_value()
+                ));
+            }
+            Some(_) => {}
+        }
+    }
+
+    if ride.maintenance_status != maintenance_status && !MAINTENANCE_STATUS_ROLES.contains(&session.role.as_str()) {
+        return Err(format!("Role {} cannot change a ride's maintenance status", session.role));
+    }
+
+    apply_ride_state(db, policy, &session.user_id, ride, status, maintenance_status, reason).await
+}
+
+// Writes a ride state change without checking who asked for it; callers that act for a user
+// go through `change_ride_state`, others (such as maintenance tickets) drive the ride directly.
+pub async fn apply_ride_state<C: ConnectionTrait>(
+    db: &C,
+    policy: &QueuePolicy,
+    actor_id: &str,
+    ride: ride::Model,
+    status: RideStatus,
+    maintenance_status: RideMaintenanceStatus,
+    reason: Option<String>,
+) -> Result<(ride::Model, Vec<String>), String> {
+    let from = (ride.status, ride.maintenance_status);
+    if from == (status, maintenance_status) {
+        return Ok((ride, Vec::new()));
+    }
+
+    let was_operational = ride_is_operational(&ride);
+
+    if status == RideStatus::Operational && maintenance_status == RideMaintenanceStatus::Available {
+        let failed = failed_required_items(db, &ride.ride_id).await?;
+        if !failed.is_empty() {
+            return Err(format!(
+                "{} cannot reopen while required inspection items have failed: {}",
+                ride.name,
+                failed.join(", ")
+            ));
+        }
+    }
+
+    let mut active_ride: RideActiveModel = ride.into();
+    active_ride.status = Set(status);
+    active_ride.maintenance_status = Set(maintenance_status);
+    let updated_ride = active_ride
+        .update(db)
+        .await
+        .map_err(|err| format!("Failed to update ride: {}", err))?;
+
+    record_ride_status_change(db, &updated_ride.ride_id, Some(from), (status, maintenance_status), actor_id, reason)
+        .await?;
+
+    let affected = match (was_operational, ride_is_operational(&updated_ride)) {
+        (true, false) => suspend_ride_queue(db, policy, &updated_ride).await?,
+        (false, true) => resume_ride_queue(db, &updated_ride).await?,
+        _ => Vec::new(),
+    };
+
+    Ok((updated_ride, affected))
+}
+
+// Cache tags to drop after a status change, including the queues and inboxes of affected customers.
+pub async fn invalidate_ride_state(state: &AppState, ride_id: &str, affected: &[String]) {
+    let affected_tags: Vec<String> = affected
+        .iter()
+        .flat_map(|customer_id| [format!("notification:{}", customer_id), format!("user:{}", customer_id)])
+        .collect();
+    let ride_tag = format!("ride:{}", ride_id);
+    let mut tags: Vec<&str> = vec!["ride", &ride_tag];
+    if !affected.is_empty() {
+        tags.extend(["queue", "user"]);
+        tags.extend(affected_tags.iter().map(String::as_str));
+    }
+    cache_invalidate(&state.redis_pool, &tags).await;
+}
 
 #[derive(Serialize, Deserialize)]
 pub struct RideResponse {
@@ -18,9 +210,17 @@ pub struct RideResponse {
     price: f64,
     description: String,
     location: String,
-    status: String,
+    ride_type: Option<String>,
+    status: RideStatus,
     capacity: i32,
-    maintenance_status: String,
+    maintenance_status: RideMaintenanceStatus,
+    cycle_duration: i32,
+    min_age: Option<i32>,
+    min_height_cm: Option<i32>,
+    max_height_cm: Option<i32>,
+    wheelchair_accessible: bool,
+    health_advisory: Option<String>,
+    estimated_wait_seconds: i64,
     image: String,
 }
 
@@ -32,88 +232,163 @@ struct RideCache {
     price: f64,
     description: String,
     location: String,
-    status: String,
+    ride_type: Option<String>,
+    status: RideStatus,
     capacity: i32,
-    maintenance_status: String,
+    maintenance_status: RideMaintenanceStatus,
+    cycle_duration: i32,
+    min_age: Option<i32>,
+    min_height_cm: Option<i32>,
+    max_height_cm: Option<i32>,
+    wheelchair_accessible: bool,
+    health_advisory: Option<String>,
+    image: String,
+}
+
+// Expected wait for someone joining the back of the ride's line right now.
+async fn estimate_ride_wait(state: &AppState, ride: &ride::Model) -> Result<i64, String> {
+    let seats_in_line: Option<Option<i64>> = Queue::find()
+        .select_only()
+        .column_as(queue::Column::PartySize.sum().cast_as(Alias::new("bigint")), "seats")
+        .filter(queue::Column::RideId.eq(&ride.ride_id))
+        .into_tuple()
+        .one(&state.db)
+        .await
+        .map_err(|err| err.to_string())?;
+
+    let estimator = WaitEstimator::for_ride(&state.db, ride).await?;
+    Ok(estimator.wait_for_seats_ahead(seats_in_line.flatten().unwrap_or(0)))
+}
+
+// The same estimate for every ride in a listing, from one queue query and one cycle query.
+async fn estimate_ride_waits(state: &AppState, rides: &[RideCache]) -> Result<HashMap<String, i64>, String> {
+    let ride_ids: Vec<String> = rides.iter().map(|r| r.ride_id.clone()).collect();
+
+    let seats_in_line: HashMap<String, i64> = Queue::find()
+        .select_only()
+        .column(queue::Column::RideId)
+        .column_as(queue::Column::PartySize.sum().cast_as(Alias::new("bigint")), "seats")
+        .filter(queue::Column::RideId.is_in(ride_ids.clone()))
+        .group_by(queue::Column::RideId)
+        .into_tuple::<(String, Option<i64>)>()
+        .all(&state.db)
+        .await
+        .map_err(|err| err.to_string())?
+        .into_iter()
+        .map(|(ride_id, seats)| (ride_id, seats.unwrap_or(0)))
+        .collect();
+
+    let mut cycles = WaitEstimator::recent_cycles(&state.db, ride_ids).await?;
+
+    Ok(rides
+        .iter()
+        .map(|ride| {
+            let history = cycles.remove(&ride.ride_id).unwrap_or_default();
+            let estimator = WaitEstimator::from_recent_cycles(ride.capacity, ride.cycle_duration, &history);
+            let seats = seats_in_line.get(&ride.ride_id).copied().unwrap_or(0);
+            (ride.ride_id.clone(), estimator.wait_for_seats_ahead(seats))
+        })
+        .collect())
 }
 
 #[tauri::command]
 pub async fn view_all_rides(
     state: State<'_, AppState>,
+    session_token: String,
 ) -> Result<ApiResponse<Vec<RideResponse>>, String> {
+    if let Err(message) = authorize(&state, &session_token, "view_all_rides").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     let cache_key = "get_all_rides_cache";
 
-    if let Some(cached_rides) = cache_get::<Vec<RideCache>>(&state.redis_pool, cache_key).await {
-        let mut ride_responses = Vec::new();
-
-        for ride_cache in cached_rides {
-            if let Ok(Some(model)) = Ride::find_by_id(ride_cache.ride_id.clone()).one(&state.db).await {
-                ride_responses.push(RideResponse {
-                    ride_id: ride_cache.ride_id,
-                    staff_id: ride_cache.staff_id,
-                    name: ride_cache.name,
-                    price: ride_cache.price,
-                    description: ride_cache.description,
-                    location: ride_cache.location,
-                    status: ride_cache.status,
-                    capacity: ride_cache.capacity,
-                    maintenance_status: ride_cache.maintenance_status,
-                    image: encode(&model.image),
-                });
-            }
+    let rides = match cache_get::<Vec<RideCache>>(&state.redis_pool, cache_key).await {
+        Some(cached_rides) => cached_rides,
+        None => {
+            let cache_data: Vec<RideCache> = Ride::find()
+                .all(&state.db)
+                .await
+                .map_err(|err| err.to_string())?
+                .into_iter()
+                .map(|ride| RideCache {
+                    image: STANDARD.encode(&ride.image),
+                    ride_id: ride.ride_id,
+                    staff_id: ride.staff_id,
+                    name: ride.name,
+                    price: ride.price,
+                    description: ride.description,
+                    location: ride.location,
+                    ride_type: ride.ride_type,
+                    status: ride.status,
+                    capacity: ride.capacity,
+                    maintenance_status: ride.maintenance_status,
+                    cycle_duration: ride.cycle_duration,
+                    min_age: ride.min_age,
+                    min_height_cm: ride.min_height_cm,
+                    max_height_cm: ride.max_height_cm,
+                    wheelchair_accessible: ride.wheelchair_accessible,
+                    health_advisory: ride.health_advisory,
+                })
+                .collect();
+
+            cache_set_tagged(&state.redis_pool, cache_key, &cache_data, 60, &["ride"]).await;
+            cache_data
         }
+    };
+
+    // The wait moves with every join and dispatch, so it is never served from cache.
+    let waits = estimate_ride_waits(&state, &rides).await?;
+
+    let ride_responses = rides
+        .into_iter()
+        .map(|ride_cache| RideResponse {
+            estimated_wait_seconds: waits.get(&ride_cache.ride_id).copied().unwrap_or(0),
+            ride_id: ride_cache.ride_id,
+            staff_id: ride_cache.staff_id,
+            name: ride_cache.name,
+            price: ride_cache.price,
+            description: ride_cache.description,
+            location: ride_cache.location,
+            ride_type: ride_cache.ride_type,
+            status: ride_cache.status,
+            capacity: ride_cache.capacity,
+            maintenance_status: ride_cache.maintenance_status,
+            cycle_duration: ride_cache.cycle_duration,
+            min_age: ride_cache.min_age,
+            min_height_cm: ride_cache.min_height_cm,
+            max_height_cm: ride_cache.max_height_cm,
+            wheelchair_accessible: ride_cache.wheelchair_accessible,
+            health_advisory: ride_cache.health_advisory,
+            image: ride_cache.image,
+        })
+        .collect();
 
-        return Ok(ApiResponse::success(ride_responses));
-    }
-
-    let rides = Ride::find().all(&state.db).await.map_err(|err| err.to_string())?;
-    let mut ride_responses = Vec::new();
-    let mut cache_data = Vec::new();
-
-    for ride in rides {
-        ride_responses.push(RideResponse {
-            ride_id: ride.ride_id.clone(),
-            staff_id: ride.staff_id.clone(),
-            name: ride.name.clone(),
-            price: ride.price,
-            description: ride.description.clone(),
-            location: ride.location.clone(),
-            status: ride.status.clone(),
-            capacity: ride.capacity,
-            maintenance_status: ride.maintenance_status.clone(),
-            image: encode(&ride.image),
-        });
-
-        cache_data.push(RideCache {
-            ride_id: ride.ride_id,
-            staff_id: ride.staff_id,
-            name: ride.name,
-            price: ride.price,
-            description: ride.description,
-            location: ride.location,
-            status: ride.status,
-            capacity: ride.capacity,
-            maintenance_status: ride.maintenance_status,
-        });
-    }
-
-    cache_set(&state.redis_pool, cache_key, &cache_data, 60).await;
     Ok(ApiResponse::success(ride_responses))
 }
 
 #[tauri::command]
 pub async fn view_ride(
     state: State<'_, AppState>,
+    session_token: String,
     ride_id: String
 ) -> Result<ApiResponse<RideResponse>, String> {
+    if let Err(message) = authorize(&state, &session_token, "view_ride").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     let cache_key = format!("ride_{}", ride_id);
 
-    if let Some(cached_ride) = cache_get::<RideResponse>(&state.redis_pool, &cache_key).await {
+    if let Some(mut cached_ride) = cache_get::<RideResponse>(&state.redis_pool, &cache_key).await {
+        // The wait moves with every join and dispatch, so it is never served from cache.
+        if let Ok(Some(ride)) = Ride::find_by_id(ride_id.clone()).one(&state.db).await {
+            cached_ride.estimated_wait_seconds = estimate_ride_wait(&state, &ride).await?;
+        }
         return Ok(ApiResponse::success(cached_ride));
     }
 
     match Ride::find_by_id(ride_id.clone()).one(&state.db).await {
         Ok(Some(ride)) => {
+            let estimated_wait_seconds = estimate_ride_wait(&state, &ride).await?;
             let formatted_ride = RideResponse {
                 ride_id: ride.ride_id,
                 staff_id: ride.staff_id,
@@ -121,13 +396,21 @@ pub async fn view_ride(
                 price: ride.price,
                 description: ride.description,
                 location: ride.location,
+                ride_type: ride.ride_type,
                 status: ride.status,
                 capacity: ride.capacity,
                 maintenance_status: ride.maintenance_status,
-                image: encode(&ride.image)
+                cycle_duration: ride.cycle_duration,
+                min_age: ride.min_age,
+                min_height_cm: ride.min_height_cm,
+                max_height_cm: ride.max_height_cm,
+                wheelchair_accessible: ride.wheelchair_accessible,
+                health_advisory: ride.health_advisory,
+                estimated_wait_seconds,
+                image: STANDARD.encode(&ride.image)
             };
 
-            cache_set(&state.redis_pool, &cache_key, &formatted_ride, 60).await;
+            cache_set_tagged(&state.redis_pool, &cache_key, &formatted_ride, 60, &[&format!("ride:{}", ride_id)]).await;
 
             Ok(ApiResponse::success(formatted_ride))
         }
@@ -144,16 +427,41 @@ pub struct CreateRideRequest {
     pub image: Vec<u8>,
     pub description: String,
     pub location: String,
-    pub status: String,
+    pub ride_type: Option<String>,
+    pub status: RideStatus,
     pub capacity: i32,
-    pub maintenance_status: String,
+    pub maintenance_status: RideMaintenanceStatus,
+    pub cycle_duration: Option<i32>,
+    pub min_age: Option<i32>,
+    pub min_height_cm: Option<i32>,
+    pub max_height_cm: Option<i32>,
+    pub wheelchair_accessible: Option<bool>,
+    pub health_advisory: Option<String>,
 }
 
 #[tauri::command]
 pub async fn create_ride(
     state: State<'_, AppState>,
+    session_token: String,
     payload: CreateRideRequest,
 ) -> Result<ApiResponse<ride::Model>, String> {
+    let session = match authorize(&state, &session_token, "create_ride").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    if payload.cycle_duration.is_some_and(|seconds| seconds <= 0) {
+        return Ok(ApiResponse::error("Cycle duration must be a positive number of seconds".to_string()));
+    }
+
+    if payload.capacity <= 0 {
+        return Ok(ApiResponse::error("Capacity must be at least 1".to_string()));
+    }
+
+    if let Err(message) = validate_eligibility(payload.min_age, payload.min_height_cm, payload.max_height_cm) {
+        return Ok(ApiResponse::error(message));
+    }
+
     let new_ride = RideActiveModel {
         ride_id: Set(payload.ride_id),
         name: Set(payload.name),
@@ -161,19 +469,48 @@ pub async fn create_ride(
         image: Set(payload.image),
         description: Set(payload.description),
         location: Set(payload.location),
+        ride_type: Set(payload.ride_type),
         status: Set(payload.status),
         capacity: Set(payload.capacity),
         maintenance_status: Set(payload.maintenance_status),
+        cycle_duration: Set(payload.cycle_duration.unwrap_or(DEFAULT_CYCLE_DURATION_SECONDS)),
+        min_age: Set(payload.min_age),
+        min_height_cm: Set(payload.min_height_cm),
+        max_height_cm: Set(payload.max_height_cm),
+        wheelchair_accessible: Set(payload.wheelchair_accessible.unwrap_or(true)),
+        health_advisory: Set(payload.health_advisory),
         ..Default::default()
     };
 
-    match new_ride.insert(&state.db).await {
-        Ok(ride) => {
-            cache_delete(&state.redis_pool, "get_all_rides_cache").await;
-            Ok(ApiResponse::success(ride))
-        }
-        Err(err) => Ok(ApiResponse::error(format!("Failed to create ride: {}", err))),
+    let txn = match state.db.begin().await {
+        Ok(txn) => txn,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    let ride = match new_ride.insert(&txn).await {
+        Ok(ride) => ride,
+        Err(err) => return Ok(ApiResponse::error(format!("Failed to create ride: {}", err))),
+    };
+
+    if let Err(message) = record_ride_status_change(
+        &txn,
+        &ride.ride_id,
+        None,
+        (ride.status, ride.maintenance_status),
+        &session.user_id,
+        None,
+    )
+    .await
+    {
+        return Ok(ApiResponse::error(message));
     }
+
+    if let Err(err) = txn.commit().await {
+        return Ok(ApiResponse::error(format!("Failed to create ride: {}", err)));
+    }
+
+    cache_invalidate(&state.redis_pool, &["ride"]).await;
+    Ok(ApiResponse::success(ride))
 }
 
 #[derive(Deserialize)]
@@ -184,44 +521,206 @@ pub struct UpdateRideRequest {
     pub image: Vec<u8>,
     pub description: String,
     pub location: String,
-    pub status: String,
+    pub ride_type: Option<String>,
+    pub status: RideStatus,
     pub capacity: i32,
-    pub maintenance_status: String,
+    pub maintenance_status: RideMaintenanceStatus,
+    pub cycle_duration: Option<i32>,
+    pub min_age: Option<i32>,
+    pub min_height_cm: Option<i32>,
+    pub max_height_cm: Option<i32>,
+    pub wheelchair_accessible: Option<bool>,
+    pub health_advisory: Option<String>,
+    pub reason: Option<String>,
 }
 
 #[tauri::command]
 pub async fn update_ride(
     state: State<'_, AppState>,
+    session_token: String,
     payload: UpdateRideRequest,
 ) -> Result<ApiResponse<ride::Model>, String> {
-    match Ride::find_by_id(payload.ride_id.clone()).one(&state.db).await {
-        Ok(Some(existing_ride)) => {
-            let mut active_ride: RideActiveModel = existing_ride.into();
-            active_ride.name = Set(payload.name);
-            active_ride.price = Set(payload.price);
-            active_ride.image = Set(payload.image);
-            active_ride.description = Set(payload.description);
-            active_ride.location = Set(payload.location);
-            active_ride.status = Set(payload.status);
-            active_ride.capacity = Set(payload.capacity);
-            active_ride.maintenance_status = Set(payload.maintenance_status);
-
-            match active_ride.update(&state.db).await {
-                Ok(updated_ride) => {
-                    cache_delete(&state.redis_pool, "get_all_rides_cache").await;
-                    Ok(ApiResponse::success(updated_ride))
-                }
-                Err(err) => Ok(ApiResponse::error(format!("Failed to update ride: {}", err))),
+    let session = match authorize(&state, &session_token, "update_ride").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    if payload.cycle_duration.is_some_and(|seconds| seconds <= 0) {
+        return Ok(ApiResponse::error("Cycle duration must be a positive number of seconds".to_string()));
+    }
+
+    if payload.capacity <= 0 {
+        return Ok(ApiResponse::error("Capacity must be at least 1".to_string()));
+    }
+
+    if let Err(message) = validate_eligibility(payload.min_age, payload.min_height_cm, payload.max_height_cm) {
+        return Ok(ApiResponse::error(message));
+    }
+
+    let txn = match state.db.begin().await {
+        Ok(txn) => txn,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    // Locked so status changes can't interleave with queue joins and dispatches on this ride.
+    let existing_ride = match Ride::find_by_id(payload.ride_id.clone()).lock_exclusive().one(&txn).await {
+        Ok(Some(existing_ride)) => existing_ride,
+        Ok(None) => {
+            return Ok(ApiResponse::error(format!(
+                "No ride found with ID: {}",
+                payload.ride_id
+            )))
+        }
+        Err(err) => {
+            return Ok(ApiResponse::error(format!(
+                "Database error while updating ride: {}",
+                err
+            )))
+        }
+    };
+
+    // Parties board whole, so one that no longer fits in a cycle would hold up the line for good.
+    if payload.capacity < existing_ride.capacity {
+        match Queue::find()
+            .filter(queue::Column::RideId.eq(&existing_ride.ride_id))
+            .order_by_desc(queue::Column::PartySize)
+            .one(&txn)
+            .await
+        {
+            Ok(Some(largest)) if largest.party_size > payload.capacity => {
+                return Ok(ApiResponse::error(format!(
+                    "A party of {} is waiting for {}, so capacity can't go below {}",
+                    largest.party_size, existing_ride.name, largest.party_size
+                )))
             }
+            Ok(_) => {}
+            Err(err) => return Ok(ApiResponse::error(format!("Database error while updating ride: {}", err))),
         }
-        Ok(None) => Ok(ApiResponse::error(format!(
-            "No ride found with ID: {}",
-            payload.ride_id
-        ))),
-        Err(err) => Ok(ApiResponse::error(format!(
-            "Database error while updating ride: {}",
-            err
-        ))),
+    }
+
+    let (existing_ride, affected) = match change_ride_state(
+        &txn,
+        &state.queue_policy,
+        &session,
+        existing_ride,
+        payload.status,
+        payload.maintenance_status,
+        payload.reason,
+    )
+    .await
+    {
+        Ok(changed) => changed,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    let mut active_ride: RideActiveModel = existing_ride.into();
+    active_ride.name = Set(payload.name);
+    active_ride.price = Set(payload.price);
+    active_ride.image = Set(payload.image);
+    active_ride.description = Set(payload.description);
+    active_ride.location = Set(payload.location);
+    active_ride.ride_type = Set(payload.ride_type);
+    active_ride.capacity = Set(payload.capacity);
+    if let Some(cycle_duration) = payload.cycle_duration {
+        active_ride.cycle_duration = Set(cycle_duration);
+    }
+    active_ride.min_age = Set(payload.min_age);
+    active_ride.min_height_cm = Set(payload.min_height_cm);
+    active_ride.max_height_cm = Set(payload.max_height_cm);
+    if let Some(wheelchair_accessible) = payload.wheelchair_accessible {
+        active_ride.wheelchair_accessible = Set(wheelchair_accessible);
+    }
+    active_ride.health_advisory = Set(payload.health_advisory);
+
+    let updated_ride = match active_ride.update(&txn).await {
+        Ok(updated_ride) => updated_ride,
+        Err(err) => return Ok(ApiResponse::error(format!("Failed to update ride: {}", err))),
+    };
+
+    if let Err(err) = txn.commit().await {
+        return Ok(ApiResponse::error(format!("Failed to update ride: {}", err)));
+    }
+
+    invalidate_ride_state(&state, &updated_ride.ride_id, &affected).await;
+    Ok(ApiResponse::success(updated_ride))
+}
+
+#[derive(Deserialize)]
+pub struct ChangeRideStatusRequest {
+    pub ride_id: String,
+    pub status: RideStatus,
+    pub maintenance_status: Option<RideMaintenanceStatus>,
+    pub reason: Option<String>,
+}
+
+#[tauri::command]
+pub async fn change_ride_status(
+    state: State<'_, AppState>,
+    session_token: String,
+    payload: ChangeRideStatusRequest,
+) -> Result<ApiResponse<ride::Model>, String> {
+    let session = match authorize(&state, &session_token, "change_ride_status").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    let txn = match state.db.begin().await {
+        Ok(txn) => txn,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    let existing_ride = match Ride::find_by_id(payload.ride_id.clone()).lock_exclusive().one(&txn).await {
+        Ok(Some(existing_ride)) => existing_ride,
+        Ok(None) => return Ok(ApiResponse::error(format!("No ride found with ID: {}", payload.ride_id))),
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    if !can_operate_ride(&session, &existing_ride) {
+        return Ok(ApiResponse::error("Ride staff can only change the status of their assigned ride".to_string()));
+    }
+
+    let maintenance_status = payload.maintenance_status.unwrap_or(existing_ride.maintenance_status);
+    let (updated_ride, affected) = match change_ride_state(
+        &txn,
+        &state.queue_policy,
+        &session,
+        existing_ride,
+        payload.status,
+        maintenance_status,
+        payload.reason,
+    )
+    .await
+    {
+        Ok(changed) => changed,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    if let Err(err) = txn.commit().await {
+        return Ok(ApiResponse::error(format!("Failed to update ride: {}", err)));
+    }
+
+    invalidate_ride_state(&state, &updated_ride.ride_id, &affected).await;
+    Ok(ApiResponse::success(updated_ride))
+}
+
+#[tauri::command]
+pub async fn view_ride_status_history(
+    state: State<'_, AppState>,
+    session_token: String,
+    ride_id: String,
+) -> Result<ApiResponse<Vec<ride_status_history::Model>>, String> {
+    if let Err(message) = authorize(&state, &session_token, "view_ride_status_history").await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    match RideStatusHistory::find()
+        .filter(ride_status_history::Column::RideId.eq(&ride_id))
+        .order_by_asc(ride_status_history::Column::ChangedAt)
+        .all(&state.db)
+        .await
+    {
+        Ok(history) => Ok(ApiResponse::success(history)),
+        Err(err) => Ok(ApiResponse::error(format!("Database error: {}", err))),
     }
 }
 
@@ -233,8 +732,13 @@ pub struct DeleteRideRequest {
 #[tauri::command]
 pub async fn delete_ride(
     state: State<'_, AppState>,
+    session_token: String,
     payload: DeleteRideRequest,
 ) -> Result<ApiResponse<()>, String> {
+    if let Err(message) = authorize(&state, &session_token, "delete_ride").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     match Ride::delete_by_id(payload.ride_id.clone()).exec(&state.db).await {
         Ok(delete_result) => {
             if delete_result.rows_affected == 0 {
@@ -243,7 +747,7 @@ pub async fn delete_ride(
                     payload.ride_id
                 )));
             }
-            cache_delete(&state.redis_pool, "get_all_rides_cache").await;
+            cache_invalidate(&state.redis_pool, &["ride", &format!("ride:{}", payload.ride_id)]).await;
             Ok(ApiResponse::success(()))
         }
         Err(err) => Ok(ApiResponse::error(format!("Failed to delete ride: {}", err))),
@@ -1,10 +1,15 @@
 use entity::{ride, user};
-use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, ActiveValue::Set, ActiveModelTrait};
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, ActiveValue::Set, ActiveModelTrait, TransactionTrait};
 use serde::Deserialize;
 use tauri::State;
-use crate::{AppState, ApiResponse, cache_delete};
+use crate::{AppState, ApiResponse, cache_invalidate};
+use crate::controllers::session_handler::authorize;
 use chrono::Utc;
 use entity::maintenance::{self, ActiveModel as MaintenanceActiveModel};
+use entity::sea_orm_active_enums::MaintenanceStatus;
+use crate::controllers::maintenance_handler::{record_ticket_change, sync_ride_maintenance};
+use crate::controllers::maintenance_sla_handler::format_reported_at;
+use crate::controllers::ride_handler::invalidate_ride_state;
 
 #[derive(Deserialize)]
 pub struct AssignRideStaffRequest {
@@ -15,8 +20,13 @@ pub struct AssignRideStaffRequest {
 #[tauri::command]
 pub async fn assign_ride_staff(
     state: State<'_, AppState>,
+    session_token: String,
     payload: AssignRideStaffRequest,
 ) -> Result<ApiResponse<ride::Model>, String> {
+    if let Err(message) = authorize(&state, &session_token, "assign_ride_staff").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     // Validasi staff
     let staff = user::Entity::find()
         .filter(user::Column::UserId.eq(&payload.staff_id))
@@ -43,7 +53,7 @@ pub async fn assign_ride_staff(
             staff_active.update(&state.db).await.map_err(|e| e.to_string())?;
 
             // Clear ride cache
-            cache_delete(&state.redis_pool, "get_all_rides_cache").await;
+            cache_invalidate(&state.redis_pool, &["ride", &format!("ride:{}", payload.ride_id)]).await;
 
             Ok(ApiResponse::success(updated_ride))
         } else {
@@ -66,9 +76,20 @@ pub struct CreateMaintenanceRequest {
 #[tauri::command]
 pub async fn create_maintenance_request(
     state: State<'_, AppState>,
+    session_token: String,
     payload: CreateMaintenanceRequest,
 ) -> Result<ApiResponse<maintenance::Model>, String> {
-    let now_date = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    let session = match authorize(&state, &session_token, "create_maintenance_request").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    if payload.sender_id != session.user_id {
+        return Ok(ApiResponse::error("Sender does not match the signed-in user".to_string()));
+    }
+
+    let now = Utc::now();
+    let now_date = now.format("%Y-%m-%d %H:%M:%S").to_string();
 
     let new_request = MaintenanceActiveModel {
         maintenance_id: Set(payload.maintenance_id),
@@ -76,17 +97,48 @@ pub async fn create_maintenance_request(
         r#type: Set(payload.r#type),
         issue: Set(payload.issue),
         date: Set(now_date),
-        status: Set("Pending".to_string()),
+        reported_at: Set(Some(format_reported_at(now))),
+        status: Set(MaintenanceStatus::Pending),
         maintenance_staff_id: Set(None),
         sender_id: Set(payload.sender_id),
         ..Default::default()
     };
 
-    match new_request.insert(&state.db).await {
-        Ok(data) => {
-            cache_delete(&state.redis_pool, "get_all_maintenance_cache").await;
-            Ok(ApiResponse::success(data))
-        }
-        Err(err) => Err(format!("Failed to create maintenance request: {}", err)),
+    let txn = match state.db.begin().await {
+        Ok(txn) => txn,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    let data = match new_request.insert(&txn).await {
+        Ok(data) => data,
+        Err(err) => return Err(format!("Failed to create maintenance request: {}", err)),
+    };
+
+    if let Err(message) = record_ticket_change(
+        &txn,
+        &data.maintenance_id,
+        None,
+        MaintenanceStatus::Pending,
+        &session.user_id,
+        Some(data.issue.clone()),
+    )
+    .await
+    {
+        return Ok(ApiResponse::error(message));
+    }
+
+    // An open ticket takes the ride out of service until the work is done.
+    let reason = format!("Maintenance ticket {} raised", data.maintenance_id);
+    let affected = match sync_ride_maintenance(&txn, &state.queue_policy, &session.user_id, &data.ride_id, reason).await {
+        Ok(affected) => affected,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    if let Err(err) = txn.commit().await {
+        return Err(format!("Failed to create maintenance request: {}", err));
     }
+
+    cache_invalidate(&state.redis_pool, &["maintenance"]).await;
+    invalidate_ride_state(&state, &data.ride_id, &affected).await;
+    Ok(ApiResponse::success(data))
 }
\ No newline at end of file
@@ -0,0 +1,259 @@
+use serde::{Deserialize, Serialize};
+use tauri::State;
+use uuid::Uuid;
+use crate::{
+    ApiResponse, cache_get, cache_set, cache_delete, cache_replace, cache_set_add, cache_set_members, cache_set_remove,
+    AppState,
+};
+use entity::user;
+
+const SESSION_TTL_SECONDS: usize = 8 * 60 * 60;
+
+const ANY_ROLE: &[&str] = &["*"];
+const EXECUTIVES: &[&str] = &["CEO", "COO", "CFO"];
+const ALL_STAFF: &[&str] = &[
+    "CEO", "COO", "CFO", "Customer Service", "Lost And Found Staff", "Ride Manager", "Ride Staff",
+    "F&B Supervisor", "Chef", "Waiter", "Retail Manager", "Sales Associate", "Maintenance Manager",
+    "Maintenance Staff",
+];
+
+// Roles allowed to call each command. Commands missing from this table are denied.
+const PERMISSIONS: &[(&str, &[&str])] = &[
+    ("logout", ANY_ROLE),
+    ("change_password", ANY_ROLE),
+    ("reset_staff_password", EXECUTIVES),
+    // Restaurants & menus
+    ("view_all_restaurants", ANY_ROLE),
+    ("create_restaurant", &["F&B Supervisor", "CEO", "COO"]),
+    ("update_restaurant", &["F&B Supervisor", "CEO", "COO"]),
+    ("delete_restaurant", &["F&B Supervisor", "CEO", "COO"]),
+    ("view_all_menus", ANY_ROLE),
+    ("view_menu", ANY_ROLE),
+    ("create_menu", &["F&B Supervisor"]),
+    ("update_menu", &["F&B Supervisor"]),
+    ("update_menu_quantity", &["F&B Supervisor", "Chef"]),
+    ("delete_menu", &["F&B Supervisor"]),
+    ("assign_restaurant_staff", &["F&B Supervisor"]),
+    // Rides & queues
+    ("view_all_rides", ANY_ROLE),
+    ("view_ride", ANY_ROLE),
+    ("create_ride", &["Ride Manager", "CEO", "COO"]),
+    ("update_ride", &["Ride Manager", "CEO", "COO"]),
+    ("change_ride_status", &["Ride Manager", "Ride Staff", "Maintenance Manager", "CEO", "COO"]),
+    ("view_ride_status_history", &["Ride Manager", "Ride Staff", "Maintenance Manager", "CEO", "COO"]),
+    ("delete_ride", &["Ride Manager", "CEO", "COO"]),
+    ("assign_ride_staff", &["Ride Manager"]),
+    ("view_all_queues", &["Ride Staff", "Ride Manager", "CEO", "COO"]),
+    ("get_queues_by_ride", ANY_ROLE),
+    ("create_queue", &["Customer", "Ride Staff", "Ride Manager"]),
+    ("edit_queue", &["Ride Staff", "Ride Manager"]),
+    ("delete_queue", &["Customer", "Ride Staff", "Ride Manager"]),
+    ("dispatch_ride_cycle", &["Ride Staff", "Ride Manager"]),
+    ("view_fast_pass_slots", ANY_ROLE),
+    ("view_fast_passes", &["Customer", "Ride Staff", "Ride Manager"]),
+    ("book_fast_pass", &["Customer"]),
+    ("cancel_fast_pass", &["Customer", "Ride Manager"]),
+    ("redeem_fast_pass", &["Ride Staff", "Ride Manager"]),
+    // Users & notifications
+    ("get_all_users", &["CEO", "COO", "CFO", "Customer Service", "F&B Supervisor", "Ride Manager"]),
+    ("get_all_users_lite", ALL_STAFF),
+    ("get_all_ride_staff", &["Ride Manager"]),
+    ("get_user_by_id", ANY_ROLE),
+    ("get_user_lite_by_id", ANY_ROLE),
+    ("create_customer", &["Customer Service"]),
+    ("update_rider_profile", &["Customer Service", "Ride Staff", "Ride Manager"]),
+    ("get_balance", ANY_ROLE),
+    ("top_up_balance", &["Customer", "Customer Service"]),
+    ("view_wallet_transactions", &["Customer", "Customer Service", "CFO"]),
+    ("reconcile_wallets", &["CFO"]),
+    ("get_notifications", ANY_ROLE),
+    ("view_notification", ANY_ROLE),
+    ("mark_all_notifications_read", ANY_ROLE),
+    ("send_notification", ALL_STAFF),
+    // Stores & souvenirs
+    ("view_all_stores", ANY_ROLE),
+    ("create_store", &["Retail Manager", "CEO", "COO"]),
+    ("update_store", &["Retail Manager", "CEO", "COO"]),
+    ("delete_store", &["Retail Manager", "CEO", "COO"]),
+    ("view_all_souvenirs", ANY_ROLE),
+    ("view_souvenir", ANY_ROLE),
+    // Orders
+    ("view_all_orders", &[
+        "Chef", "Waiter", "F&B Supervisor", "Retail Manager", "Sales Associate", "CEO", "COO", "CFO",
+    ]),
+    ("view_orders", ANY_ROLE),
+    ("create_order", &["Customer"]),
+    ("update_order", &["Customer"]),
+    ("delete_order", &["Customer", "F&B Supervisor", "Retail Manager"]),
+    ("checkout_order", &["Customer"]),
+    ("refund_store_order", &["Retail Manager", "Sales Associate"]),
+    ("update_order_status", &["Chef", "Waiter", "Customer"]),
+    ("view_kitchen_queue", &["Chef", "Waiter"]),
+    ("bump_kitchen_ticket", &["Chef", "Waiter"]),
+    ("recall_kitchen_ticket", &["Chef", "Waiter"]),
+    ("view_order_status_history", &["Customer", "Chef", "Waiter", "F&B Supervisor", "CEO", "COO"]),
+    // Lost and found
+    ("view_lost_and_found_items", ANY_ROLE),
+    ("create_lost_item", &["Lost And Found Staff", "Customer Service"]),
+    ("update_lost_item", &["Lost And Found Staff", "Customer Service"]),
+    ("delete_lost_item", &["Lost And Found Staff", "Customer Service"]),
+    // Chats & broadcasts
+    ("send_group_message", ALL_STAFF),
+    ("fetch_group_info", ALL_STAFF),
+    ("get_all_groups", ALL_STAFF),
+    ("listen_to_group_chat", ALL_STAFF),
+    ("fetch_group_chat_messages", ALL_STAFF),
+    ("listen_to_official_chat", &["Customer", "Customer Service"]),
+    ("send_official_chat_message", &["Customer", "Customer Service"]),
+    ("fetch_official_chat_messages", &["Customer", "Customer Service"]),
+    ("fetch_official_chat_customers", &["Customer Service"]),
+    ("fetch_maintenance_chat_ride_managers", &["Maintenance Manager", "Maintenance Staff"]),
+    ("listen_to_maintenance_chat", &["Ride Manager", "Maintenance Manager", "Maintenance Staff"]),
+    ("fetch_maintenance_chat_messages", &["Ride Manager", "Maintenance Manager", "Maintenance Staff"]),
+    ("send_maintenance_chat_message", &["Ride Manager", "Maintenance Manager", "Maintenance Staff"]),
+    ("send_broadcast_message", &["Customer Service", "CEO", "COO", "CFO"]),
+    // Proposals & maintenance
+    ("create_proposal", &["Ride Manager", "F&B Supervisor", "Retail Manager", "Maintenance Manager"]),
+    ("view_all_proposal", &[
+        "Ride Manager", "F&B Supervisor", "Retail Manager", "Maintenance Manager", "CEO", "COO", "CFO",
+    ]),
+    ("review_proposal", &["CEO", "COO", "CFO"]),
+    ("create_maintenance_request", &["Ride Manager"]),
+    ("view_all_maintenance", &["Ride Manager", "Maintenance Manager", "Maintenance Staff", "CEO", "COO"]),
+    ("view_maintenance_staff_workload", &["Maintenance Manager"]),
+    ("assign_maintenance_staff", &["Maintenance Manager"]),
+    ("start_maintenance", &["Maintenance Staff"]),
+    ("pause_maintenance", &["Maintenance Staff"]),
+    ("complete_maintenance", &["Maintenance Staff"]),
+    ("view_maintenance_notes", &["Ride Manager", "Maintenance Manager", "Maintenance Staff", "CEO", "COO"]),
+    ("view_maintenance_plans", &["Ride Manager", "Maintenance Manager", "Maintenance Staff", "CEO", "COO"]),
+    ("create_maintenance_plan", &["Ride Manager", "Maintenance Manager"]),
+    ("update_maintenance_plan", &["Ride Manager", "Maintenance Manager"]),
+    ("delete_maintenance_plan", &["Ride Manager", "Maintenance Manager"]),
+    ("view_maintenance_calendar", &["Ride Manager", "Maintenance Manager", "Maintenance Staff", "CEO", "COO"]),
+    ("view_checklist_templates", &["Ride Manager", "Maintenance Manager", "Maintenance Staff"]),
+    ("create_checklist_template", &["Maintenance Manager"]),
+    ("set_checklist_template_active", &["Maintenance Manager"]),
+    ("view_maintenance_checklist", &["Ride Manager", "Maintenance Manager", "Maintenance Staff", "CEO", "COO"]),
+    ("record_checklist_result", &["Maintenance Staff"]),
+    ("view_checklist_progress", &["Ride Manager", "Maintenance Manager", "Maintenance Staff", "CEO", "COO"]),
+    ("view_spare_parts", &["Maintenance Manager", "Maintenance Staff", "CEO", "COO", "CFO"]),
+    ("create_spare_part", &["Maintenance Manager"]),
+    ("update_spare_part", &["Maintenance Manager"]),
+    ("restock_spare_part", &["Maintenance Manager"]),
+    ("record_part_usage", &["Maintenance Staff"]),
+    ("view_maintenance_parts", &["Ride Manager", "Maintenance Manager", "Maintenance Staff", "CEO", "COO", "CFO"]),
+    ("view_sla_policies", &["Ride Manager", "Maintenance Manager", "Maintenance Staff", "CEO", "COO"]),
+    ("save_sla_policy", &["Maintenance Manager", "COO"]),
+    ("delete_sla_policy", &["Maintenance Manager", "COO"]),
+    ("view_ride_availability_report", &["Ride Manager", "Maintenance Manager", "CEO", "COO", "CFO"]),
+    ("export_ride_availability_report", &["CEO", "COO", "CFO"]),
+];
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Session {
+    pub user_id: String,
+    pub role: String,
+    pub restaurant_id: Option<String>,
+    pub must_change_password: bool,
+}
+
+impl Session {
+    pub fn is_customer(&self) -> bool {
+        self.role == "Customer"
+    }
+
+    // Staff may act on behalf of any customer; customers only on themselves.
+    pub fn can_act_for(&self, user_id: &str) -> bool {
+        !self.is_customer() || self.user_id == user_id
+    }
+}
+
+fn session_key(session_token: &str) -> String {
+    format!("session_{}", session_token)
+}
+
+// Every live token of a user, so role, restaurant and password changes reach all their sessions.
+fn user_sessions_key(user_id: &str) -> String {
+    format!("user_sessions_{}", user_id)
+}
+
+fn is_allowed(role: &str, command: &str) -> bool {
+    PERMISSIONS
+        .iter()
+        .find(|(name, _)| *name == command)
+        .is_some_and(|(_, roles)| roles.iter().any(|r| *r == "*" || *r == role))
+}
+
+fn session_for(user: &user::Model) -> Session {
+    Session {
+        user_id: user.user_id.clone(),
+        role: user.role.clone(),
+        restaurant_id: user.restaurant_id.clone(),
+        must_change_password: user.must_change_password,
+    }
+}
+
+pub async fn create_session(state: &AppState, user: &user::Model) -> String {
+    let session_token = Uuid::new_v4().to_string();
+    cache_set(&state.redis_pool, &session_key(&session_token), &session_for(user), SESSION_TTL_SECONDS).await;
+    cache_set_add(&state.redis_pool, &user_sessions_key(&user.user_id), &session_token, SESSION_TTL_SECONDS).await;
+    session_token
+}
+
+pub async fn authorize(state: &AppState, session_token: &str, command: &str) -> Result<Session, String> {
+    let session = cache_get::<Session>(&state.redis_pool, &session_key(session_token))
+        .await
+        .ok_or_else(|| "Session is invalid or has expired, please log in again".to_string())?;
+
+    if session.must_change_password && command != "change_password" && command != "logout" {
+        return Err("Password change required before continuing".to_string());
+    }
+
+    if !is_allowed(&session.role, command) {
+        return Err(format!("Role {} is not allowed to perform {}", session.role, command));
+    }
+
+    Ok(session)
+}
+
+// Brings every live session of the user in line with the user row after a role or restaurant
+// change. Sessions keep their original expiry; tokens that have already expired are forgotten.
+pub async fn refresh_user_sessions(state: &AppState, user: &user::Model) {
+    let index_key = user_sessions_key(&user.user_id);
+    let session = session_for(user);
+
+    for session_token in cache_set_members(&state.redis_pool, &index_key).await {
+        if !cache_replace(&state.redis_pool, &session_key(&session_token), &session).await {
+            cache_set_remove(&state.redis_pool, &index_key, &session_token).await;
+        }
+    }
+}
+
+// Signs the user out everywhere except, optionally, the session that made the change.
+pub async fn revoke_user_sessions(state: &AppState, user_id: &str, keep_token: Option<&str>) {
+    let index_key = user_sessions_key(user_id);
+
+    for session_token in cache_set_members(&state.redis_pool, &index_key).await {
+        if Some(session_token.as_str()) == keep_token {
+            continue;
+        }
+        cache_delete(&state.redis_pool, &session_key(&session_token)).await;
+        cache_set_remove(&state.redis_pool, &index_key, &session_token).await;
+    }
+}
+
+#[tauri::command]
+pub async fn logout(
+    state: State<'_, AppState>,
+    session_token: String,
+) -> Result<ApiResponse<()>, String> {
+    let session = match authorize(&state, &session_token, "logout").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    cache_delete(&state.redis_pool, &session_key(&session_token)).await;
+    cache_set_remove(&state.redis_pool, &user_sessions_key(&session.user_id), &session_token).await;
+    Ok(ApiResponse::success(()))
+}
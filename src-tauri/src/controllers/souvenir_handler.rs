@@ -1,10 +1,11 @@
 use anyhow::Result;
-use base64::encode;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
 use entity::souvenir::{self, Entity as Souvenir};
 use sea_orm::{EntityTrait, QueryOrder};
 use serde::{Deserialize, Serialize};
 use tauri::State;
-use crate::{ApiResponse, cache_get, cache_set, AppState};
+use crate::{ApiResponse, cache_get, cache_set_tagged, AppState};
+use crate::controllers::session_handler::authorize;
 
 #[derive(Serialize, Deserialize)]    
 pub struct SouvenirResponse {
@@ -30,7 +31,12 @@ struct SouvenirCache {
 #[tauri::command]
 pub async fn view_all_souvenirs(
     state: State<'_, AppState>,
+    session_token: String,
 ) -> Result<ApiResponse<Vec<SouvenirResponse>>, String> {
+    if let Err(message) = authorize(&state, &session_token, "view_all_souvenirs").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     let cache_key = "get_all_souvenirs_cache";
 
     if let Some(cached_souvenirs) = cache_get::<Vec<SouvenirCache>>(&state.redis_pool, cache_key).await {
@@ -45,7 +51,7 @@ pub async fn view_all_souvenirs(
                     description: cache.description,
                     price: cache.price,
                     stock: cache.stock,
-                    image: encode(&model.image),
+                    image: STANDARD.encode(&model.image),
                 });
             }
         }
@@ -68,7 +74,7 @@ pub async fn view_all_souvenirs(
                     description: s.description.clone(),
                     price: s.price,
                     stock: s.stock,
-                    image: encode(&s.image),
+                    image: STANDARD.encode(&s.image),
                 })
                 .collect();
 
@@ -84,7 +90,7 @@ pub async fn view_all_souvenirs(
                 })
                 .collect();
 
-            cache_set(&state.redis_pool, cache_key, &cache_data, 60).await;
+            cache_set_tagged(&state.redis_pool, cache_key, &cache_data, 60, &["souvenir"]).await;
             Ok(ApiResponse::success(full_responses))
         }
         Err(err) => Ok(ApiResponse::error(format!("Database error: {}", err))),
@@ -94,8 +100,13 @@ pub async fn view_all_souvenirs(
 #[tauri::command]
 pub async fn view_souvenir(
     state: State<'_, AppState>,
+    session_token: String,
     souvenir_id: String
 ) -> Result<ApiResponse<SouvenirResponse>, String> {
+    if let Err(message) = authorize(&state, &session_token, "view_souvenir").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     let cache_key = format!("souvenir_{}", souvenir_id);
 
     if let Some(cached_souvenir) = cache_get::<SouvenirResponse>(&state.redis_pool, &cache_key).await {
@@ -111,10 +122,10 @@ pub async fn view_souvenir(
                 description: souvenir.description,
                 price: souvenir.price,
                 stock: souvenir.stock,
-                image: encode(&souvenir.image),
+                image: STANDARD.encode(&souvenir.image),
             };
 
-            cache_set(&state.redis_pool, &cache_key, &formatted_souvenir, 60).await;
+            cache_set_tagged(&state.redis_pool, &cache_key, &formatted_souvenir, 60, &[&format!("souvenir:{}", souvenir_id)]).await;
 
             Ok(ApiResponse::success(formatted_souvenir))
         }
@@ -0,0 +1,483 @@
+use chrono::Utc;
+use entity::maintenance::Entity as Maintenance;
+use entity::maintenance_part_usage::{self, Entity as MaintenancePartUsage};
+use entity::proposal::{self, Entity as Proposal};
+use entity::sea_orm_active_enums::MaintenanceStatus;
+use entity::spare_part::{self, Entity as SparePart};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+    TransactionTrait, ActiveValue::Set,
+};
+use serde::{Deserialize, Serialize};
+use tauri::State;
+use uuid::Uuid;
+use crate::{ApiResponse, AppState, cache_invalidate};
+use crate::controllers::maintenance_plan_handler::notify_maintenance_managers;
+use crate::controllers::session_handler::authorize;
+
+fn is_low_stock(part: &spare_part::Model) -> bool {
+    part.stock_quantity <= part.reorder_threshold
+}
+
+// Raises a purchase proposal when the part has fallen to its reorder threshold, unless one is
+// still waiting for approval. Returns the managers who were notified.
+async fn raise_reorder_if_low<C: ConnectionTrait>(
+    db: &C,
+    part: spare_part::Model,
+    sender_id: &str,
+) -> Result<Vec<String>, String> {
+    if !part.is_active || !is_low_stock(&part) {
+        return Ok(Vec::new());
+    }
+
+    if let Some(proposal_id) = &part.reorder_proposal_id {
+        let pending = Proposal::find_by_id(proposal_id.clone())
+            .one(db)
+            .await
+            .map_err(|e| format!("Database error: {}", e))?
+            .is_some_and(|proposal| proposal.status == "Pending");
+        if pending {
+            return Ok(Vec::new());
+        }
+    }
+
+    let proposal = proposal::ActiveModel {
+        proposal_id: Set(format!("PP-{}", Uuid::new_v4())),
+        r#type: Set("Purchase".to_string()),
+        title: Set(format!("Restock {} ({})", part.name, part.part_number)),
+        description: Set(format!(
+            "{} is down to {} {} against a reorder threshold of {}. Order {} {} at {:.2} each.",
+            part.name,
+            part.stock_quantity,
+            part.unit,
+            part.reorder_threshold,
+            part.reorder_quantity,
+            part.unit,
+            part.unit_cost
+        )),
+        cost: Set(f64::from(part.reorder_quantity) * part.unit_cost),
+        image: Set(Vec::new()),
+        feedback: Set(None),
+        status: Set("Pending".to_string()),
+        date: Set(Utc::now().format("%B %d, %Y").to_string()),
+        sender_id: Set(sender_id.to_string()),
+    }
+    .insert(db)
+    .await
+    .map_err(|e| format!("Failed to raise purchase proposal: {}", e))?;
+
+    let message = format!(
+        "{} is low on stock ({} {} left); purchase proposal {} is waiting for approval",
+        part.name, part.stock_quantity, part.unit, proposal.proposal_id
+    );
+
+    let mut active_part: spare_part::ActiveModel = part.into();
+    active_part.reorder_proposal_id = Set(Some(proposal.proposal_id));
+    active_part
+        .update(db)
+        .await
+        .map_err(|e| format!("Failed to update spare part: {}", e))?;
+
+    notify_maintenance_managers(db, "Spare part low on stock", &message).await
+}
+
+async fn invalidate_reorder(state: &AppState, notified: &[String]) {
+    if notified.is_empty() {
+        return;
+    }
+
+    let tags: Vec<String> = notified.iter().map(|user_id| format!("notification:{}", user_id)).collect();
+    let mut tag_refs: Vec<&str> = tags.iter().map(String::as_str).collect();
+    tag_refs.push("proposal");
+    cache_invalidate(&state.redis_pool, &tag_refs).await;
+}
+
+#[derive(Serialize)]
+pub struct SparePartResponse {
+    #[serde(flatten)]
+    pub part: spare_part::Model,
+    pub low_stock: bool,
+}
+
+#[tauri::command]
+pub async fn view_spare_parts(
+    state: State<'_, AppState>,
+    session_token: String,
+) -> Result<ApiResponse<Vec<SparePartResponse>>, String> {
+    if let Err(message) = authorize(&state, &session_token, "view_spare_parts").await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    match SparePart::find().order_by_asc(spare_part::Column::Name).all(&state.db).await {
+        Ok(parts) => Ok(ApiResponse::success(
+            parts
+                .into_iter()
+                .map(|part| SparePartResponse { low_stock: is_low_stock(&part), part })
+                .collect(),
+        )),
+        Err(err) => Ok(ApiResponse::error(format!("Database error: {}", err))),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct SparePartRequest {
+    pub name: String,
+    pub description: Option<String>,
+    pub unit: String,
+    pub unit_cost: f64,
+    pub reorder_threshold: i32,
+    pub reorder_quantity: i32,
+}
+
+fn validate_part(payload: &SparePartRequest) -> Result<(), String> {
+    if payload.name.trim().is_empty() || payload.unit.trim().is_empty() {
+        return Err("A spare part needs a name and a unit".to_string());
+    }
+    if !payload.unit_cost.is_finite() || payload.unit_cost < 0.0 {
+        return Err("Unit cost cannot be negative".to_string());
+    }
+    if payload.reorder_threshold < 0 {
+        return Err("Reorder threshold cannot be negative".to_string());
+    }
+    if payload.reorder_quantity <= 0 {
+        return Err("Reorder quantity must be at least 1".to_string());
+    }
+    Ok(())
+}
+
+#[derive(Deserialize)]
+pub struct CreateSparePartRequest {
+    pub part_number: String,
+    pub stock_quantity: i32,
+    #[serde(flatten)]
+    pub details: SparePartRequest,
+}
+
+#[tauri::command]
+pub async fn create_spare_part(
+    state: State<'_, AppState>,
+    session_token: String,
+    payload: CreateSparePartRequest,
+) -> Result<ApiResponse<spare_part::Model>, String> {
+    let session = match authorize(&state, &session_token, "create_spare_part").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    if let Err(message) = validate_part(&payload.details) {
+        return Ok(ApiResponse::error(message));
+    }
+
+    if payload.part_number.trim().is_empty() {
+        return Ok(ApiResponse::error("A spare part needs a part number".to_string()));
+    }
+
+    if payload.stock_quantity < 0 {
+        return Ok(ApiResponse::error("Stock cannot be negative".to_string()));
+    }
+
+    let txn = match state.db.begin().await {
+        Ok(txn) => txn,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    let details = payload.details;
+    let part = match (spare_part::ActiveModel {
+        part_id: Set(format!("SP-{}", Uuid::new_v4())),
+        part_number: Set(payload.part_number.trim().to_string()),
+        name: Set(details.name),
+        description: Set(details.description),
+        unit: Set(details.unit),
+        unit_cost: Set(details.unit_cost),
+        stock_quantity: Set(payload.stock_quantity),
+        reorder_threshold: Set(details.reorder_threshold),
+        reorder_quantity: Set(details.reorder_quantity),
+        reorder_proposal_id: Set(None),
+        is_active: Set(true),
+    })
+    .insert(&txn)
+    .await
+    {
+        Ok(part) => part,
+        Err(err) => return Ok(ApiResponse::error(format!("Failed to create spare part: {}", err))),
+    };
+
+    let part_id = part.part_id.clone();
+    let notified = match raise_reorder_if_low(&txn, part, &session.user_id).await {
+        Ok(notified) => notified,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    let part = match SparePart::find_by_id(part_id).one(&txn).await {
+        Ok(Some(part)) => part,
+        Ok(None) => return Ok(ApiResponse::error("Failed to create spare part".to_string())),
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    if let Err(err) = txn.commit().await {
+        return Ok(ApiResponse::error(format!("Failed to create spare part: {}", err)));
+    }
+
+    invalidate_reorder(&state, &notified).await;
+    Ok(ApiResponse::success(part))
+}
+
+#[derive(Deserialize)]
+pub struct UpdateSparePartRequest {
+    pub part_id: String,
+    pub is_active: bool,
+    #[serde(flatten)]
+    pub details: SparePartRequest,
+}
+
+#[tauri::command]
+pub async fn update_spare_part(
+    state: State<'_, AppState>,
+    session_token: String,
+    payload: UpdateSparePartRequest,
+) -> Result<ApiResponse<spare_part::Model>, String> {
+    let session = match authorize(&state, &session_token, "update_spare_part").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    if let Err(message) = validate_part(&payload.details) {
+        return Ok(ApiResponse::error(message));
+    }
+
+    match adjust_part(&state, &session.user_id, &payload.part_id, |part| {
+        let details = payload.details;
+        part.name = Set(details.name);
+        part.description = Set(details.description);
+        part.unit = Set(details.unit);
+        part.unit_cost = Set(details.unit_cost);
+        part.reorder_threshold = Set(details.reorder_threshold);
+        part.reorder_quantity = Set(details.reorder_quantity);
+        part.is_active = Set(payload.is_active);
+        Ok(())
+    })
+    .await
+    {
+        Ok(part) => Ok(ApiResponse::success(part)),
+        Err(message) => Ok(ApiResponse::error(message)),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct RestockSparePartRequest {
+    pub part_id: String,
+    pub quantity: i32,
+}
+
+// Records delivered stock. Once the part is back above its threshold the next shortage
+// raises a fresh purchase proposal.
+#[tauri::command]
+pub async fn restock_spare_part(
+    state: State<'_, AppState>,
+    session_token: String,
+    payload: RestockSparePartRequest,
+) -> Result<ApiResponse<spare_part::Model>, String> {
+    let session = match authorize(&state, &session_token, "restock_spare_part").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    if payload.quantity <= 0 {
+        return Ok(ApiResponse::error("Restock quantity must be at least 1".to_string()));
+    }
+
+    match adjust_part(&state, &session.user_id, &payload.part_id, |part| {
+        let stock = part.stock_quantity.as_ref().checked_add(payload.quantity).ok_or("Stock is too large")?;
+        part.stock_quantity = Set(stock);
+        if stock > *part.reorder_threshold.as_ref() {
+            part.reorder_proposal_id = Set(None);
+        }
+        Ok(())
+    })
+    .await
+    {
+        Ok(part) => Ok(ApiResponse::success(part)),
+        Err(message) => Ok(ApiResponse::error(message)),
+    }
+}
+
+// Applies a change to a locked part row and raises a purchase proposal if it leaves the part low.
+async fn adjust_part(
+    state: &AppState,
+    actor_id: &str,
+    part_id: &str,
+    change: impl FnOnce(&mut spare_part::ActiveModel) -> Result<(), &'static str>,
+) -> Result<spare_part::Model, String> {
+    let txn = state.db.begin().await.map_err(|e| format!("Database error: {}", e))?;
+
+    let part = SparePart::find_by_id(part_id.to_string())
+        .lock_exclusive()
+        .one(&txn)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .ok_or_else(|| format!("No spare part found with ID: {}", part_id))?;
+
+    let mut active_part: spare_part::ActiveModel = part.into();
+    change(&mut active_part)?;
+    let part = active_part
+        .update(&txn)
+        .await
+        .map_err(|e| format!("Failed to update spare part: {}", e))?;
+
+    let notified = raise_reorder_if_low(&txn, part, actor_id).await?;
+
+    let part = SparePart::find_by_id(part_id.to_string())
+        .one(&txn)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .ok_or_else(|| format!("No spare part found with ID: {}", part_id))?;
+
+    txn.commit().await.map_err(|e| format!("Failed to update spare part: {}", e))?;
+
+    invalidate_reorder(state, &notified).await;
+    Ok(part)
+}
+
+#[derive(Deserialize)]
+pub struct RecordPartUsageRequest {
+    pub maintenance_id: String,
+    pub part_id: String,
+    pub quantity: i32,
+}
+
+#[tauri::command]
+pub async fn record_part_usage(
+    state: State<'_, AppState>,
+    session_token: String,
+    payload: RecordPartUsageRequest,
+) -> Result<ApiResponse<maintenance_part_usage::Model>, String> {
+    let session = match authorize(&state, &session_token, "record_part_usage").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    if payload.quantity <= 0 {
+        return Ok(ApiResponse::error("Quantity must be at least 1".to_string()));
+    }
+
+    match consume_part(&state, &session.user_id, payload).await {
+        Ok(usage) => Ok(ApiResponse::success(usage)),
+        Err(message) => Ok(ApiResponse::error(message)),
+    }
+}
+
+async fn consume_part(
+    state: &AppState,
+    actor_id: &str,
+    payload: RecordPartUsageRequest,
+) -> Result<maintenance_part_usage::Model, String> {
+    let txn = state.db.begin().await.map_err(|e| format!("Database error: {}", e))?;
+
+    let ticket = Maintenance::find_by_id(payload.maintenance_id.clone())
+        .one(&txn)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .ok_or_else(|| format!("No maintenance request found with ID: {}", payload.maintenance_id))?;
+
+    if ticket.maintenance_staff_id.as_deref() != Some(actor_id) {
+        return Err("Only the assigned maintenance staff can record parts on this ticket".to_string());
+    }
+
+    if ticket.status != MaintenanceStatus::InProgress {
+        return Err("Parts can only be recorded while work is in progress".to_string());
+    }
+
+    // Locked so concurrent jobs can never take the same stock twice.
+    let part = SparePart::find_by_id(payload.part_id.clone())
+        .lock_exclusive()
+        .one(&txn)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .ok_or_else(|| format!("No spare part found with ID: {}", payload.part_id))?;
+
+    if !part.is_active {
+        return Err(format!("{} is no longer stocked", part.name));
+    }
+
+    if part.stock_quantity < payload.quantity {
+        return Err(format!(
+            "Only {} {} of {} left in stock",
+            part.stock_quantity, part.unit, part.name
+        ));
+    }
+
+    let usage = maintenance_part_usage::ActiveModel {
+        usage_id: Set(format!("PU-{}", Uuid::new_v4())),
+        maintenance_id: Set(ticket.maintenance_id),
+        part_id: Set(part.part_id.clone()),
+        quantity: Set(payload.quantity),
+        unit_cost: Set(part.unit_cost),
+        used_by: Set(actor_id.to_string()),
+        used_at: Set(Utc::now().to_rfc3339()),
+    }
+    .insert(&txn)
+    .await
+    .map_err(|e| format!("Failed to record part usage: {}", e))?;
+
+    let stock = part.stock_quantity - payload.quantity;
+    let mut active_part: spare_part::ActiveModel = part.into();
+    active_part.stock_quantity = Set(stock);
+    let part = active_part
+        .update(&txn)
+        .await
+        .map_err(|e| format!("Failed to update spare part: {}", e))?;
+
+    let notified = raise_reorder_if_low(&txn, part, actor_id).await?;
+
+    txn.commit().await.map_err(|e| format!("Failed to record part usage: {}", e))?;
+
+    invalidate_reorder(state, &notified).await;
+    Ok(usage)
+}
+
+#[derive(Serialize)]
+pub struct PartUsageResponse {
+    #[serde(flatten)]
+    pub usage: maintenance_part_usage::Model,
+    pub part_number: String,
+    pub part_name: String,
+    pub unit: String,
+    pub total_cost: f64,
+}
+
+#[tauri::command]
+pub async fn view_maintenance_parts(
+    state: State<'_, AppState>,
+    session_token: String,
+    maintenance_id: String,
+) -> Result<ApiResponse<Vec<PartUsageResponse>>, String> {
+    if let Err(message) = authorize(&state, &session_token, "view_maintenance_parts").await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    let usages = match MaintenancePartUsage::find()
+        .filter(maintenance_part_usage::Column::MaintenanceId.eq(&maintenance_id))
+        .order_by_asc(maintenance_part_usage::Column::UsedAt)
+        .find_also_related(SparePart)
+        .all(&state.db)
+        .await
+    {
+        Ok(usages) => usages,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    Ok(ApiResponse::success(
+        usages
+            .into_iter()
+            .filter_map(|(usage, part)| {
+                part.map(|part| PartUsageResponse {
+                    total_cost: f64::from(usage.quantity) * usage.unit_cost,
+                    usage,
+                    part_number: part.part_number,
+                    part_name: part.name,
+                    unit: part.unit,
+                })
+            })
+            .collect(),
+    ))
+}
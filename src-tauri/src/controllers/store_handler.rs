@@ -1,11 +1,12 @@
 use anyhow::Result;
-use base64::encode;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
 use entity::store::{self, ActiveModel as StoreActiveModel, Entity as Store};
 use sea_orm::{ActiveModelTrait, EntityTrait, QueryOrder, ActiveValue::Set};
 use serde::Deserialize;
 use serde::Serialize;
 use tauri::State;
-use crate::{ApiResponse, cache_get, cache_set, cache_delete, AppState};
+use crate::{ApiResponse, cache_get, cache_set_tagged, cache_invalidate, AppState};
+use crate::controllers::session_handler::authorize;
 
 #[derive(Serialize)]
 pub struct StoreResponse {
@@ -29,7 +30,12 @@ struct StoreCache {
 #[tauri::command]
 pub async fn view_all_stores(
     state: State<'_, AppState>,
+    session_token: String,
 ) -> Result<ApiResponse<Vec<StoreResponse>>, String> {
+    if let Err(message) = authorize(&state, &session_token, "view_all_stores").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     let cache_key = "get_all_stores_cache";
 
     if let Some(cached_stores) = cache_get::<Vec<StoreCache>>(&state.redis_pool, cache_key).await {
@@ -43,7 +49,7 @@ pub async fn view_all_stores(
                     name: cache.name,
                     description: cache.description,
                     operational_status: cache.operational_status,
-                    image: encode(&model.image),
+                    image: STANDARD.encode(&model.image),
                 });
             }
         }
@@ -65,7 +71,7 @@ pub async fn view_all_stores(
                     name: s.name.clone(),
                     description: s.description.clone(),
                     operational_status: s.operational_status.clone(),
-                    image: encode(&s.image),
+                    image: STANDARD.encode(&s.image),
                 })
                 .collect();
 
@@ -80,7 +86,7 @@ pub async fn view_all_stores(
                 })
                 .collect();
 
-            cache_set(&state.redis_pool, cache_key, &cache_data, 60).await;
+            cache_set_tagged(&state.redis_pool, cache_key, &cache_data, 60, &["store"]).await;
 
             Ok(ApiResponse::success(full_responses))
         }
@@ -101,8 +107,13 @@ pub struct CreateStoreRequest {
 #[tauri::command]
 pub async fn create_store(
     state: State<'_, AppState>,
+    session_token: String,
     payload: CreateStoreRequest,
 ) -> Result<ApiResponse<store::Model>, String> {
+    if let Err(message) = authorize(&state, &session_token, "create_store").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     let new_store = StoreActiveModel {
         store_id: Set(payload.store_id),
         sales_associate_id: Set(payload.sales_associate_id),
@@ -115,7 +126,7 @@ pub async fn create_store(
 
     match new_store.insert(&state.db).await {
         Ok(store) => {
-            cache_delete(&state.redis_pool, "get_all_stores_cache").await;
+            cache_invalidate(&state.redis_pool, &["store"]).await;
             Ok(ApiResponse::success(store))
         }
         Err(err) => Ok(ApiResponse::error(format!("Failed to create store: {}", err))),
@@ -135,8 +146,13 @@ pub struct UpdateStoreRequest {
 #[tauri::command]
 pub async fn update_store(
     state: State<'_, AppState>,
+    session_token: String,
     payload: UpdateStoreRequest,
 ) -> Result<ApiResponse<store::Model>, String> {
+    if let Err(message) = authorize(&state, &session_token, "update_store").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     match Store::find_by_id(payload.store_id.clone()).one(&state.db).await {
         Ok(Some(existing_store)) => {
             let mut active_store: StoreActiveModel = existing_store.into();
@@ -148,7 +164,7 @@ pub async fn update_store(
 
             match active_store.update(&state.db).await {
                 Ok(updated_store) => {
-                    cache_delete(&state.redis_pool, "get_all_stores_cache").await;
+                    cache_invalidate(&state.redis_pool, &["store"]).await;
                     Ok(ApiResponse::success(updated_store))
                 }
                 Err(err) => Ok(ApiResponse::error(format!("Failed to update store: {}", err))),
@@ -167,14 +183,19 @@ pub struct DeleteStoreRequest {
 #[tauri::command]
 pub async fn delete_store(
     state: State<'_, AppState>,
+    session_token: String,
     payload: DeleteStoreRequest,
 ) -> Result<ApiResponse<()>, String> {
+    if let Err(message) = authorize(&state, &session_token, "delete_store").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     match Store::delete_by_id(payload.store_id.clone()).exec(&state.db).await {
         Ok(delete_result) => {
             if delete_result.rows_affected == 0 {
                 return Ok(ApiResponse::error(format!("No store found with ID: {}", payload.store_id)));
             }
-            cache_delete(&state.redis_pool, "get_all_stores_cache").await;
+            cache_invalidate(&state.redis_pool, &["store"]).await;
             Ok(ApiResponse::success(()))
         }
         Err(err) => Ok(ApiResponse::error(format!("Failed to delete store: {}", err))),
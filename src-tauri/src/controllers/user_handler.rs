@@ -1,10 +1,54 @@
 use anyhow::Result;
-use sea_orm::{ActiveModelTrait, EntityTrait, QueryFilter, ColumnTrait, ActiveValue::Set};
+use argon2::{
+    password_hash::{rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+    Argon2,
+};
+use chrono::Utc;
+use sea_orm::{ActiveModelTrait, EntityTrait, QueryFilter, ColumnTrait, TransactionTrait, ActiveValue::Set};
 use serde::{Deserialize, Serialize};
 use tauri::State;
-use crate::{ApiResponse, cache_get, cache_set, AppState};
+use uuid::Uuid;
+use crate::{ApiResponse, cache_get, cache_set_tagged, cache_invalidate, AppState};
 use entity::user::{self, Entity as User};
 use crate::controllers::notification_handler;
+use crate::controllers::session_handler::{authorize, create_session, refresh_user_sessions, revoke_user_sessions};
+use crate::controllers::wallet_handler::{post_wallet_entry, to_major_units, to_minor_units};
+use entity::sea_orm_active_enums::WalletTransactionKind;
+
+const MIN_PASSWORD_LENGTH: usize = 8;
+
+fn hash_password(password: &str) -> Result<String, String> {
+    let salt = SaltString::generate(&mut OsRng);
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|err| format!("Failed to hash password: {}", err))
+}
+
+fn verify_password(password: &str, stored_hash: &str) -> bool {
+    match PasswordHash::new(stored_hash) {
+        Ok(parsed) => Argon2::default()
+            .verify_password(password.as_bytes(), &parsed)
+            .is_ok(),
+        Err(_) => false,
+    }
+}
+
+fn validate_new_password(user_id: &str, password: &str) -> Result<(), String> {
+    if password.chars().count() < MIN_PASSWORD_LENGTH {
+        return Err(format!("Password must be at least {} characters long", MIN_PASSWORD_LENGTH));
+    }
+
+    if password.eq_ignore_ascii_case(user_id) {
+        return Err("Password must not be the same as the user ID".to_string());
+    }
+
+    Ok(())
+}
+
+async fn clear_user_cache(state: &AppState, user_id: &str) {
+    cache_invalidate(&state.redis_pool, &["user", &format!("user:{}", user_id)]).await;
+}
 
 #[derive(Serialize)]
 pub struct UserResponse {
@@ -14,23 +58,40 @@ pub struct UserResponse {
     pub role: String,
     pub balance: f64,
     pub restaurant_id: Option<String>,
+    pub must_change_password: bool,
+}
+
+#[derive(Serialize)]
+pub struct LoginResponse {
+    #[serde(flatten)]
+    pub user: UserResponse,
+    pub session_token: String,
 }
 
 #[tauri::command]
 pub async fn login_user(
     state: State<'_, AppState>,
     user_id: String
-) -> Result<ApiResponse<UserResponse>, String> {
+) -> Result<ApiResponse<LoginResponse>, String> {
     let cache_key = format!("user_{}", user_id);
 
     if let Some(cached_user) = cache_get::<user::Model>(&state.redis_pool, &cache_key).await {
-        return Ok(ApiResponse::success(UserResponse {
-            user_id: cached_user.user_id,
-            name: cached_user.name,
-            email: cached_user.email,
-            role: cached_user.role,
-            balance: cached_user.balance,
-            restaurant_id: cached_user.restaurant_id.clone(),
+        if cached_user.role != "Customer" {
+            return Ok(ApiResponse::error("Staff must sign in with a password".to_string()));
+        }
+
+        let session_token = create_session(&state, &cached_user).await;
+        return Ok(ApiResponse::success(LoginResponse {
+            user: UserResponse {
+                user_id: cached_user.user_id,
+                name: cached_user.name,
+                email: cached_user.email,
+                role: cached_user.role,
+                balance: to_major_units(cached_user.balance),
+                restaurant_id: cached_user.restaurant_id.clone(),
+                must_change_password: cached_user.must_change_password,
+            },
+            session_token,
         }));
     }
 
@@ -40,16 +101,22 @@ pub async fn login_user(
         .await
     {
         Ok(Some(user)) => {
+            if user.role != "Customer" {
+                return Ok(ApiResponse::error("Staff must sign in with a password".to_string()));
+            }
+
             let response = UserResponse {
                 user_id: user.user_id.clone(),
                 name: user.name.clone(),
                 email: user.email.clone(),
                 role: user.role.clone(),
-                balance: user.balance,
+                balance: to_major_units(user.balance),
                 restaurant_id: user.restaurant_id.clone(),
+                must_change_password: user.must_change_password,
             };
-            cache_set(&state.redis_pool, &cache_key, &user, 3600).await;
-            Ok(ApiResponse::success(response))
+            cache_set_tagged(&state.redis_pool, &cache_key, &user, 3600, &[&format!("user:{}", user_id)]).await;
+            let session_token = create_session(&state, &user).await;
+            Ok(ApiResponse::success(LoginResponse { user: response, session_token }))
         }
         Ok(None) => Ok(ApiResponse::error("User not found".to_string())),
         Err(err) => Ok(ApiResponse::error(format!("Database error: {}", err))),
@@ -61,7 +128,7 @@ pub async fn staff_login(
     state: State<'_, AppState>,
     user_id: String,
     password: String
-) -> Result<ApiResponse<UserResponse>, String> {
+) -> Result<ApiResponse<LoginResponse>, String> {
     let cache_key = format!("user_{}", user_id);
 
     match User::find()
@@ -75,17 +142,19 @@ pub async fn staff_login(
             }
 
             if let Some(stored_password) = &user.password {
-                if stored_password == &password {
+                if verify_password(&password, stored_password) {
                     let response = UserResponse {
                         user_id: user.user_id.clone(),
                         name: user.name.clone(),
                         email: user.email.clone(),
                         role: user.role.clone(),
-                        balance: user.balance,
+                        balance: to_major_units(user.balance),
                         restaurant_id: user.restaurant_id.clone(),
+                        must_change_password: user.must_change_password,
                     };
-                    cache_set(&state.redis_pool, &cache_key, &user, 3600).await;
-                    Ok(ApiResponse::success(response))
+                    cache_set_tagged(&state.redis_pool, &cache_key, &user, 3600, &[&format!("user:{}", user_id)]).await;
+                    let session_token = create_session(&state, &user).await;
+                    Ok(ApiResponse::success(LoginResponse { user: response, session_token }))
                 } else {
                     Ok(ApiResponse::error("Invalid password".to_string()))
                 }
@@ -107,43 +176,78 @@ pub struct TopUpRequest {
 #[tauri::command]
 pub async fn top_up_balance(
     state: State<'_, AppState>,
+    session_token: String,
     payload: TopUpRequest,
 ) -> Result<ApiResponse<UserResponse>, String> {
-    match User::find()
-        .filter(user::Column::UserId.eq(&payload.user_id))
-        .one(&state.db)
-        .await
+    let session = match authorize(&state, &session_token, "top_up_balance").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    if !session.can_act_for(&payload.user_id) {
+        return Ok(ApiResponse::error("Customers can only top up their own balance".to_string()));
+    }
+
+    if !payload.amount.is_finite() || payload.amount <= 0.0 {
+        return Ok(ApiResponse::error("Top up amount must be greater than zero".to_string()));
+    }
+
+    let amount = to_minor_units(payload.amount);
+    if amount <= 0 {
+        return Ok(ApiResponse::error("Top up amount must be greater than zero".to_string()));
+    }
+
+    let txn = match state.db.begin().await {
+        Ok(txn) => txn,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    let updated_user = match post_wallet_entry(
+        &txn,
+        &payload.user_id,
+        WalletTransactionKind::TopUp,
+        amount,
+        None,
+        format!("Top up by {}", session.user_id),
+    )
+    .await
     {
-        Ok(Some(user)) => {
-            let mut active_user: user::ActiveModel = user.into();
-            active_user.balance = Set(active_user.balance.unwrap() + payload.amount);
+        Ok((updated_user, _)) => updated_user,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
 
-            match active_user.update(&state.db).await {
-                Ok(updated_user) => {
-                    let response = UserResponse {
-                        user_id: updated_user.user_id.clone(),
-                        name: updated_user.name.clone(),
-                        email: updated_user.email.clone(),
-                        role: updated_user.role.clone(),
-                        balance: updated_user.balance,
-                        restaurant_id: updated_user.restaurant_id.clone(),
-                    };
-                    cache_set(&state.redis_pool, &format!("user_{}", payload.user_id), &updated_user, 3600).await;
-                    Ok(ApiResponse::success(response))
-                }
-                Err(err) => Ok(ApiResponse::error(format!("Failed to top up: {}", err))),
-            }
-        }
-        Ok(None) => Ok(ApiResponse::error("User not found".to_string())),
-        Err(err) => Ok(ApiResponse::error(format!("Database error: {}", err))),
+    if let Err(err) = txn.commit().await {
+        return Ok(ApiResponse::error(format!("Failed to top up: {}", err)));
     }
+
+    let response = UserResponse {
+        user_id: updated_user.user_id.clone(),
+        name: updated_user.name.clone(),
+        email: updated_user.email.clone(),
+        role: updated_user.role.clone(),
+        balance: to_major_units(updated_user.balance),
+        restaurant_id: updated_user.restaurant_id.clone(),
+        must_change_password: updated_user.must_change_password,
+    };
+    clear_user_cache(&state, &payload.user_id).await;
+    Ok(ApiResponse::success(response))
 }
 
 #[tauri::command]
 pub async fn get_balance(
     state: State<'_, AppState>,
+    session_token: String,
     user_id: String
 ) -> Result<ApiResponse<UserResponse>, String> {
+    let session = match authorize(&state, &session_token, "get_balance").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    if !session.can_act_for(&user_id) {
+        return Ok(ApiResponse::error("Customers can only view their own balance".to_string()));
+    }
+
     let cache_key = format!("user_{}", user_id);
 
     if let Some(cached_user) = cache_get::<user::Model>(&state.redis_pool, &cache_key).await {
@@ -152,8 +256,9 @@ pub async fn get_balance(
             name: cached_user.name,
             email: cached_user.email,
             role: cached_user.role,
-            balance: cached_user.balance,
+            balance: to_major_units(cached_user.balance),
             restaurant_id: cached_user.restaurant_id.clone(),
+            must_change_password: cached_user.must_change_password,
         }));
     }
 
@@ -168,10 +273,11 @@ pub async fn get_balance(
                 name: user.name.clone(),
                 email: user.email.clone(),
                 role: user.role.clone(),
-                balance: user.balance,
+                balance: to_major_units(user.balance),
                 restaurant_id: user.restaurant_id.clone(),
+                must_change_password: user.must_change_password,
             };
-            cache_set(&state.redis_pool, &cache_key, &user, 3600).await;
+            cache_set_tagged(&state.redis_pool, &cache_key, &user, 3600, &[&format!("user:{}", user_id)]).await;
             Ok(ApiResponse::success(response))
         }
         Ok(None) => Ok(ApiResponse::error("User not found".to_string())),
@@ -182,15 +288,25 @@ pub async fn get_balance(
 #[tauri::command]
 pub async fn get_notifications(
     state: State<'_, AppState>,
+    session_token: String,
     user_id: String
 ) -> Result<ApiResponse<Vec<notification_handler::NotificationResponse>>, String> {
-    notification_handler::view_notification(state, user_id).await
+    if let Err(message) = authorize(&state, &session_token, "get_notifications").await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    notification_handler::view_notification(state, session_token, user_id, None, None).await
 }
 
 #[tauri::command]
 pub async fn get_all_users(
-    state: State<'_, AppState>
+    state: State<'_, AppState>,
+    session_token: String,
 ) -> Result<ApiResponse<Vec<UserResponse>>, String> {
+    if let Err(message) = authorize(&state, &session_token, "get_all_users").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     match User::find().all(&state.db).await {
         Ok(users) => {
             let response: Vec<UserResponse> = users.into_iter().map(|user| UserResponse {
@@ -198,8 +314,9 @@ pub async fn get_all_users(
                 name: user.name,
                 email: user.email,
                 role: user.role,
-                balance: user.balance,
+                balance: to_major_units(user.balance),
                 restaurant_id: user.restaurant_id,
+                must_change_password: user.must_change_password,
             }).collect();
 
             Ok(ApiResponse::success(response))
@@ -217,8 +334,13 @@ pub struct UserLiteResponse {
 
 #[tauri::command]
 pub async fn get_all_users_lite(
-    state: State<'_, AppState>
+    state: State<'_, AppState>,
+    session_token: String,
 ) -> Result<ApiResponse<Vec<UserLiteResponse>>, String> {
+    if let Err(message) = authorize(&state, &session_token, "get_all_users_lite").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     match User::find().all(&state.db).await {
         Ok(users) => {
             let response: Vec<UserLiteResponse> = users.into_iter().map(|user| UserLiteResponse {
@@ -244,7 +366,12 @@ pub struct RideStaffResponse {
 #[tauri::command]
 pub async fn get_all_ride_staff(
     state: State<'_, AppState>,
+    session_token: String,
 ) -> Result<ApiResponse<Vec<RideStaffResponse>>, String> {
+    if let Err(message) = authorize(&state, &session_token, "get_all_ride_staff").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     match User::find()
         .filter(user::Column::Role.eq("Ride Staff"))
         .all(&state.db)
@@ -270,8 +397,18 @@ pub async fn get_all_ride_staff(
 #[tauri::command]
 pub async fn get_user_by_id(
     state: State<'_, AppState>,
+    session_token: String,
     user_id: String,
 ) -> Result<ApiResponse<UserResponse>, String> {
+    let session = match authorize(&state, &session_token, "get_user_by_id").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    if !session.can_act_for(&user_id) {
+        return Ok(ApiResponse::error("Customers can only view their own balance".to_string()));
+    }
+
     let cache_key = format!("user_by_{}", user_id);
 
     if let Some(cached_user) = cache_get::<user::Model>(&state.redis_pool, &cache_key).await {
@@ -280,8 +417,9 @@ pub async fn get_user_by_id(
             name: cached_user.name,
             role: cached_user.role,
             email: cached_user.email,
-            balance: cached_user.balance,
+            balance: to_major_units(cached_user.balance),
             restaurant_id: cached_user.restaurant_id,
+            must_change_password: cached_user.must_change_password,
         }));
     }
 
@@ -296,10 +434,11 @@ pub async fn get_user_by_id(
                 name: user.name.clone(),
                 role: user.role.clone(),
                 email: user.email.clone(),
-                balance: user.balance,
+                balance: to_major_units(user.balance),
                 restaurant_id: user.restaurant_id.clone(),
+                must_change_password: user.must_change_password,
             };
-            cache_set(&state.redis_pool, &cache_key, &user, 3600).await;
+            cache_set_tagged(&state.redis_pool, &cache_key, &user, 3600, &[&format!("user:{}", user_id)]).await;
             Ok(ApiResponse::success(response))
         }
         Ok(None) => Ok(ApiResponse::error("User not found".into())),
@@ -310,8 +449,13 @@ pub async fn get_user_by_id(
 #[tauri::command]
 pub async fn get_user_lite_by_id(
     state: State<'_, AppState>,
+    session_token: String,
     user_id: String,
 ) -> Result<ApiResponse<UserLiteResponse>, String> {
+    if let Err(message) = authorize(&state, &session_token, "get_user_lite_by_id").await {
+        return Ok(ApiResponse::error(message));
+    }
+
     let cache_key = format!("user_lite_{}", user_id);
 
     if let Some(cached_user) = cache_get::<user::Model>(&state.redis_pool, &cache_key).await {
@@ -333,7 +477,7 @@ pub async fn get_user_lite_by_id(
                 name: user.name.clone(),
                 role: user.role.clone(),
             };
-            cache_set(&state.redis_pool, &cache_key, &user, 3600).await;
+            cache_set_tagged(&state.redis_pool, &cache_key, &user, 3600, &[&format!("user:{}", user_id)]).await;
             Ok(ApiResponse::success(response))
         }
         Ok(None) => Ok(ApiResponse::error("User not found".into())),
@@ -348,37 +492,251 @@ pub struct CreateCustomerRequest {
     pub email: String,
     pub dob: String,
     pub balance: f64,
+    pub height_cm: Option<i32>,
+    #[serde(default)]
+    pub uses_wheelchair: bool,
 }
 
 #[tauri::command]
 pub async fn create_customer(
     state: State<'_, AppState>,
+    session_token: String,
     payload: CreateCustomerRequest,
 ) -> Result<ApiResponse<UserResponse>, String> {
+    if let Err(message) = authorize(&state, &session_token, "create_customer").await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    if !payload.balance.is_finite() || payload.balance < 0.0 {
+        return Ok(ApiResponse::error("Opening balance cannot be negative".to_string()));
+    }
+
+    if payload.height_cm.is_some_and(|height| height <= 0) {
+        return Ok(ApiResponse::error("Height must be a positive number of centimetres".to_string()));
+    }
+
+    let opening_balance = to_minor_units(payload.balance);
+
+    let txn = match state.db.begin().await {
+        Ok(txn) => txn,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    // The row starts at zero and the opening balance is posted through the ledger.
     let new_customer = user::ActiveModel {
         user_id: Set(payload.user_id.clone()),
         name: Set(payload.name.clone()),
         email: Set(payload.email.clone()),
         dob: Set(payload.dob.clone()),
-        balance: Set(payload.balance),
+        balance: Set(0),
         role: Set("Customer".to_string()),
         password: Set(None),
         status: Set(None),
         restaurant_id: Set(None),
+        must_change_password: Set(false),
+        password_changed_at: Set(None),
+        height_cm: Set(payload.height_cm),
+        uses_wheelchair: Set(payload.uses_wheelchair),
     };
 
-    match new_customer.insert(&state.db).await {
-        Ok(created) => {
-            let response = UserResponse {
-                user_id: created.user_id,
-                name: created.name,
-                email: created.email,
-                role: created.role,
-                balance: created.balance,
-                restaurant_id: created.restaurant_id,
-            };
-            Ok(ApiResponse::success(response))
+    let mut created = match new_customer.insert(&txn).await {
+        Ok(created) => created,
+        Err(err) => return Ok(ApiResponse::error(format!("Failed to create customer: {}", err))),
+    };
+
+    if opening_balance > 0 {
+        created = match post_wallet_entry(
+            &txn,
+            &created.user_id,
+            WalletTransactionKind::OpeningBalance,
+            opening_balance,
+            None,
+            "Opening balance at registration".to_string(),
+        )
+        .await
+        {
+            Ok((updated, _)) => updated,
+            Err(message) => return Ok(ApiResponse::error(message)),
+        };
+    }
+
+    if let Err(err) = txn.commit().await {
+        return Ok(ApiResponse::error(format!("Failed to create customer: {}", err)));
+    }
+
+    let response = UserResponse {
+        user_id: created.user_id,
+        name: created.name,
+        email: created.email,
+        role: created.role,
+        balance: to_major_units(created.balance),
+        restaurant_id: created.restaurant_id,
+        must_change_password: created.must_change_password,
+    };
+    Ok(ApiResponse::success(response))
+}
+
+#[derive(Deserialize)]
+pub struct ChangePasswordRequest {
+    pub current_password: String,
+    pub new_password: String,
+}
+
+#[tauri::command]
+pub async fn change_password(
+    state: State<'_, AppState>,
+    session_token: String,
+    payload: ChangePasswordRequest,
+) -> Result<ApiResponse<UserResponse>, String> {
+    let session = match authorize(&state, &session_token, "change_password").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    let user = match User::find_by_id(session.user_id.clone()).one(&state.db).await {
+        Ok(Some(user)) => user,
+        Ok(None) => return Ok(ApiResponse::error("User not found".to_string())),
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    match &user.password {
+        Some(stored_password) if verify_password(&payload.current_password, stored_password) => {}
+        Some(_) => return Ok(ApiResponse::error("Current password is incorrect".to_string())),
+        None => return Ok(ApiResponse::error("User does not have a password set".to_string())),
+    }
+
+    if payload.current_password == payload.new_password {
+        return Ok(ApiResponse::error("New password must differ from the current password".to_string()));
+    }
+
+    if let Err(message) = validate_new_password(&user.user_id, &payload.new_password) {
+        return Ok(ApiResponse::error(message));
+    }
+
+    let hashed = match hash_password(&payload.new_password) {
+        Ok(hashed) => hashed,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    let mut active_user: user::ActiveModel = user.into();
+    active_user.password = Set(Some(hashed));
+    active_user.must_change_password = Set(false);
+    active_user.password_changed_at = Set(Some(Utc::now().to_rfc3339()));
+
+    match active_user.update(&state.db).await {
+        Ok(updated_user) => {
+            clear_user_cache(&state, &updated_user.user_id).await;
+            // Other devices signed in with the old password are signed out.
+            revoke_user_sessions(&state, &updated_user.user_id, Some(&session_token)).await;
+            refresh_user_sessions(&state, &updated_user).await;
+            Ok(ApiResponse::success(UserResponse {
+                user_id: updated_user.user_id,
+                name: updated_user.name,
+                email: updated_user.email,
+                role: updated_user.role,
+                balance: to_major_units(updated_user.balance),
+                restaurant_id: updated_user.restaurant_id,
+                must_change_password: updated_user.must_change_password,
+            }))
+        }
+        Err(err) => Ok(ApiResponse::error(format!("Failed to change password: {}", err))),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct ResetPasswordRequest {
+    pub user_id: String,
+}
+
+#[derive(Serialize)]
+pub struct ResetPasswordResponse {
+    pub user_id: String,
+    pub temporary_password: String,
+}
+
+#[tauri::command]
+pub async fn reset_staff_password(
+    state: State<'_, AppState>,
+    session_token: String,
+    payload: ResetPasswordRequest,
+) -> Result<ApiResponse<ResetPasswordResponse>, String> {
+    if let Err(message) = authorize(&state, &session_token, "reset_staff_password").await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    let user = match User::find_by_id(payload.user_id.clone()).one(&state.db).await {
+        Ok(Some(user)) => user,
+        Ok(None) => return Ok(ApiResponse::error("User not found".to_string())),
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    if user.role == "Customer" {
+        return Ok(ApiResponse::error("Customers do not have staff passwords".to_string()));
+    }
+
+    // The temporary password is only returned once; the user must replace it on next login.
+    let temporary_password = Uuid::new_v4().simple().to_string()[..12].to_string();
+    let hashed = match hash_password(&temporary_password) {
+        Ok(hashed) => hashed,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    let mut active_user: user::ActiveModel = user.into();
+    active_user.password = Set(Some(hashed));
+    active_user.must_change_password = Set(true);
+    active_user.password_changed_at = Set(Some(Utc::now().to_rfc3339()));
+
+    match active_user.update(&state.db).await {
+        Ok(updated_user) => {
+            clear_user_cache(&state, &updated_user.user_id).await;
+            revoke_user_sessions(&state, &updated_user.user_id, None).await;
+            Ok(ApiResponse::success(ResetPasswordResponse {
+                user_id: updated_user.user_id,
+                temporary_password,
+            }))
         }
-        Err(err) => Ok(ApiResponse::error(format!("Failed to create customer: {}", err))),
+        Err(err) => Ok(ApiResponse::error(format!("Failed to reset password: {}", err))),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct UpdateRiderProfileRequest {
+    pub user_id: String,
+    pub height_cm: Option<i32>,
+    pub uses_wheelchair: bool,
+}
+
+// Records what ride eligibility is checked against; heights are taken by staff rather than self-reported.
+#[tauri::command]
+pub async fn update_rider_profile(
+    state: State<'_, AppState>,
+    session_token: String,
+    payload: UpdateRiderProfileRequest,
+) -> Result<ApiResponse<()>, String> {
+    if let Err(message) = authorize(&state, &session_token, "update_rider_profile").await {
+        return Ok(ApiResponse::error(message));
     }
-}
\ No newline at end of file
+
+    if payload.height_cm.is_some_and(|height| height <= 0) {
+        return Ok(ApiResponse::error("Height must be a positive number of centimetres".to_string()));
+    }
+
+    let user = match User::find_by_id(payload.user_id.clone()).one(&state.db).await {
+        Ok(Some(user)) if user.role == "Customer" => user,
+        Ok(Some(_)) => return Ok(ApiResponse::error("Rider profiles are only kept for customers".to_string())),
+        Ok(None) => return Ok(ApiResponse::error("User not found".to_string())),
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    let mut active_user: user::ActiveModel = user.into();
+    active_user.height_cm = Set(payload.height_cm);
+    active_user.uses_wheelchair = Set(payload.uses_wheelchair);
+
+    match active_user.update(&state.db).await {
+        Ok(updated_user) => {
+            clear_user_cache(&state, &updated_user.user_id).await;
+            Ok(ApiResponse::success(()))
+        }
+        Err(err) => Ok(ApiResponse::error(format!("Failed to update rider profile: {}", err))),
+    }
+}
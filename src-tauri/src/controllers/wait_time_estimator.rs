@@ -0,0 +1,175 @@
+use chrono::{DateTime, Utc};
+use entity::ride;
+use entity::ride_cycle::{self, Entity as RideCycle};
+use sea_orm::sea_query::{Alias, Asterisk, Expr, Order, Query, WindowStatement};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, FromQueryResult, QueryFilter, QueryOrder, QuerySelect};
+use std::collections::HashMap;
+
+// Number of recent dispatches that feed the observed cycle time.
+const CYCLE_HISTORY_SIZE: u64 = 10;
+
+// Gaps longer than this many configured cycles are idle time or downtime rather than loading,
+// so they are left out of the observed cycle time.
+const MAX_GAP_CYCLES: i64 = 3;
+
+pub struct WaitEstimator {
+    riders_per_cycle: i64,
+    cycle_seconds: i64,
+    next_dispatch_in: i64,
+}
+
+impl WaitEstimator {
+    pub async fn for_ride<C: ConnectionTrait>(db: &C, ride: &ride::Model) -> Result<Self, String> {
+        let cycles = RideCycle::find()
+            .filter(ride_cycle::Column::RideId.eq(&ride.ride_id))
+            .order_by_desc(ride_cycle::Column::DispatchedAt)
+            .limit(CYCLE_HISTORY_SIZE)
+            .all(db)
+            .await
+            .map_err(|err| format!("Database error: {}", err))?;
+
+        Ok(Self::from_history(ride.capacity, ride.cycle_duration, &cycles, Utc::now()))
+    }
+
+    // The same recent dispatches `for_ride` reads, for many rides in one query, newest first.
+    pub async fn recent_cycles<C: ConnectionTrait>(
+        db: &C,
+        ride_ids: Vec<String>,
+    ) -> Result<HashMap<String, Vec<ride_cycle::Model>>, String> {
+        let ranked = Query::select()
+            .column(Asterisk)
+            .expr_window_as(
+                Expr::cust("ROW_NUMBER()"),
+                WindowStatement::partition_by(ride_cycle::Column::RideId)
+                    .order_by(ride_cycle::Column::DispatchedAt, Order::Desc)
+                    .to_owned(),
+                Alias::new("recency"),
+            )
+            .from(RideCycle)
+            .and_where(ride_cycle::Column::RideId.is_in(ride_ids))
+            .to_owned();
+
+        let query = Query::select()
+            .column(Asterisk)
+            .from_subquery(ranked, Alias::new("ranked"))
+            .and_where(Expr::col(Alias::new("recency")).lte(CYCLE_HISTORY_SIZE))
+            .order_by(Alias::new("recency"), Order::Asc)
+            .to_owned();
+
+        let cycles = ride_cycle::Model::find_by_statement(db.get_database_backend().build(&query))
+            .all(db)
+            .await
+            .map_err(|err| format!("Database error: {}", err))?;
+
+        let mut by_ride: HashMap<String, Vec<ride_cycle::Model>> = HashMap::new();
+        for cycle in cycles {
+            by_ride.entry(cycle.ride_id.clone()).or_default().push(cycle);
+        }
+        Ok(by_ride)
+    }
+
+    // For a ride's recent dispatches as returned by `recent_cycles`.
+    pub fn from_recent_cycles(capacity: i32, cycle_duration: i32, cycles: &[ride_cycle::Model]) -> Self {
+        Self::from_history(capacity, cycle_duration, cycles, Utc::now())
+    }
+
+    // Uses the ride's configured cycle duration until at least two dispatches have been
+    // observed, then the average gap between recent dispatches.
+    fn from_history(capacity: i32, cycle_duration: i32, cycles: &[ride_cycle::Model], now: DateTime<Utc>) -> Self {
+        let configured_seconds = i64::from(cycle_duration.max(1));
+
+        let dispatched: Vec<DateTime<Utc>> = cycles
+            .iter()
+            .filter_map(|cycle| DateTime::parse_from_rfc3339(&cycle.dispatched_at).ok())
+            .map(|at| at.with_timezone(&Utc))
+            .collect();
+
+        let gaps: Vec<i64> = dispatched
+            .windows(2)
+            .map(|pair| (pair[0] - pair[1]).num_seconds())
+            .filter(|gap| *gap > 0 && *gap <= configured_seconds * MAX_GAP_CYCLES)
+            .collect();
+
+        let cycle_seconds = if gaps.is_empty() {
+            configured_seconds
+        } else {
+            gaps.iter().sum::<i64>() / gaps.len() as i64
+        };
+
+        let next_dispatch_in = dispatched.first().map_or(0, |last| {
+            (cycle_seconds - (now - *last).num_seconds()).clamp(0, cycle_seconds)
+        });
+
+        WaitEstimator {
+            riders_per_cycle: i64::from(capacity.max(1)),
+            cycle_seconds,
+            next_dispatch_in,
+        }
+    }
+
+    // Seconds until a party with `seats_ahead` seats queued in front of it is expected to board.
+    pub fn wait_for_seats_ahead(&self, seats_ahead: i64) -> i64 {
+        let cycles_ahead = seats_ahead.max(0) / self.riders_per_cycle;
+        self.next_dispatch_in + cycles_ahead * self.cycle_seconds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(value: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(value).unwrap().with_timezone(&Utc)
+    }
+
+    // Newest first, as `for_ride` loads them.
+    fn cycles(dispatched_at: &[&str]) -> Vec<ride_cycle::Model> {
+        dispatched_at
+            .iter()
+            .enumerate()
+            .map(|(i, at)| ride_cycle::Model {
+                cycle_id: format!("RC-{}", i),
+                ride_id: "R-1".to_string(),
+                staff_id: "S-1".to_string(),
+                rider_count: 20,
+                dispatched_at: at.to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn uses_the_configured_cycle_without_history() {
+        let estimator = WaitEstimator::from_history(20, 300, &[], at("2026-10-18T10:00:00Z"));
+
+        assert_eq!(estimator.wait_for_seats_ahead(0), 0);
+        assert_eq!(estimator.wait_for_seats_ahead(19), 0);
+        assert_eq!(estimator.wait_for_seats_ahead(45), 600);
+    }
+
+    #[test]
+    fn averages_the_gaps_between_recent_dispatches() {
+        let history = cycles(&["2026-10-18T10:04:00Z", "2026-10-18T10:02:00Z", "2026-10-18T10:00:00Z"]);
+        let estimator = WaitEstimator::from_history(20, 300, &history, at("2026-10-18T10:05:00Z"));
+
+        // 120 second cycles, the last of which left a minute ago.
+        assert_eq!(estimator.wait_for_seats_ahead(0), 60);
+        assert_eq!(estimator.wait_for_seats_ahead(40), 300);
+    }
+
+    #[test]
+    fn leaves_idle_gaps_out_of_the_average() {
+        let history = cycles(&["2026-10-18T12:02:00Z", "2026-10-18T12:00:00Z", "2026-10-18T10:00:00Z"]);
+        let estimator = WaitEstimator::from_history(20, 300, &history, at("2026-10-18T12:02:00Z"));
+
+        assert_eq!(estimator.wait_for_seats_ahead(20), 240);
+    }
+
+    #[test]
+    fn next_dispatch_is_never_overdue() {
+        let history = cycles(&["2026-10-18T10:00:00Z"]);
+        let estimator = WaitEstimator::from_history(20, 300, &history, at("2026-10-18T11:00:00Z"));
+
+        assert_eq!(estimator.wait_for_seats_ahead(0), 0);
+        assert_eq!(estimator.wait_for_seats_ahead(20), 300);
+    }
+}
@@ -0,0 +1,177 @@
+use anyhow::Result;
+use chrono::Utc;
+use sea_orm::{
+    sea_query::Alias, ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, ActiveValue::Set,
+};
+use serde::Serialize;
+use tauri::State;
+use uuid::Uuid;
+use crate::{ApiResponse, AppState};
+use crate::controllers::session_handler::authorize;
+use entity::sea_orm_active_enums::WalletTransactionKind;
+use entity::user::{self, Entity as User};
+use entity::wallet_transaction::{self, Entity as WalletTransaction};
+
+// Balances are stored in minor units (cents); the frontend still works in major units.
+pub fn to_minor_units(amount: f64) -> i64 {
+    (amount * 100.0).round() as i64
+}
+
+pub fn to_major_units(amount: i64) -> f64 {
+    amount as f64 / 100.0
+}
+
+// Appends a ledger entry and moves the stored balance with it. Must be called inside a
+// transaction so the user row lock is held until the caller commits.
+pub async fn post_wallet_entry<C: ConnectionTrait>(
+    db: &C,
+    user_id: &str,
+    kind: WalletTransactionKind,
+    amount: i64,
+    reference_id: Option<String>,
+    description: String,
+) -> Result<(user::Model, wallet_transaction::Model), String> {
+    let user = User::find()
+        .filter(user::Column::UserId.eq(user_id))
+        .lock_exclusive()
+        .one(db)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .ok_or_else(|| "User not found".to_string())?;
+
+    let balance_after = user.balance + amount;
+    if balance_after < 0 {
+        return Err("Insufficient balance".to_string());
+    }
+
+    let mut active_user: user::ActiveModel = user.into();
+    active_user.balance = Set(balance_after);
+    let updated_user = active_user
+        .update(db)
+        .await
+        .map_err(|e| format!("Failed to update balance: {}", e))?;
+
+    let entry = wallet_transaction::ActiveModel {
+        transaction_id: Set(format!("WTX-{}", Uuid::new_v4())),
+        user_id: Set(user_id.to_string()),
+        kind: Set(kind),
+        amount: Set(amount),
+        balance_after: Set(balance_after),
+        reference_id: Set(reference_id),
+        description: Set(description),
+        created_at: Set(Utc::now().to_rfc3339()),
+    }
+    .insert(db)
+    .await
+    .map_err(|e| format!("Failed to record wallet transaction: {}", e))?;
+
+    Ok((updated_user, entry))
+}
+
+#[derive(Serialize)]
+pub struct WalletTransactionResponse {
+    pub transaction_id: String,
+    pub kind: WalletTransactionKind,
+    pub amount: f64,
+    pub balance_after: f64,
+    pub reference_id: Option<String>,
+    pub description: String,
+    pub created_at: String,
+}
+
+#[tauri::command]
+pub async fn view_wallet_transactions(
+    state: State<'_, AppState>,
+    session_token: String,
+    user_id: String,
+) -> Result<ApiResponse<Vec<WalletTransactionResponse>>, String> {
+    let session = match authorize(&state, &session_token, "view_wallet_transactions").await {
+        Ok(session) => session,
+        Err(message) => return Ok(ApiResponse::error(message)),
+    };
+
+    if !session.can_act_for(&user_id) {
+        return Ok(ApiResponse::error("Customers can only view their own transactions".to_string()));
+    }
+
+    match WalletTransaction::find()
+        .filter(wallet_transaction::Column::UserId.eq(&user_id))
+        .order_by_desc(wallet_transaction::Column::CreatedAt)
+        .all(&state.db)
+        .await
+    {
+        Ok(entries) => {
+            let response = entries
+                .into_iter()
+                .map(|entry| WalletTransactionResponse {
+                    transaction_id: entry.transaction_id,
+                    kind: entry.kind,
+                    amount: to_major_units(entry.amount),
+                    balance_after: to_major_units(entry.balance_after),
+                    reference_id: entry.reference_id,
+                    description: entry.description,
+                    created_at: entry.created_at,
+                })
+                .collect();
+            Ok(ApiResponse::success(response))
+        }
+        Err(err) => Ok(ApiResponse::error(format!("Database error: {}", err))),
+    }
+}
+
+#[derive(Serialize)]
+pub struct WalletDiscrepancy {
+    pub user_id: String,
+    pub stored_balance: f64,
+    pub ledger_balance: f64,
+}
+
+// Lists every user whose stored balance no longer matches the sum of their ledger.
+#[tauri::command]
+pub async fn reconcile_wallets(
+    state: State<'_, AppState>,
+    session_token: String,
+) -> Result<ApiResponse<Vec<WalletDiscrepancy>>, String> {
+    if let Err(message) = authorize(&state, &session_token, "reconcile_wallets").await {
+        return Ok(ApiResponse::error(message));
+    }
+
+    let users = match User::find().all(&state.db).await {
+        Ok(users) => users,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    let totals: Vec<(String, Option<i64>)> = match WalletTransaction::find()
+        .select_only()
+        .column(wallet_transaction::Column::UserId)
+        // Postgres widens SUM(bigint) to numeric, so narrow it back for decoding.
+        .column_as(wallet_transaction::Column::Amount.sum().cast_as(Alias::new("bigint")), "total")
+        .group_by(wallet_transaction::Column::UserId)
+        .into_tuple()
+        .all(&state.db)
+        .await
+    {
+        Ok(totals) => totals,
+        Err(err) => return Ok(ApiResponse::error(format!("Database error: {}", err))),
+    };
+
+    let discrepancies = users
+        .into_iter()
+        .filter_map(|user| {
+            let ledger_balance = totals
+                .iter()
+                .find(|(user_id, _)| *user_id == user.user_id)
+                .and_then(|(_, total)| *total)
+                .unwrap_or(0);
+
+            (ledger_balance != user.balance).then(|| WalletDiscrepancy {
+                user_id: user.user_id,
+                stored_balance: to_major_units(user.balance),
+                ledger_balance: to_major_units(ledger_balance),
+            })
+        })
+        .collect();
+
+    Ok(ApiResponse::success(discrepancies))
+}
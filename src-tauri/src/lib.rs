@@ -12,29 +12,38 @@ use firestore::{FirestoreDb, FirestoreDbOptions};
 use tokio::task::JoinHandle;
 
 use controllers::restaurant_handler::{view_all_restaurants, create_restaurant, delete_restaurant, update_restaurant};
-use controllers::ride_handler::{view_all_rides, view_ride, create_ride, update_ride, delete_ride};
+use controllers::ride_handler::{view_all_rides, view_ride, create_ride, update_ride, change_ride_status, view_ride_status_history, delete_ride};
 use controllers::menu_handler::{update_menu_quantity, view_all_menus, view_menu, create_menu, update_menu, delete_menu};
-use controllers::queue_handler::{view_all_queues, create_queue, edit_queue, delete_queue, get_queues_by_ride};
-use controllers::user_handler::{get_user_by_id, get_all_users, get_all_ride_staff, get_all_users_lite, get_user_lite_by_id, login_user, staff_login, get_balance, top_up_balance, get_notifications, create_customer};
+use controllers::queue_handler::{view_all_queues, create_queue, edit_queue, delete_queue, get_queues_by_ride, dispatch_ride_cycle, QueuePolicy};
+use controllers::user_handler::{get_user_by_id, get_all_users, get_all_ride_staff, get_all_users_lite, get_user_lite_by_id, login_user, staff_login, get_balance, top_up_balance, get_notifications, create_customer, update_rider_profile, change_password, reset_staff_password};
 use controllers::notification_handler::{view_notification, mark_all_notifications_read, send_notification};
 use controllers::store_handler::{view_all_stores, create_store, update_store, delete_store};
 use controllers::souvenir_handler::{view_all_souvenirs, view_souvenir};
-use controllers::order_handler::{update_order_status, checkout_order, view_all_orders, view_orders, create_order, update_order, delete_order};
+use controllers::order_handler::{update_order_status, view_order_status_history, view_kitchen_queue, bump_kitchen_ticket, recall_kitchen_ticket, checkout_order, refund_store_order, view_all_orders, view_orders, create_order, update_order, delete_order};
 use controllers::lost_and_found_handler::{view_lost_and_found_items, create_lost_item, update_lost_item, delete_lost_item};
 use controllers::chat_handler::{send_group_message, fetch_group_info, get_all_groups, listen_to_group_chat, fetch_group_chat_messages};
 use controllers::official_chat_handler::{fetch_official_chat_customers, fetch_official_chat_messages, send_official_chat_message, listen_to_official_chat};
 use controllers::official_chat_maintenance_handler::{fetch_maintenance_chat_ride_managers, listen_to_maintenance_chat, fetch_maintenance_chat_messages, send_maintenance_chat_message};
 use controllers::broadcast_handler::send_broadcast_message;
 use controllers::ride_manager_handler::{assign_ride_staff, create_maintenance_request};
-use controllers::proposal_handler::{create_proposal, view_all_proposal};
-use controllers::maintenance_handler::view_all_maintenance;
+use controllers::proposal_handler::{create_proposal, view_all_proposal, review_proposal};
+use controllers::maintenance_handler::{view_all_maintenance, view_maintenance_staff_workload, assign_maintenance_staff, start_maintenance, pause_maintenance, complete_maintenance, view_maintenance_notes};
+use controllers::maintenance_plan_handler::{view_maintenance_plans, create_maintenance_plan, update_maintenance_plan, delete_maintenance_plan, view_maintenance_calendar, spawn_maintenance_scheduler};
+use controllers::checklist_handler::{view_checklist_templates, create_checklist_template, set_checklist_template_active, view_maintenance_checklist, record_checklist_result, view_checklist_progress};
+use controllers::spare_part_handler::{view_spare_parts, create_spare_part, update_spare_part, restock_spare_part, record_part_usage, view_maintenance_parts};
+use controllers::maintenance_sla_handler::{view_sla_policies, save_sla_policy, delete_sla_policy};
 use controllers::fnb_supervisor_handler::assign_restaurant_staff;
+use controllers::session_handler::logout;
+use controllers::wallet_handler::{view_wallet_transactions, reconcile_wallets};
+use controllers::fast_pass_handler::{view_fast_pass_slots, view_fast_passes, book_fast_pass, cancel_fast_pass, redeem_fast_pass};
+use controllers::ride_availability_handler::{view_ride_availability_report, export_ride_availability_report};
 
 pub struct AppState {
     pub db: DatabaseConnection,
     pub redis_pool: RedisPool,
     pub firestore: Arc<Mutex<Option<FirestoreDb>>>,
     pub listener: Arc<Mutex<Option<JoinHandle<()>>>>,
+    pub queue_policy: QueuePolicy,
 }
 
 async fn init_firestore() -> Result<FirestoreDb, String> {
@@ -125,6 +134,88 @@ async fn cache_delete(pool: &RedisPool, key: &str) {
     }
 }
 
+// Overwrites a key only if it still exists, keeping its remaining TTL. Returns whether it was written.
+async fn cache_replace<T: Serialize>(pool: &RedisPool, key: &str, value: &T) -> bool {
+    let mut conn = match pool.get().await {
+        Ok(conn) => conn,
+        Err(err) => {
+            eprintln!("Redis error (cache_replace - get connection): {}", err);
+            return false;
+        }
+    };
+
+    let json_value = match serde_json::to_string(&CachedData { data: value }) {
+        Ok(json) => json,
+        Err(err) => {
+            eprintln!("Redis error (cache_replace - serialization): {}", err);
+            return false;
+        }
+    };
+
+    match cmd("SET")
+        .arg(&[key, &json_value, "XX", "KEEPTTL"])
+        .query_async::<Option<String>>(&mut conn)
+        .await
+    {
+        Ok(written) => written.is_some(),
+        Err(err) => {
+            eprintln!("Redis error (cache_replace - SET): {}", err);
+            false
+        }
+    }
+}
+
+async fn cache_set_add(pool: &RedisPool, key: &str, member: &str, ttl: usize) {
+    let mut conn = match pool.get().await {
+        Ok(conn) => conn,
+        Err(err) => {
+            eprintln!("Redis error (cache_set_add - get connection): {}", err);
+            return;
+        }
+    };
+
+    if let Err(err) = cmd("SADD").arg(key).arg(member).query_async::<()>(&mut conn).await {
+        eprintln!("Redis error (cache_set_add - SADD): {}", err);
+        return;
+    }
+
+    if let Err(err) = cmd("EXPIRE").arg(key).arg(ttl).query_async::<()>(&mut conn).await {
+        eprintln!("Redis error (cache_set_add - EXPIRE): {}", err);
+    }
+}
+
+async fn cache_set_remove(pool: &RedisPool, key: &str, member: &str) {
+    let mut conn = match pool.get().await {
+        Ok(conn) => conn,
+        Err(err) => {
+            eprintln!("Redis error (cache_set_remove - get connection): {}", err);
+            return;
+        }
+    };
+
+    if let Err(err) = cmd("SREM").arg(key).arg(member).query_async::<()>(&mut conn).await {
+        eprintln!("Redis error (cache_set_remove - SREM): {}", err);
+    }
+}
+
+async fn cache_set_members(pool: &RedisPool, key: &str) -> Vec<String> {
+    let mut conn = match pool.get().await {
+        Ok(conn) => conn,
+        Err(err) => {
+            eprintln!("Redis error (cache_set_members - get connection): {}", err);
+            return Vec::new();
+        }
+    };
+
+    match cmd("SMEMBERS").arg(key).query_async(&mut conn).await {
+        Ok(members) => members,
+        Err(err) => {
+            eprintln!("Redis error (cache_set_members - SMEMBERS): {}", err);
+            Vec::new()
+        }
+    }
+}
+
 async fn cache_get<T: for<'de> Deserialize<'de>>(pool: &RedisPool, key: &str) -> Option<T> {
     let mut conn = match pool.get().await {
         Ok(conn) => conn,
@@ -155,6 +246,81 @@ async fn cache_get<T: for<'de> Deserialize<'de>>(pool: &RedisPool, key: &str) ->
     }
 }
 
+// Tag sets outlive the keys they index so a tag is never dropped before its longest-lived key.
+const CACHE_TAG_TTL_SECONDS: usize = 24 * 60 * 60;
+
+fn cache_tag_key(tag: &str) -> String {
+    format!("cache_tag_{}", tag)
+}
+
+// Caches a value and records its key under every tag it was derived from. List caches are
+// tagged with the entity name (e.g. "menu") and single-row caches with "entity:id".
+async fn cache_set_tagged<T: Serialize>(pool: &RedisPool, key: &str, value: &T, ttl: usize, tags: &[&str]) {
+    cache_set(pool, key, value, ttl).await;
+
+    let mut conn = match pool.get().await {
+        Ok(conn) => conn,
+        Err(err) => {
+            eprintln!("Redis error (cache_set_tagged - get connection): {}", err);
+            return;
+        }
+    };
+
+    for tag in tags {
+        let tag_key = cache_tag_key(tag);
+
+        if let Err(err) = cmd("SADD").arg(&tag_key).arg(key).query_async::<()>(&mut conn).await {
+            eprintln!("Redis error (cache_set_tagged - SADD): {}", err);
+            continue;
+        }
+
+        if let Err(err) = cmd("EXPIRE")
+            .arg(&tag_key)
+            .arg(CACHE_TAG_TTL_SECONDS)
+            .query_async::<()>(&mut conn)
+            .await
+        {
+            eprintln!("Redis error (cache_set_tagged - EXPIRE): {}", err);
+        }
+    }
+}
+
+// Drops every cached key recorded under the given tags. Handlers call this after any write
+// so derived caches never outlive the rows they were built from.
+async fn cache_invalidate(pool: &RedisPool, tags: &[&str]) {
+    let mut conn = match pool.get().await {
+        Ok(conn) => conn,
+        Err(err) => {
+            eprintln!("Redis error (cache_invalidate - get connection): {}", err);
+            return;
+        }
+    };
+
+    for tag in tags {
+        let tag_key = cache_tag_key(tag);
+
+        let mut keys: Vec<String> = match cmd("SMEMBERS").arg(&tag_key).query_async(&mut conn).await {
+            Ok(keys) => keys,
+            Err(err) => {
+                eprintln!("Redis error (cache_invalidate - SMEMBERS): {}", err);
+                continue;
+            }
+        };
+        keys.push(tag_key);
+
+        if let Err(err) = cmd("DEL").arg(keys).query_async::<()>(&mut conn).await {
+            eprintln!("Redis error (cache_invalidate - DEL): {}", err);
+        }
+    }
+}
+
+// Listing commands page with a caller-chosen limit, capped so one call can't pull a whole table.
+const MAX_PAGE_SIZE: u64 = 200;
+
+fn page_limit(limit: Option<u64>) -> u64 {
+    limit.unwrap_or(MAX_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE)
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub async fn run() {
     dotenv().ok();
@@ -168,6 +334,9 @@ pub async fn run() {
     // Initialize Redis
     let redis_pool = init_redis().await.expect("Failed to initialize Redis");
 
+    // Raise planned maintenance as it comes due
+    spawn_maintenance_scheduler(db.clone(), redis_pool.clone());
+
     // Initialize Firestore
     let firestore = init_firestore()
         .await
@@ -179,6 +348,7 @@ pub async fn run() {
         redis_pool,
         firestore: Arc::new(Mutex::new(Some(firestore))),
         listener: Arc::new(Mutex::new(None)),
+        queue_policy: QueuePolicy::from_env(),
     };
 
     tauri::Builder::default()
@@ -202,12 +372,20 @@ pub async fn run() {
             assign_ride_staff,
             create_ride,
             update_ride,
+            change_ride_status,
+            view_ride_status_history,
             delete_ride,
             view_all_queues,
             create_queue,
             edit_queue,
             delete_queue,
             get_queues_by_ride,
+            dispatch_ride_cycle,
+            view_fast_pass_slots,
+            view_fast_passes,
+            book_fast_pass,
+            cancel_fast_pass,
+            redeem_fast_pass,
             get_all_users,
             get_all_users_lite,
             get_all_ride_staff,
@@ -215,8 +393,14 @@ pub async fn run() {
             create_customer,
             login_user,
             staff_login,
+            logout,
+            change_password,
+            reset_staff_password,
+            update_rider_profile,
             get_balance,
             top_up_balance,
+            view_wallet_transactions,
+            reconcile_wallets,
             get_notifications,
             view_notification,
             send_notification,
@@ -248,16 +432,50 @@ pub async fn run() {
             send_broadcast_message,
             create_proposal,
             view_all_proposal,
+            review_proposal,
             create_maintenance_request,
             view_all_maintenance,
+            view_maintenance_staff_workload,
+            assign_maintenance_staff,
+            start_maintenance,
+            pause_maintenance,
+            complete_maintenance,
+            view_maintenance_notes,
+            view_maintenance_plans,
+            create_maintenance_plan,
+            update_maintenance_plan,
+            delete_maintenance_plan,
+            view_maintenance_calendar,
+            view_checklist_templates,
+            create_checklist_template,
+            set_checklist_template_active,
+            view_maintenance_checklist,
+            record_checklist_result,
+            view_checklist_progress,
+            view_spare_parts,
+            create_spare_part,
+            update_spare_part,
+            restock_spare_part,
+            record_part_usage,
+            view_maintenance_parts,
+            view_sla_policies,
+            save_sla_policy,
+            delete_sla_policy,
+            view_ride_availability_report,
+            export_ride_availability_report,
             fetch_maintenance_chat_ride_managers,
             listen_to_maintenance_chat,
             fetch_maintenance_chat_messages,
             send_maintenance_chat_message,
             assign_restaurant_staff,
             checkout_order,
+            refund_store_order,
             update_menu_quantity,
             update_order_status,
+            view_order_status_history,
+            view_kitchen_queue,
+            bump_kitchen_ticket,
+            recall_kitchen_ticket,
             get_user_by_id,
         ])
         .run(tauri::generate_context!())
@@ -1,6 +1,6 @@
 import type React from "react";
 import { useState } from "react";
-import { invoke } from "@tauri-apps/api/core";
+import { invoke } from "@/lib/session";
 import { Button } from "@/components/ui/button";
 import {
   Dialog,
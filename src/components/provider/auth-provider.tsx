@@ -8,7 +8,7 @@ import {
   type ReactNode,
 } from "react";
 import { useToast } from "@/hooks/use-toast";
-import { invoke } from "@tauri-apps/api/core";
+import { invoke, setSessionToken } from "@/lib/session";
 
 type AuthContextType = {
   user: User | null;
@@ -78,18 +78,20 @@ export function AuthProvider({ children }: { children: ReactNode }) {
       console.log("Trying login");
       const result = await invoke<{
         success: boolean;
-        data?: User;
+        data?: User & { session_token: string };
         message?: string;
       }>("login_user", { userId: uid });
 
       console.log("login result : ", result);
       if (result.data) {
-        setUser(result.data);
-        localStorage.setItem("vortekia-user", JSON.stringify(result.data));
+        const { session_token, ...loggedInUser } = result.data;
+        setSessionToken(session_token);
+        setUser(loggedInUser);
+        localStorage.setItem("vortekia-user", JSON.stringify(loggedInUser));
 
         toast({
           title: "Login successful",
-          description: `Welcome back, ${result.data.name}!`,
+          description: `Welcome back, ${loggedInUser.name}!`,
         });
 
         return true;
@@ -113,6 +115,8 @@ export function AuthProvider({ children }: { children: ReactNode }) {
   };
 
   const logout = () => {
+    invoke("logout").catch((error) => console.error("Logout error:", error));
+    setSessionToken(null);
     setUser(null);
     setNotifications([]);
     localStorage.removeItem("vortekia-user");
@@ -132,7 +136,7 @@ export function AuthProvider({ children }: { children: ReactNode }) {
         data?: { balance: number };
       }>("get_balance", { userId: user.user_id });
 
-      if (result.success && result.data) {
+      if (result.data) {
         setUser((prev) =>
           prev ? { ...prev, balance: result.data!.balance } : null
         );
@@ -1,6 +1,6 @@
 import { useState, useEffect } from "react";
 import { useParams, useNavigate } from "react-router";
-import { invoke } from "@tauri-apps/api/core";
+import { invoke } from "@/lib/session";
 import {
   Card,
   CardContent,
@@ -1,4 +1,4 @@
-import { invoke } from "@tauri-apps/api/core";
+import { invoke } from "@/lib/session";
 import { useEffect, useState } from "react";
 import { useNavigate, useParams } from "react-router";
 import { Button } from "@/components/ui/button";
@@ -29,9 +29,7 @@ interface CreateOrderPayload {
   order_id: string;
   customer_id: string;
   item_type: string;
-  item_id: string;
-  quantity: number;
-  is_paid: boolean;
+  lines: { item_id: string; quantity: number }[];
 }
 
 interface Menu {
@@ -113,24 +111,22 @@ export default function MenuSection() {
       const orderId = `ORD_${Date.now()}`;
       console.log("Creating order...");
 
-      await invoke("update_menu_quantity", {
-        payload: {
-          menu_id: selectedMenu.menu_id,
-          available_quantity: selectedMenu.available_quantity - quantity,
-        },
-      });
+      // Stock is taken out when the order is checked out, not when it is added to the cart.
+      const response = await invoke<{ status: string; message?: string }>(
+        "create_order",
+        {
+          payload: {
+            order_id: orderId,
+            customer_id: user.user_id,
+            item_type: "restaurant",
+            lines: [{ item_id: selectedMenu.menu_id, quantity }],
+          } as CreateOrderPayload,
+        }
+      );
 
-      await invoke("create_order", {
-        payload: {
-          order_id: orderId,
-          customer_id: user.user_id,
-          item_type: "restaurant",
-          item_id: selectedMenu.menu_id,
-          quantity: quantity,
-          date: new Date().toISOString(),
-          is_paid: false,
-        } as CreateOrderPayload,
-      });
+      if (response.status === "error") {
+        throw new Error(response.message);
+      }
 
       console.log("Order created successfully!");
 
@@ -11,63 +11,56 @@ import { Button } from "@/components/ui/button";
 import { Badge } from "@/components/ui/badge";
 import { Input } from "@/components/ui/input";
 import { Search } from "lucide-react";
-import { invoke } from "@tauri-apps/api/core";
+import { invoke } from "@/lib/session";
 
+// Values match `OrderStatus` on the backend.
 export type OrderStatus =
-  | "Waiting for Cooking"
-  | "Waiting for Delivery"
-  | "Delivered";
+  | "Placed"
+  | "Cooking"
+  | "Ready"
+  | "Served"
+  | "Picked Up"
+  | "Cancelled";
 
-interface MenuItem {
-  menu_id: string;
-  restaurant_id: string;
-  name: string;
-  image: string;
-  description: string;
-  price: number;
-  available_quantity: number;
-}
-
-interface Order {
+export interface Order {
   order_id: string;
-  item_id: string;
   customer_id: string;
-  quantity: number;
+  restaurant_id?: string;
+  lines: { line_id: string; item_name: string; quantity: number }[];
   status: OrderStatus;
 }
 
 interface RestaurantOrderProps {
   orders: Order[];
-  menus: MenuItem[];
   userRole: "chef" | "waiter";
   onUpdateStatus: (orderId: string, newStatus: OrderStatus) => void;
 }
 
 export function RestaurantOrder({
   orders,
-  menus,
   userRole,
   onUpdateStatus,
 }: RestaurantOrderProps) {
   const [searchTerm, setSearchTerm] = useState("");
 
+  const itemNames = (order: Order) =>
+    order.lines.map((line) => `${line.quantity}x ${line.item_name}`).join(", ");
+
   const filteredOrders = orders.filter((order) => {
-    const menu = menus.find((menu) => menu.menu_id === order.item_id);
     return (
-      menu?.name.toLowerCase().includes(searchTerm.toLowerCase()) ||
+      itemNames(order).toLowerCase().includes(searchTerm.toLowerCase()) ||
       order.customer_id.toLowerCase().includes(searchTerm.toLowerCase()) ||
       order.order_id.toString().includes(searchTerm)
     );
   });
 
   const getNextStatus = (currentStatus: string): OrderStatus | null => {
-    if (userRole === "chef" && currentStatus === "Waiting for Cooking") {
-      return "Waiting for Delivery";
-    } else if (
-      userRole === "waiter" &&
-      currentStatus === "Waiting for Delivery"
-    ) {
-      return "Delivered";
+    if (userRole === "chef" && currentStatus === "Placed") {
+      return "Cooking";
+    } else if (userRole === "chef" && currentStatus === "Cooking") {
+      return "Ready";
+    } else if (userRole === "waiter" && currentStatus === "Ready") {
+      return "Served";
     }
     return null;
   };
@@ -77,24 +70,31 @@ export function RestaurantOrder({
     if (nextStatus) {
       try {
         // Update status di backend
-        await invoke("update_order_status", {
-          payload: {
-            order_id: orderId,
-            status: nextStatus,
-          },
-        });
+        const response = await invoke<{ status: string; message?: string }>(
+          "update_order_status",
+          {
+            payload: {
+              order_id: orderId,
+              status: nextStatus,
+            },
+          }
+        );
+
+        if (response.status === "error") {
+          throw new Error(response.message);
+        }
 
         // Panggil callback untuk update state di parent component
         onUpdateStatus(orderId, nextStatus);
 
-        // Jika status menjadi "Delivered", kirim notifikasi ke pelanggan
-        if (nextStatus === "Delivered") {
+        // Jika status menjadi "Served", kirim notifikasi ke pelanggan
+        if (nextStatus === "Served") {
           const order = orders.find((o) => o.order_id === orderId);
           if (order) {
             await invoke("send_notification", {
               recipientId: order.customer_id,
-              title: "Order Delivered",
-              message: `Your order ${orderId} has been delivered.`,
+              title: "Order Served",
+              message: `Your order ${orderId} has been served.`,
               notifType: "Restaurant Order",
             });
           }
@@ -110,11 +110,13 @@ export function RestaurantOrder({
 
   const getStatusBadgeVariant = (status: OrderStatus) => {
     switch (status) {
-      case "Waiting for Cooking":
+      case "Placed":
         return "destructive";
-      case "Waiting for Delivery":
+      case "Cooking":
+      case "Ready":
         return "warning";
-      case "Delivered":
+      case "Served":
+      case "Picked Up":
         return "success";
       default:
         return "secondary";
@@ -156,9 +158,6 @@ export function RestaurantOrder({
               </TableRow>
             ) : (
               filteredOrders.map((order) => {
-                const menu = menus.find(
-                  (menu) => menu.menu_id === order.item_id
-                );
                 const nextStatus = getNextStatus(order.status);
                 const canUpdateStatus = nextStatus !== null;
 
@@ -167,9 +166,14 @@ export function RestaurantOrder({
                     <TableCell className="font-medium">
                       {order.order_id}
                     </TableCell>
-                    <TableCell>{menu?.name || "Unknown"}</TableCell>
+                    <TableCell>{itemNames(order)}</TableCell>
                     <TableCell>{order.customer_id}</TableCell>
-                    <TableCell>{order.quantity}</TableCell>
+                    <TableCell>
+                      {order.lines.reduce(
+                        (count, line) => count + line.quantity,
+                        0
+                      )}
+                    </TableCell>
                     <TableCell>
                       <Badge
                         variant={
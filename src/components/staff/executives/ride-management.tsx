@@ -31,6 +31,7 @@ import { Search, Plus, Edit, Trash2, Upload, Camera, Info } from "lucide-react";
 export type RideStatus =
   | "Operational"
   | "Maintenance"
+  | "Weather Hold"
   | "Closed"
   | "Under Construction";
 export type ThrillLevel = "Low" | "Moderate" | "High" | "Extreme";
@@ -231,6 +232,7 @@ export function RideManagement({
       case "Operational":
         return "success";
       case "Maintenance":
+      case "Weather Hold":
         return "warning";
       case "Closed":
         return "destructive";
@@ -593,6 +595,7 @@ export function RideManagement({
                     >
                       <option value="Operational">Operational</option>
                       <option value="Maintenance">Maintenance</option>
+                      <option value="Weather Hold">Weather Hold</option>
                       <option value="Closed">Closed</option>
                       <option value="Under Construction">
                         Under Construction
@@ -897,6 +900,7 @@ export function RideManagement({
                     >
                       <option value="Operational">Operational</option>
                       <option value="Maintenance">Maintenance</option>
+                      <option value="Weather Hold">Weather Hold</option>
                       <option value="Closed">Closed</option>
                       <option value="Under Construction">
                         Under Construction
@@ -28,10 +28,11 @@ interface Order {
   order_id: string;
   customer_id: string;
   item_type: string;
-  item_id: string;
   date: string;
-  quantity: number;
   is_paid: boolean;
+  restaurant_id?: string;
+  lines: { item_id: string; quantity: number; subtotal: number }[];
+  total: number;
 }
 
 interface Menu {
@@ -111,8 +112,6 @@ export function FinancialOverview({
         order.is_paid
     );
 
-    const menuMap = new Map(menus.map((menu) => [menu.menu_id, menu]));
-
     const restaurantData: {
       [key: string]: {
         totalIncome: number;
@@ -122,30 +121,32 @@ export function FinancialOverview({
     } = {};
 
     todayOrders.forEach((order) => {
-      const menu = menuMap.get(order.item_id);
-      if (menu) {
-        const revenue = menu.price * order.quantity;
-        if (!restaurantData[menu.restaurant_id]) {
-          restaurantData[menu.restaurant_id] = {
+      const restaurantId = order.restaurant_id;
+      if (restaurantId) {
+        if (!restaurantData[restaurantId]) {
+          restaurantData[restaurantId] = {
             totalIncome: 0,
             mealsSold: 0,
             orderCount: 0,
           };
         }
-        restaurantData[menu.restaurant_id].totalIncome += revenue;
-        restaurantData[menu.restaurant_id].mealsSold += order.quantity;
-        restaurantData[menu.restaurant_id].orderCount += 1;
+        restaurantData[restaurantId].totalIncome += order.total;
+        restaurantData[restaurantId].mealsSold += order.lines.reduce(
+          (count, line) => count + line.quantity,
+          0
+        );
+        restaurantData[restaurantId].orderCount += 1;
       }
     });
 
     const yesterdayData: { [key: string]: number } = {};
     yesterdayOrders.forEach((order) => {
-      const menu = menuMap.get(order.item_id);
-      if (menu) {
-        if (!yesterdayData[menu.restaurant_id]) {
-          yesterdayData[menu.restaurant_id] = 0;
+      const restaurantId = order.restaurant_id;
+      if (restaurantId) {
+        if (!yesterdayData[restaurantId]) {
+          yesterdayData[restaurantId] = 0;
         }
-        yesterdayData[menu.restaurant_id] += menu.price * order.quantity;
+        yesterdayData[restaurantId] += order.total;
       }
     });
 
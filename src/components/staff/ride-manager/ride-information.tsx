@@ -152,6 +152,7 @@ export function RideInformation({
       case "Operational":
         return "success";
       case "Maintenance":
+      case "Weather Hold":
         return "warning";
       case "Closed":
         return "destructive";
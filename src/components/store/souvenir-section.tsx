@@ -1,4 +1,4 @@
-import { invoke } from "@tauri-apps/api/core";
+import { invoke } from "@/lib/session";
 import { useEffect, useState } from "react";
 import { useNavigate, useParams } from "react-router";
 import { Button } from "@/components/ui/button";
@@ -38,9 +38,7 @@ interface CreateOrderPayload {
   order_id: string;
   customer_id: string;
   item_type: string;
-  item_id: string;
-  quantity: number;
-  is_paid: boolean;
+  lines: { item_id: string; quantity: number }[];
 }
 
 export default function SouvenirSection() {
@@ -100,16 +98,21 @@ export default function SouvenirSection() {
       const orderId = `ORD_${Date.now()}`;
       console.log("Creating order...");
 
-      await invoke("create_order", {
-        payload: {
-          order_id: orderId,
-          customer_id: user.user_id,
-          item_type: "store",
-          item_id: selectedSouvenir.souvenir_id,
-          quantity: quantity,
-          is_paid: false,
-        } as CreateOrderPayload,
-      });
+      const response = await invoke<{ status: string; message?: string }>(
+        "create_order",
+        {
+          payload: {
+            order_id: orderId,
+            customer_id: user.user_id,
+            item_type: "store",
+            lines: [{ item_id: selectedSouvenir.souvenir_id, quantity }],
+          } as CreateOrderPayload,
+        }
+      );
+
+      if (response.status === "error") {
+        throw new Error(response.message);
+      }
 
       console.log("Order created successfully!");
 
@@ -2,7 +2,7 @@
 
 import { CardFooter } from "@/components/ui/card";
 
-import { invoke } from "@tauri-apps/api/core";
+import { invoke } from "@/lib/session";
 import { useEffect, useState } from "react";
 import { useNavigate, useParams } from "react-router";
 import { Button } from "@/components/ui/button";
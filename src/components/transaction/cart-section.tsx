@@ -1,5 +1,5 @@
 import { useEffect, useState } from "react";
-import { invoke } from "@tauri-apps/api/core";
+import { invoke } from "@/lib/session";
 import { Button } from "@/components/ui/button";
 import {
   Card,
@@ -18,18 +18,14 @@ interface CartSectionProps {
   pageType: "restaurant" | "store" | "ride";
 }
 
-interface ItemDetails {
-  name: string;
-  price: number;
-  image?: string;
+interface ItemImages {
+  [itemId: string]: string;
 }
 
 export function CartSection({ pageType }: CartSectionProps) {
   const navigate = useNavigate();
   const [cartItems, setCartItems] = useState<Order[]>([]);
-  const [itemDetails, setItemDetails] = useState<{
-    [key: string]: ItemDetails;
-  }>({});
+  const [itemImages, setItemImages] = useState<ItemImages>({});
   const [loading, setLoading] = useState<boolean>(true);
   const user = useAuth();
   const [customerId, setCustomerId] = useState<string | null>(null);
@@ -61,7 +57,7 @@ export function CartSection({ pageType }: CartSectionProps) {
             (order: Order) => !order.is_paid
           );
           setCartItems(unpaidOrders);
-          fetchItemDetails(unpaidOrders);
+          fetchItemImages(unpaidOrders);
         } else {
           setCartItems([]);
         }
@@ -78,45 +74,43 @@ export function CartSection({ pageType }: CartSectionProps) {
     }
   }, [pageType, customerId]);
 
-  async function fetchItemDetails(orders: Order[]) {
-    const newItemDetails: { [key: string]: ItemDetails } = {};
+  async function fetchItemImages(orders: Order[]) {
+    const newItemImages: ItemImages = {};
 
     await Promise.all(
-      orders.map(async (order) => {
-        try {
-          let response;
-          if (order.item_type === "restaurant") {
-            response = await invoke("view_menu", {
-              menuId: order.item_id,
-            });
-          } else if (order.item_type === "store") {
-            response = await invoke("view_souvenir", {
-              souvenirId: order.item_id,
-            });
+      orders.flatMap((order) =>
+        order.lines.map(async (line) => {
+          try {
+            let response;
+            if (order.item_type === "restaurant") {
+              response = await invoke("view_menu", {
+                menuId: line.item_id,
+              });
+            } else if (order.item_type === "store") {
+              response = await invoke("view_souvenir", {
+                souvenirId: line.item_id,
+              });
+            }
+
+            if (response && response.data && response.data.image) {
+              newItemImages[line.item_id] =
+                `data:image/png;base64,${response.data.image}`;
+            }
+          } catch (error) {
+            console.error(
+              `❌ Failed to fetch item details for ${line.item_id}:`,
+              error
+            );
           }
-
-          if (response && response.data) {
-            newItemDetails[order.item_id] = {
-              name: response.data.name,
-              price: response.data.price,
-              image: response.data.image
-                ? `data:image/png;base64,${response.data.image}`
-                : "/placeholder.svg",
-            };
-          }
-        } catch (error) {
-          console.error(
-            `❌ Failed to fetch item details for ${order.item_id}:`,
-            error
-          );
-        }
-      })
+        })
+      )
     );
 
-    setItemDetails(newItemDetails);
+    setItemImages(newItemImages);
   }
 
-  const itemCount = cartItems.reduce((total, item) => total + item.quantity, 0);
+  const cartLines = cartItems.flatMap((order) => order.lines);
+  const itemCount = cartLines.reduce((total, line) => total + line.quantity, 0);
 
   return (
     <section id="cart-summary" className="mt-12">
@@ -146,31 +140,27 @@ export function CartSection({ pageType }: CartSectionProps) {
         <CardContent>
           {loading ? (
             <p>Loading cart...</p>
-          ) : cartItems.length > 0 ? (
+          ) : cartLines.length > 0 ? (
             <div className="space-y-4">
-              {cartItems.map((item) => {
-                const details = itemDetails[item.item_id] || {
-                  name: "Loading...",
-                  price: 0,
-                };
+              {cartLines.map((line) => {
                 return (
-                  <div key={item.order_id} className="flex items-center gap-3">
+                  <div key={line.line_id} className="flex items-center gap-3">
                     <div className="h-16 w-16 rounded-md overflow-hidden flex-shrink-0">
                       <img
-                        src={details.image || "/placeholder.svg"}
-                        alt={details.name}
+                        src={itemImages[line.item_id] || "/placeholder.svg"}
+                        alt={line.item_name}
                         className="h-full w-full object-cover"
                       />
                     </div>
 
                     <div className="flex-1 min-w-0">
-                      <h4 className="font-medium truncate">{details.name}</h4>
+                      <h4 className="font-medium truncate">{line.item_name}</h4>
                       <div className="flex justify-between items-center mt-1">
                         <div className="text-sm text-muted-foreground">
-                          Qty: {item.quantity} × ${details.price.toFixed(2)}
+                          Qty: {line.quantity} × ${line.unit_price.toFixed(2)}
                         </div>
                         <div className="font-bold">
-                          ${(details.price * item.quantity).toFixed(2)}
+                          ${line.subtotal.toFixed(2)}
                         </div>
                       </div>
                     </div>
@@ -10,7 +10,7 @@ import { ShoppingBag, Check } from "lucide-react";
 import { useState } from "react";
 import { Button } from "../ui/button";
 import { useAuth } from "../provider/auth-provider";
-import { invoke } from "@tauri-apps/api/core";
+import { invoke } from "@/lib/session";
 
 interface CheckoutDialogProps {
   totalItems: number;
@@ -35,7 +35,7 @@ export default function CheckoutDialog({
 }: CheckoutDialogProps) {
   const [isProcessing, setIsProcessing] = useState(false);
   const [isComplete, setIsComplete] = useState(false);
-  const { user } = useAuth();
+  const { fetchBalance } = useAuth();
 
   const handleCheckout = async () => {
     setIsProcessing(true);
@@ -43,21 +43,23 @@ export default function CheckoutDialog({
     try {
       const unpaidOrders = orders.filter((order) => !order.is_paid);
 
+      // checkout_order debits the wallet itself, so only the shown balance needs refreshing.
       for (const order of unpaidOrders) {
-        await invoke("checkout_order", {
-          payload: {
-            order_id: order.order_id,
-            is_paid: true,
-          },
-        });
+        const response = await invoke<{ status: string; message?: string }>(
+          "checkout_order",
+          {
+            payload: {
+              order_id: order.order_id,
+            },
+          }
+        );
+
+        if (response.status === "error") {
+          throw new Error(response.message);
+        }
       }
 
-      await invoke("top_up_balance", {
-        payload: {
-          user_id: user?.user_id,
-          amount: -total,
-        },
-      });
+      fetchBalance();
 
       setIsProcessing(false);
       setIsComplete(true);
@@ -68,6 +70,8 @@ export default function CheckoutDialog({
       }, 2000);
     } catch (error) {
       console.error("Error during checkout:", error);
+      // Orders checked out before the failure have still been paid for.
+      fetchBalance();
       setIsProcessing(false);
     }
   };
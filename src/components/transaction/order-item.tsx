@@ -34,33 +34,29 @@ export default function OrderItem({
             <p>Loading orders...</p>
           ) : orders.length > 0 ? (
             <div className="space-y-6">
-              {orders.map((order) => {
-                const item = itemDetails[order.item_id] || {
-                  name: "Loading...",
-                  description: "",
-                  price: 0,
-                };
+              {orders.flatMap((order) => order.lines).map((line) => {
+                const item = itemDetails[line.item_id];
                 return (
-                  <div key={order.order_id} className="flex gap-4">
+                  <div key={line.line_id} className="flex gap-4">
                     <div className="h-24 w-24 rounded-md overflow-hidden flex-shrink-0">
                       <img
-                        src={item.image || "/placeholder.svg"}
-                        alt={item.name}
+                        src={item?.image || "/placeholder.svg"}
+                        alt={line.item_name}
                         className="h-full w-full object-cover"
                       />
                     </div>
                     <div className="flex-1">
-                      <h3 className="font-medium">{item.name}</h3>
+                      <h3 className="font-medium">{line.item_name}</h3>
                       <p className="text-sm text-muted-foreground">
-                        {item.description}
+                        {item?.description}
                       </p>
                       <div className="flex justify-between items-center mt-2">
                         <div className="font-bold">
-                          ${item.price.toFixed(2)}
+                          ${line.unit_price.toFixed(2)}
                         </div>
                         <div className="flex items-center">
                           <span className="w-10 text-center">
-                            {order.quantity}
+                            {line.quantity}
                           </span>
                         </div>
                       </div>
@@ -1,6 +1,6 @@
 import { useEffect, useState } from "react";
 import { useAuth } from "../provider/auth-provider";
-import { invoke } from "@tauri-apps/api/core";
+import { invoke } from "@/lib/session";
 import OrderSummary from "./order-summary";
 import CheckoutDialog from "./checkout-dialog";
 import OrderItem from "./order-item";
@@ -64,47 +64,51 @@ export default function OrderSection({ pageType }: OrderSectionProps) {
     const newItemDetails: { [key: string]: ItemDetails } = {};
 
     await Promise.all(
-      orders.map(async (order) => {
-        try {
-          let response;
-          if (order.item_type === "restaurant") {
-            response = await invoke("view_menu", { menuId: order.item_id });
-          } else if (order.item_type === "store") {
-            response = await invoke("view_souvenir", {
-              souvenirId: order.item_id,
-            });
-          }
+      orders.flatMap((order) =>
+        order.lines.map(async (line) => {
+          try {
+            let response;
+            if (order.item_type === "restaurant") {
+              response = await invoke("view_menu", { menuId: line.item_id });
+            } else if (order.item_type === "store") {
+              response = await invoke("view_souvenir", {
+                souvenirId: line.item_id,
+              });
+            }
 
-          if (response && response.data) {
-            newItemDetails[order.item_id] = {
-              name: response.data.name,
-              description: response.data.description,
-              price: response.data.price,
-              image: response.data.image
-                ? `data:image/png;base64,${response.data.image}`
-                : "/placeholder.svg",
-            };
+            if (response && response.data) {
+              newItemDetails[line.item_id] = {
+                name: response.data.name,
+                description: response.data.description,
+                price: response.data.price,
+                image: response.data.image
+                  ? `data:image/png;base64,${response.data.image}`
+                  : "/placeholder.svg",
+              };
+            }
+          } catch (error) {
+            console.error(
+              `Failed to fetch item details for ${line.item_id}:`,
+              error
+            );
           }
-        } catch (error) {
-          console.error(
-            `Failed to fetch item details for ${order.item_id}:`,
-            error
-          );
-        }
-      })
+        })
+      )
     );
 
     setItemDetails(newItemDetails);
   }
 
   const balance = user.user?.balance || 0;
-  const subtotal = orders.reduce((total, order) => {
-    const item = itemDetails[order.item_id];
-    return total + (item ? item.price * order.quantity : 0);
-  }, 0);
+  // Lines carry the price they were added at, which is what checkout charges.
+  const subtotal = orders.reduce((total, order) => total + order.total, 0);
   const tax = subtotal * 0.08;
   const total = subtotal + tax;
-  const totalItems = orders.reduce((count, order) => count + order.quantity, 0);
+  const totalItems = orders.reduce(
+    (count, order) =>
+      count + order.lines.reduce((lines, line) => lines + line.quantity, 0),
+    0
+  );
   const insufficientBalance = (user.user?.balance || 0) < total;
 
   return (
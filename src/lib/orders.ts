@@ -0,0 +1,32 @@
+import { invoke } from "@/lib/session";
+import { ApiResponse } from "@/types/props";
+
+const ORDER_PAGE_SIZE = 200;
+
+interface OrderFilters {
+  orderType?: string;
+  restaurantId?: string;
+}
+
+// `view_all_orders` is paged, so staff screens that show every order walk the
+// cursor until a short page comes back.
+export async function fetchAllOrders<T extends { order_id: string }>(
+  filters: OrderFilters = {}
+): Promise<ApiResponse<T[]>> {
+  const orders: T[] = [];
+  let cursor: string | null = null;
+
+  for (;;) {
+    const page: ApiResponse<T[]> = await invoke<ApiResponse<T[]>>(
+      "view_all_orders",
+      { ...filters, limit: ORDER_PAGE_SIZE, cursor }
+    );
+    if (page.status === "error" || !page.data) return page;
+
+    orders.push(...page.data);
+    if (page.data.length < ORDER_PAGE_SIZE) break;
+    cursor = page.data[page.data.length - 1].order_id;
+  }
+
+  return { status: "success", data: orders, message: null };
+}
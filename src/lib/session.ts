@@ -0,0 +1,27 @@
+import { invoke as tauriInvoke } from "@tauri-apps/api/core";
+
+const SESSION_STORAGE_KEY = "vortekia-session";
+
+export function getSessionToken(): string | null {
+  return localStorage.getItem(SESSION_STORAGE_KEY);
+}
+
+export function setSessionToken(sessionToken: string | null) {
+  if (sessionToken) {
+    localStorage.setItem(SESSION_STORAGE_KEY, sessionToken);
+  } else {
+    localStorage.removeItem(SESSION_STORAGE_KEY);
+  }
+}
+
+// Every backend command checks the caller's session, so the token returned by
+// `login_user` / `staff_login` is attached to each call.
+export function invoke<T>(
+  command: string,
+  args: Record<string, unknown> = {}
+): Promise<T> {
+  return tauriInvoke<T>(command, {
+    ...args,
+    sessionToken: getSessionToken() ?? "",
+  });
+}
@@ -1,5 +1,6 @@
 import { useState, useEffect } from "react";
-import { invoke } from "@tauri-apps/api/core";
+import { invoke } from "@/lib/session";
+import { fetchAllOrders } from "@/lib/orders";
 import { Tabs, TabsContent, TabsList, TabsTrigger } from "@/components/ui/tabs";
 import {
   Card,
@@ -52,10 +53,11 @@ interface Order {
   order_id: string;
   customer_id: string;
   item_type: string;
-  item_id: string;
-  quantity: number;
   is_paid: boolean;
   date: string;
+  restaurant_id?: string;
+  lines: { item_id: string; quantity: number; subtotal: number }[];
+  total: number;
 }
 
 // Interface untuk ApiResponse dari backend
@@ -91,7 +93,7 @@ export default function FNBSupervisor({ staffId }: FNBSupervisorProps) {
           invoke<ApiResponse<Restaurant[]>>("view_all_restaurants"),
           invoke<ApiResponse<User[]>>("get_all_users"),
           invoke<ApiResponse<MenuItem[]>>("view_all_menus"),
-          invoke<ApiResponse<Order[]>>("view_all_orders"),
+          fetchAllOrders<Order>({ orderType: "restaurant" }),
         ]);
 
         // Cek apakah response sukses dan ambil data
@@ -144,14 +146,14 @@ export default function FNBSupervisor({ staffId }: FNBSupervisorProps) {
   const totalMenuItems = menus.length;
   const today = new Date().toISOString().split("T")[0];
 
-  // Hitung revenue (quantity * price untuk item_type "restaurant" yang sudah dibayar)
+  // Hitung revenue (total pesanan untuk item_type "restaurant" yang sudah dibayar)
   const paidRestaurantOrders = orders.filter(
     (o) => o.is_paid && o.item_type === "restaurant" && o.date.split("T")[0] === today
   );
-  const revenue = paidRestaurantOrders.reduce((sum, order) => {
-    const menuItem = menus.find((m) => m.menu_id === order.item_id);
-    return menuItem ? sum + order.quantity * menuItem.price : sum;
-  }, 0);
+  const revenue = paidRestaurantOrders.reduce(
+    (sum, order) => sum + order.total,
+    0
+  );
 
   return (
     <div className="container mx-auto py-6">
@@ -3,7 +3,7 @@
 import { useEffect, useState } from "react";
 import { Tabs, TabsContent, TabsList, TabsTrigger } from "@/components/ui/tabs";
 import { v4 as uuidv4 } from "uuid";
-import { invoke } from "@tauri-apps/api/core";
+import { invoke } from "@/lib/session";
 
 import {
   RideInformation,
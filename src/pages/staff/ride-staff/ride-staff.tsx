@@ -1,7 +1,7 @@
 "use client";
 
 import { useState, useEffect } from "react";
-import { invoke } from "@tauri-apps/api/core";
+import { invoke } from "@/lib/session";
 import { Tabs, TabsContent, TabsList, TabsTrigger } from "@/components/ui/tabs";
 import {
   Card,
@@ -11,7 +11,6 @@ import {
   CardDescription,
 } from "@/components/ui/card";
 import { Badge } from "@/components/ui/badge";
-import { v4 as uuidv4 } from "uuid";
 import { AlertCircle } from "lucide-react";
 
 import {
@@ -222,7 +221,6 @@ export default function RideStaff({ staffId }: RideStaffProps) {
     try {
       const joinedAt = new Date().toISOString();
       const payload = {
-        queue_id: "QUE-".concat(uuidv4()),
         ride_id: assignedRides[0].id,
         customer_id: customerId,
         joined_at: joinedAt,
@@ -341,7 +339,8 @@ export default function RideStaff({ staffId }: RideStaffProps) {
                   variant={
                     ride.status === "Operational"
                       ? "success"
-                      : ride.status === "Maintenance"
+                      : ride.status === "Maintenance" ||
+                        ride.status === "Weather Hold"
                       ? "warning"
                       : "destructive"
                   }
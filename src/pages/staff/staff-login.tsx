@@ -1,5 +1,5 @@
 import { useState } from "react";
-import { invoke } from "@tauri-apps/api/core";
+import { invoke, setSessionToken } from "@/lib/session";
 import { useNavigate } from "react-router";
 import { NavbarStaff } from "@/components/staff/navbar-staff";
 import { Button } from "@/components/ui/button";
@@ -17,16 +17,17 @@ export default function StaffLogin() {
   const handleLogin = async () => {
     try {
       console.log("Loggin in");
-      const response = await invoke("staff_login", {
+      const response = await invoke<any>("staff_login", {
         userId: username,
         password,
       });
       console.log("Response from login:", response);
-      if (response) {
-        const user = response.data;
+      if (response.data) {
+        const { session_token, ...user } = response.data;
+        setSessionToken(session_token);
         navigate("/staff/home", { state: { user } });
       } else {
-        setError(response.error);
+        setError(response.message);
       }
     } catch (err) {
       setError("An error occurred during login");
@@ -6,19 +6,10 @@ import {
   type OrderStatus,
 } from "@/components/restaurant/restaurant-order";
 import { Tabs, TabsContent, TabsList, TabsTrigger } from "@/components/ui/tabs";
-import { invoke } from "@tauri-apps/api/core";
+import { invoke } from "@/lib/session";
+import { fetchAllOrders } from "@/lib/orders";
 import { ApiResponse } from "@/types/props";
 
-interface MenuItem {
-  menu_id: string;
-  restaurant_id: string;
-  name: string;
-  image: string;
-  description: string;
-  price: number;
-  available_quantity: number;
-}
-
 interface WaiterProps {
   staffId: string;
 }
@@ -27,7 +18,6 @@ export default function Waiter({ staffId }: WaiterProps) {
   const [activeTab, setActiveTab] = useState("orders");
   const [restaurants, setRestaurants] = useState<Restaurant[]>([]);
   const [users, setUsers] = useState<User[]>([]);
-  const [menus, setMenus] = useState<MenuItem[]>([]);
   const [orders, setOrders] = useState<Order[]>([]);
   const [staffRestaurantId, setStaffRestaurantId] = useState<string | null>(
     null
@@ -41,14 +31,12 @@ export default function Waiter({ staffId }: WaiterProps) {
         const [
           restaurantsResponse,
           usersResponse,
-          menusResponse,
           ordersResponse,
           staffResponse,
         ] = await Promise.all([
           invoke<ApiResponse<Restaurant[]>>("view_all_restaurants"),
           invoke<ApiResponse<User[]>>("get_all_users"),
-          invoke<ApiResponse<MenuItem[]>>("view_all_menus"),
-          invoke<ApiResponse<Order[]>>("view_all_orders"),
+          fetchAllOrders<Order>({ orderType: "restaurant" }),
           invoke<ApiResponse<User>>("get_user_by_id", { userId: staffId }),
         ]);
 
@@ -61,9 +49,6 @@ export default function Waiter({ staffId }: WaiterProps) {
         if (usersResponse) setUsers(usersResponse.data);
         else throw new Error(usersResponse.error || "Failed to fetch users");
 
-        if (menusResponse) setMenus(menusResponse.data);
-        else throw new Error(menusResponse.error || "Failed to fetch menus");
-
         if (ordersResponse) setOrders(ordersResponse.data);
         else throw new Error(ordersResponse.error || "Failed to fetch orders");
 
@@ -83,10 +68,9 @@ export default function Waiter({ staffId }: WaiterProps) {
     fetchData();
   }, [staffId]);
 
-  const filteredOrders = orders.filter((order) => {
-    const menu = menus.find((menu) => menu.menu_id === order.item_id);
-    return menu?.restaurant_id === staffRestaurantId;
-  });
+  const filteredOrders = orders.filter(
+    (order) => order.status && order.restaurant_id === staffRestaurantId
+  );
 
   const handleUpdateStatus = (orderId: string, newStatus: OrderStatus) => {
     setOrders((prevOrders) =>
@@ -116,7 +100,6 @@ export default function Waiter({ staffId }: WaiterProps) {
             <h2 className="text-2xl font-bold mb-4">Manage Orders</h2>
             <RestaurantOrder
               orders={filteredOrders}
-              menus={menus}
               userRole="waiter"
               onUpdateStatus={handleUpdateStatus}
             />
@@ -1,10 +1,20 @@
+interface OrderLine {
+  line_id: string;
+  item_id: string;
+  item_name: string;
+  unit_price: number;
+  quantity: number;
+  subtotal: number;
+}
+
 interface Order {
   order_id: string;
   customer_id: string;
   item_type: string;
-  item_id: string;
   date: string;
-  quantity: number;
   is_paid: boolean;
   status?: string;
+  restaurant_id?: string;
+  lines: OrderLine[];
+  total: number;
 }
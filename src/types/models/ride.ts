@@ -1,11 +1,21 @@
+// Mirrors `RideStatus` / `RideMaintenanceStatus` on the backend; the database rejects anything else.
+type RideStatus =
+  | "Operational"
+  | "Closed"
+  | "Maintenance"
+  | "Weather Hold"
+  | "Under Construction";
+
+type RideMaintenanceStatus = "Available" | "Pending" | "In Progress";
+
 interface Ride {
   ride_id: string;
   name: string;
   image: string;
   description: string;
   location: string;
-  status: string;
+  status: RideStatus;
   capacity: number;
-  maintenance_status: string;
+  maintenance_status: RideMaintenanceStatus;
   queue_count: number;
 }